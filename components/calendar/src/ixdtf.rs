@@ -0,0 +1,607 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing and serialization of dates and times in the Internet Extended Date/Time Format
+//! (IXDTF), as specified in [RFC 9557](https://www.rfc-editor.org/rfc/rfc9557.html).
+//!
+//! [`Date`] and [`DateTime`] implement [`Writeable`] with the default [`IxdtfOptions`].
+//! Use [`Date::to_ixdtf()`] and [`DateTime::to_ixdtf()`] for other options.
+//!
+//! Dates in calendars other than ISO are written as the equivalent ISO date with a
+//! `[u-ca=...]` annotation, so the output can be read back with the `ixdtf` crate.
+//!
+//! With the `ixdtf` Cargo feature, IXDTF strings can be parsed into a [`Date`] or [`DateTime`]
+//! in the calendar given by the `[u-ca=...]` annotation, see [`Date::try_from_str()`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::calendar::ixdtf::{IxdtfOptions, IxdtfPrecision};
+//! use icu::calendar::{types::Time, Date, DateTime};
+//! use writeable::assert_writeable_eq;
+//!
+//! let date = Date::try_new_hebrew_date(5784, 7, 1).unwrap();
+//! assert_writeable_eq!(date, "2024-03-11[u-ca=hebrew]");
+//!
+//! let datetime = DateTime::new(
+//!     Date::try_new_iso_date(2024, 3, 11).unwrap(),
+//!     Time::try_new(10, 30, 15, 120_000_000).unwrap(),
+//! );
+//! assert_writeable_eq!(datetime, "2024-03-11T10:30:15.12");
+//!
+//! let mut options = IxdtfOptions::default();
+//! options.precision = IxdtfPrecision::Minute;
+//! assert_writeable_eq!(datetime.to_ixdtf(options), "2024-03-11T10:30");
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::types::Time;
+#[cfg(feature = "ixdtf")]
+use crate::CalendarError;
+use crate::{AsCalendar, Calendar, Date, DateTime, Iso};
+use core::fmt::{self, Write};
+#[cfg(feature = "ixdtf")]
+use displaydoc::Display;
+#[cfg(all(feature = "ixdtf", feature = "compiled_data"))]
+use ixdtf::parser::ParsedDateTime;
+use writeable::Writeable;
+
+/// The precision of the time written by [`DateTime::to_ixdtf()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum IxdtfPrecision {
+    /// Write the seconds, and as many fractional digits as needed to represent the
+    /// nanoseconds exactly, e.g. `10:30:15.12`.
+    #[default]
+    Auto,
+    /// Write hours and minutes only, e.g. `10:30`.
+    Minute,
+    /// Write whole seconds, e.g. `10:30:15`.
+    Second,
+    /// Write the given number of fractional digits, from 1 to 9, e.g. `10:30:15.120`.
+    ///
+    /// Extra digits are truncated. Values above 9 are treated as 9.
+    FractionalDigits(u8),
+}
+
+/// When to write the `[u-ca=...]` calendar annotation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum IxdtfCalendarDisplay {
+    /// Write the annotation unless the calendar is ISO.
+    #[default]
+    Auto,
+    /// Always write the annotation, e.g. `[u-ca=iso8601]`.
+    Always,
+    /// Always write the annotation with the critical flag, e.g. `[!u-ca=iso8601]`.
+    Critical,
+    /// Never write the annotation.
+    Never,
+}
+
+/// Options for writing IXDTF strings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct IxdtfOptions {
+    /// The precision of the time.
+    pub precision: IxdtfPrecision,
+    /// When to write the calendar annotation.
+    pub calendar: IxdtfCalendarDisplay,
+}
+
+/// A [`Date`] that can be written as an IXDTF string, returned by [`Date::to_ixdtf()`].
+#[derive(Debug)]
+pub struct IxdtfDate<'a, A: AsCalendar> {
+    date: &'a Date<A>,
+    options: IxdtfOptions,
+}
+
+/// A [`DateTime`] that can be written as an IXDTF string, returned by [`DateTime::to_ixdtf()`].
+#[derive(Debug)]
+pub struct IxdtfDateTime<'a, A: AsCalendar> {
+    datetime: &'a DateTime<A>,
+    options: IxdtfOptions,
+}
+
+/// The `[u-ca=...]` annotation of a calendar.
+///
+/// This is useful to write the calendar after other annotations, such as a time zone.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::ixdtf::{IxdtfCalendarAnnotation, IxdtfCalendarDisplay};
+/// use icu::calendar::{Gregorian, Iso};
+/// use writeable::assert_writeable_eq;
+///
+/// assert_writeable_eq!(
+///     IxdtfCalendarAnnotation::new(&Gregorian, IxdtfCalendarDisplay::Auto),
+///     "[u-ca=gregory]"
+/// );
+/// assert_writeable_eq!(
+///     IxdtfCalendarAnnotation::new(&Iso, IxdtfCalendarDisplay::Auto),
+///     ""
+/// );
+/// assert_writeable_eq!(
+///     IxdtfCalendarAnnotation::new(&Iso, IxdtfCalendarDisplay::Critical),
+///     "[!u-ca=iso8601]"
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IxdtfCalendarAnnotation {
+    kind: Option<AnyCalendarKind>,
+    display: IxdtfCalendarDisplay,
+}
+
+impl IxdtfCalendarAnnotation {
+    /// Creates the calendar annotation for the given calendar.
+    ///
+    /// Nothing is written for calendars that are not supported by
+    /// [`AnyCalendar`](crate::AnyCalendar).
+    pub fn new<C: Calendar + ?Sized>(calendar: &C, display: IxdtfCalendarDisplay) -> Self {
+        Self {
+            kind: calendar.any_calendar_kind(),
+            display,
+        }
+    }
+}
+
+impl Writeable for IxdtfCalendarAnnotation {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let kind = match self.kind {
+            Some(kind) => kind,
+            None => return Ok(()),
+        };
+        // RFC 9557 uses the CLDR name of the ISO calendar
+        let name = match kind {
+            AnyCalendarKind::Iso => "iso8601",
+            kind => kind.as_bcp47_string(),
+        };
+        match self.display {
+            IxdtfCalendarDisplay::Auto if kind == AnyCalendarKind::Iso => Ok(()),
+            IxdtfCalendarDisplay::Auto | IxdtfCalendarDisplay::Always => {
+                write!(sink, "[u-ca={name}]")
+            }
+            IxdtfCalendarDisplay::Critical => write!(sink, "[!u-ca={name}]"),
+            IxdtfCalendarDisplay::Never => Ok(()),
+        }
+    }
+}
+
+fn write_iso_date<W: Write + ?Sized>(date: &Date<Iso>, sink: &mut W) -> fmt::Result {
+    let year = date.year().number;
+    if (0..=9999).contains(&year) {
+        write!(sink, "{year:04}")?;
+    } else {
+        // Years outside of 0..=9999 use the expanded representation
+        let sign = if year < 0 { '-' } else { '+' };
+        write!(sink, "{sign}{:06}", year.unsigned_abs())?;
+    }
+    write!(
+        sink,
+        "-{:02}-{:02}",
+        date.month().ordinal,
+        date.day_of_month().0
+    )
+}
+
+fn write_time<W: Write + ?Sized>(
+    time: &Time,
+    precision: IxdtfPrecision,
+    sink: &mut W,
+) -> fmt::Result {
+    write!(
+        sink,
+        "{:02}:{:02}",
+        time.hour.number(),
+        time.minute.number()
+    )?;
+    let digits = match precision {
+        IxdtfPrecision::Minute => return Ok(()),
+        IxdtfPrecision::Second => 0,
+        IxdtfPrecision::FractionalDigits(digits) => digits.min(9) as usize,
+        IxdtfPrecision::Auto => {
+            let mut nanosecond = time.nanosecond.number();
+            let mut digits = 9;
+            while nanosecond != 0 && nanosecond % 10 == 0 {
+                nanosecond /= 10;
+                digits -= 1;
+            }
+            if nanosecond == 0 {
+                0
+            } else {
+                digits
+            }
+        }
+    };
+    write!(sink, ":{:02}", time.second.number())?;
+    if digits > 0 {
+        let fraction = time.nanosecond.number() / 10_u32.pow(9 - digits as u32);
+        write!(sink, ".{fraction:0digits$}")?;
+    }
+    Ok(())
+}
+
+impl<'a, A: AsCalendar> IxdtfDate<'a, A> {
+    /// Writes the ISO date, without the calendar annotation.
+    pub fn write_date_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        write_iso_date(&self.date.to_iso(), sink)
+    }
+
+    /// Returns the calendar annotation of the date.
+    pub fn calendar_annotation(&self) -> IxdtfCalendarAnnotation {
+        IxdtfCalendarAnnotation::new(self.date.calendar(), self.options.calendar)
+    }
+}
+
+impl<'a, A: AsCalendar> Writeable for IxdtfDate<'a, A> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_date_to(sink)?;
+        self.calendar_annotation().write_to(sink)
+    }
+}
+
+impl<'a, A: AsCalendar> IxdtfDateTime<'a, A> {
+    /// Writes the ISO date and the time, without the calendar annotation.
+    ///
+    /// This is useful to insert a UTC offset or a time zone annotation before the calendar
+    /// annotation.
+    pub fn write_date_time_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        write_iso_date(&self.datetime.date.to_iso(), sink)?;
+        sink.write_char('T')?;
+        write_time(&self.datetime.time, self.options.precision, sink)
+    }
+
+    /// Returns the calendar annotation of the date.
+    pub fn calendar_annotation(&self) -> IxdtfCalendarAnnotation {
+        IxdtfCalendarAnnotation::new(self.datetime.date.calendar(), self.options.calendar)
+    }
+}
+
+impl<'a, A: AsCalendar> Writeable for IxdtfDateTime<'a, A> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.write_date_time_to(sink)?;
+        self.calendar_annotation().write_to(sink)
+    }
+}
+
+impl<A: AsCalendar> Date<A> {
+    /// Returns a [`Writeable`] that writes this date as an IXDTF string with the given options.
+    ///
+    /// See the [`ixdtf`](crate::ixdtf) module for details.
+    pub fn to_ixdtf(&self, options: IxdtfOptions) -> IxdtfDate<'_, A> {
+        IxdtfDate {
+            date: self,
+            options,
+        }
+    }
+}
+
+impl<A: AsCalendar> DateTime<A> {
+    /// Returns a [`Writeable`] that writes this datetime as an IXDTF string with the given
+    /// options.
+    ///
+    /// See the [`ixdtf`](crate::ixdtf) module for details.
+    pub fn to_ixdtf(&self, options: IxdtfOptions) -> IxdtfDateTime<'_, A> {
+        IxdtfDateTime {
+            datetime: self,
+            options,
+        }
+    }
+}
+
+/// Writes the date as an IXDTF string with the default [`IxdtfOptions`].
+impl<A: AsCalendar> Writeable for Date<A> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.to_ixdtf(Default::default()).write_to(sink)
+    }
+}
+
+/// Writes the datetime as an IXDTF string with the default [`IxdtfOptions`].
+impl<A: AsCalendar> Writeable for DateTime<A> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.to_ixdtf(Default::default()).write_to(sink)
+    }
+}
+
+macro_rules! impl_display {
+    ($($type:ty),*) => {$(
+        /// This trait is implemented for compatibility with [`fmt!`](alloc::fmt).
+        /// To create a string, [`Writeable::write_to_string`] is usually more efficient.
+        impl<A: AsCalendar> fmt::Display for $type {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.write_to(f)
+            }
+        }
+    )*};
+}
+
+impl_display!(Date<A>, DateTime<A>, IxdtfDate<'_, A>, IxdtfDateTime<'_, A>);
+writeable::impl_display_with_writeable!(IxdtfCalendarAnnotation);
+
+/// An error returned when parsing an IXDTF string.
+///
+/// ✨ *Enabled with the `ixdtf` Cargo feature.*
+#[cfg(feature = "ixdtf")]
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The string is not valid IXDTF syntax.
+    #[displaydoc("Syntax error in the IXDTF string: {0:?}")]
+    Syntax(ixdtf::parser::ParseError),
+    /// The string does not contain a full date.
+    #[displaydoc("The IXDTF string does not contain a full date")]
+    MissingFields,
+    /// The `[u-ca=...]` annotation names a calendar that is not supported by
+    /// [`AnyCalendar`](crate::AnyCalendar).
+    #[displaydoc("Unknown calendar in the IXDTF string")]
+    UnknownCalendar,
+    /// The date or time is out of range.
+    #[displaydoc("{0}")]
+    Range(CalendarError),
+}
+
+#[cfg(all(feature = "ixdtf", feature = "std"))]
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "ixdtf")]
+impl From<ixdtf::parser::ParseError> for ParseError {
+    fn from(e: ixdtf::parser::ParseError) -> Self {
+        ParseError::Syntax(e)
+    }
+}
+
+#[cfg(feature = "ixdtf")]
+impl From<CalendarError> for ParseError {
+    fn from(e: CalendarError) -> Self {
+        ParseError::Range(e)
+    }
+}
+
+/// Returns the calendar of the `[u-ca=...]` annotation, or ISO if there is none.
+#[cfg(all(feature = "ixdtf", feature = "compiled_data"))]
+fn calendar_kind_for_ixdtf(ixdtf: &ParsedDateTime) -> Result<AnyCalendarKind, ParseError> {
+    match ixdtf.annotations.calendar() {
+        None | Some(b"iso8601") => Ok(AnyCalendarKind::Iso),
+        Some(calendar) => core::str::from_utf8(calendar)
+            .ok()
+            .and_then(AnyCalendarKind::get_for_bcp47_string)
+            .ok_or(ParseError::UnknownCalendar),
+    }
+}
+
+#[cfg(all(feature = "ixdtf", feature = "compiled_data"))]
+fn iso_date_for_ixdtf(ixdtf: &ParsedDateTime) -> Result<Date<Iso>, ParseError> {
+    match (ixdtf.year, ixdtf.month, ixdtf.day) {
+        (Some(year), Some(month), Some(day)) => Ok(Date::try_new_iso_date(year, month, day)?),
+        _ => Err(ParseError::MissingFields),
+    }
+}
+
+#[cfg(all(feature = "ixdtf", feature = "compiled_data"))]
+impl Date<crate::AnyCalendar> {
+    /// Parses a [`Date`] from an IXDTF string, such as `2024-03-11` or
+    /// `2024-03-11T10:00[u-ca=hebrew]`.
+    ///
+    /// The date is in the calendar of the `[u-ca=...]` annotation, or ISO if there is none.
+    /// The time, UTC offset and time zone annotation are ignored.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `ixdtf` Cargo features.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendarKind, Date};
+    ///
+    /// let date = Date::try_from_str("2024-03-11[u-ca=hebrew]").unwrap();
+    /// assert_eq!(date.calendar().kind(), AnyCalendarKind::Hebrew);
+    /// assert_eq!(date.year().number, 5784);
+    /// assert_eq!(date.day_of_month().0, 1);
+    ///
+    /// let date = Date::try_from_str("2024-03-11").unwrap();
+    /// assert_eq!(date.calendar().kind(), AnyCalendarKind::Iso);
+    /// ```
+    pub fn try_from_str(ixdtf: &str) -> Result<Self, ParseError> {
+        let ixdtf = ixdtf::parser::DateTimeParser::new(ixdtf.as_bytes()).parse()?;
+        Self::try_from_ixdtf(&ixdtf)
+    }
+
+    /// Creates a [`Date`] from the result of the `ixdtf` parser.
+    ///
+    /// See [`Self::try_from_str()`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `ixdtf` Cargo features.*
+    pub fn try_from_ixdtf(ixdtf: &ParsedDateTime) -> Result<Self, ParseError> {
+        let calendar = crate::AnyCalendar::new(calendar_kind_for_ixdtf(ixdtf)?);
+        Ok(iso_date_for_ixdtf(ixdtf)?.to_calendar(calendar))
+    }
+}
+
+#[cfg(all(feature = "ixdtf", feature = "compiled_data"))]
+impl DateTime<crate::AnyCalendar> {
+    /// Parses a [`DateTime`] from an IXDTF string, such as `2024-03-11T10:00:00` or
+    /// `2024-03-11T10:00:00+05:30[Asia/Kolkata][u-ca=hebrew]`.
+    ///
+    /// The date is in the calendar of the `[u-ca=...]` annotation, or ISO if there is none.
+    /// Missing time fields are zero. The UTC offset and time zone annotation are ignored.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `ixdtf` Cargo features.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendarKind, DateTime};
+    ///
+    /// let datetime =
+    ///     DateTime::try_from_str("2024-03-11T10:30:15.5[u-ca=buddhist]").unwrap();
+    /// assert_eq!(datetime.date.calendar().kind(), AnyCalendarKind::Buddhist);
+    /// assert_eq!(datetime.date.year().number, 2567);
+    /// assert_eq!(datetime.time.minute.number(), 30);
+    /// assert_eq!(datetime.time.nanosecond.number(), 500_000_000);
+    /// ```
+    pub fn try_from_str(ixdtf: &str) -> Result<Self, ParseError> {
+        let ixdtf = ixdtf::parser::DateTimeParser::new(ixdtf.as_bytes()).parse()?;
+        Self::try_from_ixdtf(&ixdtf)
+    }
+
+    /// Creates a [`DateTime`] from the result of the `ixdtf` parser.
+    ///
+    /// See [`Self::try_from_str()`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `ixdtf` Cargo features.*
+    pub fn try_from_ixdtf(ixdtf: &ParsedDateTime) -> Result<Self, ParseError> {
+        let date = Date::try_from_ixdtf(ixdtf)?;
+        let time = Time::try_new(
+            ixdtf.hour.unwrap_or_default(),
+            ixdtf.minute.unwrap_or_default(),
+            ixdtf.second.unwrap_or_default(),
+            ixdtf.nano_second.unwrap_or_default() as u32,
+        )?;
+        Ok(DateTime::new(date, time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnyCalendar, Gregorian};
+    use ixdtf::parser::DateTimeParser;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_years() {
+        let date = |year| Date::try_new_iso_date(year, 1, 2).unwrap();
+        assert_writeable_eq!(date(0), "0000-01-02");
+        assert_writeable_eq!(date(9999), "9999-01-02");
+        assert_writeable_eq!(date(10000), "+010000-01-02");
+        assert_writeable_eq!(date(-1), "-000001-01-02");
+    }
+
+    #[test]
+    fn test_precision() {
+        let datetime = DateTime::try_new_iso_datetime(2024, 3, 11, 10, 0, 0).unwrap();
+        let mut options = IxdtfOptions::default();
+        assert_writeable_eq!(datetime.to_ixdtf(options), "2024-03-11T10:00:00");
+        options.precision = IxdtfPrecision::FractionalDigits(3);
+        assert_writeable_eq!(datetime.to_ixdtf(options), "2024-03-11T10:00:00.000");
+
+        let datetime = DateTime::new(
+            Date::try_new_gregorian_date(2024, 3, 11).unwrap(),
+            Time::try_new(10, 0, 0, 987_654_321).unwrap(),
+        );
+        assert_writeable_eq!(datetime, "2024-03-11T10:00:00.987654321[u-ca=gregory]");
+        options.precision = IxdtfPrecision::FractionalDigits(2);
+        assert_writeable_eq!(
+            datetime.to_ixdtf(options),
+            "2024-03-11T10:00:00.98[u-ca=gregory]"
+        );
+        options.precision = IxdtfPrecision::FractionalDigits(12);
+        options.calendar = IxdtfCalendarDisplay::Never;
+        assert_writeable_eq!(datetime.to_ixdtf(options), "2024-03-11T10:00:00.987654321");
+        options.precision = IxdtfPrecision::Second;
+        options.calendar = IxdtfCalendarDisplay::Critical;
+        assert_writeable_eq!(
+            datetime.to_ixdtf(options),
+            "2024-03-11T10:00:00[!u-ca=gregory]"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let iso = DateTime::try_new_iso_datetime(2024, 3, 11, 10, 30, 15).unwrap();
+        for kind in [
+            AnyCalendarKind::Iso,
+            AnyCalendarKind::Gregorian,
+            AnyCalendarKind::Hebrew,
+            AnyCalendarKind::IslamicCivil,
+            AnyCalendarKind::Japanese,
+        ] {
+            let datetime = iso.to_calendar(AnyCalendar::new(kind));
+            let options = IxdtfOptions {
+                calendar: IxdtfCalendarDisplay::Always,
+                ..Default::default()
+            };
+            let string = datetime.to_ixdtf(options).write_to_string().into_owned();
+            let parsed = DateTimeParser::new(string.as_bytes()).parse().unwrap();
+
+            assert_eq!(parsed.year, Some(2024), "{string}");
+            assert_eq!(parsed.month, Some(3), "{string}");
+            assert_eq!(parsed.day, Some(11), "{string}");
+            assert_eq!(parsed.hour, Some(10), "{string}");
+            assert_eq!(parsed.minute, Some(30), "{string}");
+            assert_eq!(parsed.second, Some(15), "{string}");
+            let calendar = parsed.annotations.calendar().unwrap();
+            assert_eq!(
+                AnyCalendarKind::get_for_bcp47_bytes(calendar)
+                    .or_else(|| (calendar == b"iso8601").then_some(AnyCalendarKind::Iso)),
+                Some(kind),
+                "{string}"
+            );
+        }
+
+        let date = Date::try_new_iso_date(-5, 1, 1)
+            .unwrap()
+            .to_calendar(Gregorian);
+        let string = date.write_to_string();
+        let parsed = DateTimeParser::new(string.as_bytes()).parse().unwrap();
+        assert_eq!(parsed.year, Some(-5));
+        assert_eq!(parsed.annotations.calendar(), Some(&b"gregory"[..]));
+    }
+
+    #[test]
+    #[cfg(feature = "ixdtf")]
+    fn test_parse() {
+        let datetime =
+            DateTime::try_from_str("2024-03-11T10:30:15.25+01:00[Europe/Paris]").unwrap();
+        assert_eq!(datetime.date.calendar().kind(), AnyCalendarKind::Iso);
+        assert_eq!(datetime.date.year().number, 2024);
+        assert_eq!(datetime.time.hour.number(), 10);
+        assert_eq!(datetime.time.second.number(), 15);
+        assert_eq!(datetime.time.nanosecond.number(), 250_000_000);
+
+        let date = Date::try_from_str("2024-03-11T10:30[!u-ca=buddhist]").unwrap();
+        assert_eq!(date.calendar().kind(), AnyCalendarKind::Buddhist);
+        assert_eq!(date.year().number, 2567);
+        assert_eq!(date.to_iso(), Date::try_new_iso_date(2024, 3, 11).unwrap());
+
+        let datetime = DateTime::try_from_str("2024-03-11").unwrap();
+        assert_eq!(datetime.time, Time::midnight());
+        assert_eq!(
+            Date::try_from_str("2024-03-11[u-ca=iso8601]")
+                .unwrap()
+                .calendar()
+                .kind(),
+            AnyCalendarKind::Iso
+        );
+
+        assert_eq!(
+            Date::try_from_str("2024-03-11[u-ca=julian]"),
+            Err(ParseError::UnknownCalendar)
+        );
+        assert_eq!(
+            Date::try_from_str("2024-03-1"),
+            Err(ParseError::Syntax(ixdtf::parser::ParseError::DateDay))
+        );
+
+        let ixdtf = DateTimeParser::new(b"--03-11").parse_month_day().unwrap();
+        assert_eq!(Date::try_from_ixdtf(&ixdtf), Err(ParseError::MissingFields));
+    }
+
+    #[test]
+    #[cfg(feature = "ixdtf")]
+    fn test_parse_round_trip() {
+        for calendar in [
+            AnyCalendarKind::Iso,
+            AnyCalendarKind::Hebrew,
+            AnyCalendarKind::Japanese,
+        ] {
+            let date = Date::try_new_iso_date(-33, 12, 31)
+                .unwrap()
+                .to_calendar(AnyCalendar::new(calendar));
+            let string = date.write_to_string().into_owned();
+            assert_eq!(Date::try_from_str(&string), Ok(date), "{string}");
+        }
+    }
+}
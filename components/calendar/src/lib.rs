@@ -131,6 +131,7 @@ pub mod hebrew;
 pub mod indian;
 pub mod islamic;
 pub mod iso;
+pub mod ixdtf;
 pub mod japanese;
 pub mod julian;
 pub mod persian;
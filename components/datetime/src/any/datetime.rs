@@ -12,7 +12,7 @@ use icu_calendar::provider::{
     ChineseCacheV1Marker, DangiCacheV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
     WeekDataV1Marker,
 };
-use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
 use icu_plurals::provider::OrdinalV1Marker;
 use icu_provider::prelude::*;
 use icu_provider::DataLocale;
@@ -220,7 +220,7 @@ impl DateTimeFormatter {
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<BuddhistDateLengthsV1Marker>
             + DataProvider<BuddhistDateSymbolsV1Marker>
             + DataProvider<ChineseCacheV1Marker>
@@ -361,7 +361,7 @@ impl DateTimeFormatter {
             + DataProvider<TimeLengthsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<BuddhistDateLengthsV1Marker>
             + DataProvider<BuddhistDateSymbolsV1Marker>
             + DataProvider<ChineseCacheV1Marker>
@@ -18,7 +18,7 @@ use icu_calendar::provider::{
     WeekDataV1Marker,
 };
 use icu_calendar::{types::Time, DateTime};
-use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
 use icu_plurals::provider::OrdinalV1Marker;
 use writeable::Writeable;
 
@@ -236,7 +236,7 @@ impl ZonedDateTimeFormatter {
             + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
             + DataProvider<OrdinalV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<BuddhistDateLengthsV1Marker>
             + DataProvider<BuddhistDateSymbolsV1Marker>
             + DataProvider<ChineseCacheV1Marker>
@@ -390,7 +390,7 @@ impl ZonedDateTimeFormatter {
             + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
             + DataProvider<OrdinalV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<BuddhistDateLengthsV1Marker>
             + DataProvider<BuddhistDateSymbolsV1Marker>
             + DataProvider<ChineseCacheV1Marker>
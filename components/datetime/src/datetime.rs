@@ -15,7 +15,7 @@ use crate::{
 use alloc::string::String;
 use core::marker::PhantomData;
 use icu_calendar::provider::WeekDataV1Marker;
-use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
 use icu_plurals::provider::OrdinalV1Marker;
 use icu_provider::prelude::*;
 use writeable::Writeable;
@@ -119,7 +119,7 @@ impl TimeFormatter {
     where
         D: DataProvider<TimeLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + ?Sized,
     {
         let preferences = Some(preferences::Bag::from_data_locale(locale));
@@ -332,7 +332,7 @@ impl<C: CldrCalendar> TypedDateFormatter<C> {
     where
         D: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + ?Sized,
@@ -585,7 +585,7 @@ where {
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + ?Sized,
@@ -692,7 +692,7 @@ where {
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + ?Sized,
@@ -26,7 +26,7 @@ use icu_calendar::types::MonthCode;
 use icu_calendar::week::WeekCalculator;
 use icu_decimal::options::FixedDecimalFormatterOptions;
 use icu_decimal::options::GroupingStrategy;
-use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
 use icu_decimal::FixedDecimalFormatter;
 use icu_provider::prelude::*;
 use writeable::Writeable;
@@ -241,7 +241,7 @@ impl<C: CldrCalendar> TypedDateTimeNames<C> {
     #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
     pub fn try_new_unstable<P>(provider: &P, locale: &DataLocale) -> Result<Self, Error>
     where
-        P: DataProvider<DecimalSymbolsV1Marker> + ?Sized,
+        P: DataProvider<DecimalSymbolsV2Marker> + ?Sized,
     {
         let mut names = Self {
             locale: locale.clone(),
@@ -538,7 +538,7 @@ impl<C: CldrCalendar> TypedDateTimeNames<C> {
     #[inline]
     fn load_fixed_decimal_formatter<P>(&mut self, provider: &P) -> Result<&mut Self, Error>
     where
-        P: DataProvider<DecimalSymbolsV1Marker> + ?Sized,
+        P: DataProvider<DecimalSymbolsV2Marker> + ?Sized,
     {
         self.inner
             .load_fixed_decimal_formatter(&ExternalLoaderUnstable(provider), &self.locale)?;
@@ -581,7 +581,7 @@ impl<C: CldrCalendar> TypedDateTimeNames<C> {
             + DataProvider<C::MonthNamesV1Marker>
             + DataProvider<WeekdayNamesV1Marker>
             + DataProvider<DayPeriodNamesV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<WeekDataV2Marker>
             + ?Sized,
     {
@@ -22,7 +22,7 @@ use icu_calendar::provider::{
     WeekDataV2Marker,
 };
 use icu_calendar::AnyCalendar;
-use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
 use icu_provider::prelude::*;
 use writeable::Writeable;
 
@@ -154,7 +154,7 @@ impl<C: CldrCalendar> TypedNeoDateFormatter<C> {
             + DataProvider<C::MonthNamesV1Marker>
             + DataProvider<WeekdayNamesV1Marker>
             // FixedDecimalFormatter keys
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             // WeekCalculator keys
             + DataProvider<WeekDataV2Marker>,
     {
@@ -354,7 +354,7 @@ impl NeoDateFormatter {
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
             // FixedDecimalFormatter keys
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             // WeekCalculator keys
             + DataProvider<WeekDataV2Marker>,
     {
@@ -579,7 +579,7 @@ impl NeoTimeFormatter {
             + DataProvider<TimePatternV1Marker>
             + DataProvider<DayPeriodNamesV1Marker>
             // FixedDecimalFormatter keys
-            + DataProvider<DecimalSymbolsV1Marker>,
+            + DataProvider<DecimalSymbolsV2Marker>,
     {
         Self::try_new_with_length_internal(
             provider,
@@ -767,7 +767,7 @@ impl<C: CldrCalendar> TypedNeoDateTimeFormatter<C> {
             + DataProvider<C::MonthNamesV1Marker>
             + DataProvider<WeekdayNamesV1Marker>
             // FixedDecimalFormatter keys
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             // WeekCalculator keys
             + DataProvider<WeekDataV2Marker>,
     {
@@ -864,7 +864,7 @@ impl<C: CldrCalendar> TypedNeoDateTimeFormatter<C> {
             + DataProvider<TimePatternV1Marker>
             + DataProvider<DayPeriodNamesV1Marker>
             // FixedDecimalFormatter keys
-            + DataProvider<DecimalSymbolsV1Marker>,
+            + DataProvider<DecimalSymbolsV2Marker>,
     {
         Self::try_new_with_time_length_internal(
             provider,
@@ -975,7 +975,7 @@ impl<C: CldrCalendar> TypedNeoDateTimeFormatter<C> {
             // DateTime glue key
             + DataProvider<DateTimePatternV1Marker>
             // FixedDecimalFormatter key
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             // WeekCalculator key
             + DataProvider<WeekDataV2Marker>,
     {
@@ -1229,7 +1229,7 @@ impl NeoDateTimeFormatter {
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
             // FixedDecimalFormatter keys
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             // WeekCalculator keys
             + DataProvider<WeekDataV2Marker>,
     {
@@ -1381,7 +1381,7 @@ impl NeoDateTimeFormatter {
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
             // FixedDecimalFormatter keys
-            + DataProvider<DecimalSymbolsV1Marker>,
+            + DataProvider<DecimalSymbolsV2Marker>,
     {
         Self::try_new_with_time_length_internal(
             provider,
@@ -1533,7 +1533,7 @@ impl NeoDateTimeFormatter {
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
             // FixedDecimalFormatter key
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             // WeekCalculator key
             + DataProvider<WeekDataV2Marker>,
     {
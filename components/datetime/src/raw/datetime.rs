@@ -25,7 +25,7 @@ use icu_calendar::provider::WeekDataV1Marker;
 use icu_calendar::week::WeekCalculator;
 use icu_decimal::{
     options::{FixedDecimalFormatterOptions, GroupingStrategy},
-    provider::DecimalSymbolsV1Marker,
+    provider::DecimalSymbolsV2Marker,
     FixedDecimalFormatter,
 };
 use icu_plurals::{provider::OrdinalV1Marker, PluralRules};
@@ -88,7 +88,7 @@ impl TimeFormatter {
     where
         D: DataProvider<TimeLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + ?Sized,
     {
         let patterns =
@@ -224,7 +224,7 @@ impl DateFormatter {
         length: length::Date,
     ) -> Result<Self, DateTimeError>
     where
-        D: DataProvider<DecimalSymbolsV1Marker>
+        D: DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + ?Sized,
@@ -440,7 +440,7 @@ impl DateTimeFormatter {
     where
         D: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + ?Sized,
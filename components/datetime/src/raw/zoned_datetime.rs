@@ -5,7 +5,7 @@
 use icu_calendar::provider::WeekDataV1Marker;
 use icu_decimal::{
     options::{FixedDecimalFormatterOptions, GroupingStrategy},
-    provider::DecimalSymbolsV1Marker,
+    provider::DecimalSymbolsV2Marker,
     FixedDecimalFormatter,
 };
 use icu_plurals::{provider::OrdinalV1Marker, PluralRules};
@@ -127,7 +127,7 @@ impl ZonedDateTimeFormatter {
             + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
             + DataProvider<OrdinalV1Marker>
-            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
             + ?Sized,
     {
         let required = datetime::analyze_patterns(&patterns.get().0, true)
@@ -26,6 +26,11 @@ use writeable::Writeable;
 #[cfg(doc)]
 use crate::ZonedDateTimeFormatter;
 
+mod names;
+mod parser;
+pub use names::TimeZoneNames;
+pub use parser::TimeZoneParser;
+
 /// Loads a resource into its destination if the destination has not already been filled.
 fn load<D, P>(
     locale: &DataLocale,
@@ -49,6 +54,20 @@ where
     Ok(())
 }
 
+/// Loads a resource for the given locale.
+fn load_payload<D, P>(locale: &DataLocale, provider: &P) -> Result<DataPayload<D>, DateTimeError>
+where
+    D: KeyedDataMarker,
+    P: DataProvider<D> + ?Sized,
+{
+    Ok(provider
+        .load(DataRequest {
+            locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?)
+}
+
 /// [`TimeZoneFormatter`] is available for users who need to separately control the formatting of time
 /// zones.  Note: most users might prefer [`ZonedDateTimeFormatter`], which includes default time zone
 /// formatting according to the calendar.
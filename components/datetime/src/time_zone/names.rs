@@ -0,0 +1,123 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::load_payload;
+use crate::error::DateTimeError;
+use crate::provider::time_zones::*;
+use icu_provider::prelude::*;
+use icu_timezone::ZoneVariant;
+
+/// [`TimeZoneNames`] provides direct access to localized time zone display names, such as
+/// exemplar cities and metazone names.
+///
+/// Unlike [`TimeZoneFormatter`](super::TimeZoneFormatter), this does not require a
+/// [`CustomTimeZone`](icu_timezone::CustomTimeZone); names are looked up by time zone or
+/// metazone ID. This is useful for user interfaces that list time zones.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::time_zone::TimeZoneNames;
+/// use icu::locid::locale;
+/// use icu::timezone::ZoneVariant;
+///
+/// let names = TimeZoneNames::try_new(&locale!("en").into()).unwrap();
+///
+/// assert_eq!(names.exemplar_city("usnyc".parse().unwrap()), Some("New York"));
+///
+/// let america_eastern = "amea".parse().unwrap();
+/// assert_eq!(
+///     names.generic_non_location_long(america_eastern),
+///     Some("Eastern Time")
+/// );
+/// assert_eq!(names.generic_non_location_short(america_eastern), Some("ET"));
+/// assert_eq!(
+///     names.specific_non_location_long(america_eastern, ZoneVariant::daylight()),
+///     Some("Eastern Daylight Time")
+/// );
+/// assert_eq!(
+///     names.specific_non_location_short(america_eastern, ZoneVariant::standard()),
+///     Some("EST")
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TimeZoneNames {
+    exemplar_cities: DataPayload<ExemplarCitiesV1Marker>,
+    mz_generic_long: DataPayload<MetazoneGenericNamesLongV1Marker>,
+    mz_generic_short: DataPayload<MetazoneGenericNamesShortV1Marker>,
+    mz_specific_long: DataPayload<MetazoneSpecificNamesLongV1Marker>,
+    mz_specific_short: DataPayload<MetazoneSpecificNamesShortV1Marker>,
+}
+
+impl TimeZoneNames {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: DateTimeError,
+        /// Creates a new [`TimeZoneNames`] for the given locale using compiled data.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(provider: &P, locale: &DataLocale) -> Result<Self, DateTimeError>
+    where
+        P: DataProvider<ExemplarCitiesV1Marker>
+            + DataProvider<MetazoneGenericNamesLongV1Marker>
+            + DataProvider<MetazoneGenericNamesShortV1Marker>
+            + DataProvider<MetazoneSpecificNamesLongV1Marker>
+            + DataProvider<MetazoneSpecificNamesShortV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            exemplar_cities: load_payload(locale, provider)?,
+            mz_generic_long: load_payload(locale, provider)?,
+            mz_generic_short: load_payload(locale, provider)?,
+            mz_specific_long: load_payload(locale, provider)?,
+            mz_specific_short: load_payload(locale, provider)?,
+        })
+    }
+
+    /// Returns the exemplar city of a time zone, e.g. "Los Angeles" for `uslax`.
+    pub fn exemplar_city(&self, time_zone_id: TimeZoneBcp47Id) -> Option<&str> {
+        self.exemplar_cities.get().0.get(&time_zone_id)
+    }
+
+    /// Returns the long generic name of a metazone, e.g. "Pacific Time" for `ampa`.
+    pub fn generic_non_location_long(&self, metazone_id: MetazoneId) -> Option<&str> {
+        self.mz_generic_long.get().defaults.get(&metazone_id)
+    }
+
+    /// Returns the short generic name of a metazone, e.g. "PT" for `ampa`.
+    pub fn generic_non_location_short(&self, metazone_id: MetazoneId) -> Option<&str> {
+        self.mz_generic_short.get().defaults.get(&metazone_id)
+    }
+
+    /// Returns the long specific name of a metazone, e.g. "Pacific Daylight Time" for `ampa`
+    /// in daylight time.
+    pub fn specific_non_location_long(
+        &self,
+        metazone_id: MetazoneId,
+        zone_variant: ZoneVariant,
+    ) -> Option<&str> {
+        self.mz_specific_long
+            .get()
+            .defaults
+            .get_2d(&metazone_id, &zone_variant)
+    }
+
+    /// Returns the short specific name of a metazone, e.g. "PDT" for `ampa` in daylight time.
+    pub fn specific_non_location_short(
+        &self,
+        metazone_id: MetazoneId,
+        zone_variant: ZoneVariant,
+    ) -> Option<&str> {
+        self.mz_specific_short
+            .get()
+            .defaults
+            .get_2d(&metazone_id, &zone_variant)
+    }
+}
@@ -0,0 +1,400 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::load_payload;
+use crate::error::DateTimeError;
+use crate::provider::time_zones::*;
+use icu_provider::prelude::*;
+use icu_timezone::{CustomTimeZone, GmtOffset, ZoneVariant};
+use tinystr::tinystr;
+
+/// [`TimeZoneParser`] parses localized time zone names into [`CustomTimeZone`]s.
+///
+/// It uses the same data as [`TimeZoneFormatter`](super::TimeZoneFormatter) and understands
+/// the following formats:
+///
+/// - Localized GMT format, e.g. "GMT+05:30"
+/// - Specific non-location format, e.g. "Pacific Daylight Time" or "PDT"
+/// - Generic non-location format, e.g. "Pacific Time" or "PT"
+/// - Generic location format, e.g. "Los Angeles Time"
+///
+/// Only the fields of [`CustomTimeZone`] that are implied by the input are set. For example,
+/// a specific non-location name sets the metazone and the zone variant, but not the GMT offset.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::time_zone::TimeZoneParser;
+/// use icu::locid::locale;
+/// use icu::timezone::{GmtOffset, ZoneVariant};
+///
+/// let parser = TimeZoneParser::try_new(&locale!("en").into()).unwrap();
+///
+/// let (time_zone, len) = parser.parse("GMT+05:30").unwrap();
+/// assert_eq!(len, 9);
+/// assert_eq!(
+///     time_zone.gmt_offset.map(GmtOffset::offset_seconds),
+///     Some(19800)
+/// );
+///
+/// let (time_zone, len) = parser.parse("Pacific Daylight Time").unwrap();
+/// assert_eq!(len, 21);
+/// assert_eq!(time_zone.metazone_id, Some("ampa".parse().unwrap()));
+/// assert_eq!(time_zone.zone_variant, Some(ZoneVariant::daylight()));
+///
+/// // Only the matching prefix of the input is consumed
+/// let (time_zone, len) = parser.parse("Los Angeles Time, 10:00").unwrap();
+/// assert_eq!(len, 16);
+/// assert_eq!(time_zone.time_zone_id, Some("uslax".parse().unwrap()));
+///
+/// assert!(parser.parse("Not a time zone").is_none());
+/// ```
+#[derive(Debug)]
+pub struct TimeZoneParser {
+    zone_formats: DataPayload<TimeZoneFormatsV1Marker>,
+    exemplar_cities: DataPayload<ExemplarCitiesV1Marker>,
+    mz_generic_long: DataPayload<MetazoneGenericNamesLongV1Marker>,
+    mz_generic_short: DataPayload<MetazoneGenericNamesShortV1Marker>,
+    mz_specific_long: DataPayload<MetazoneSpecificNamesLongV1Marker>,
+    mz_specific_short: DataPayload<MetazoneSpecificNamesShortV1Marker>,
+}
+
+impl TimeZoneParser {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: DateTimeError,
+        /// Creates a new [`TimeZoneParser`] for the given locale using compiled data.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(provider: &P, locale: &DataLocale) -> Result<Self, DateTimeError>
+    where
+        P: DataProvider<TimeZoneFormatsV1Marker>
+            + DataProvider<ExemplarCitiesV1Marker>
+            + DataProvider<MetazoneGenericNamesLongV1Marker>
+            + DataProvider<MetazoneGenericNamesShortV1Marker>
+            + DataProvider<MetazoneSpecificNamesLongV1Marker>
+            + DataProvider<MetazoneSpecificNamesShortV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            zone_formats: load_payload(locale, provider)?,
+            exemplar_cities: load_payload(locale, provider)?,
+            mz_generic_long: load_payload(locale, provider)?,
+            mz_generic_short: load_payload(locale, provider)?,
+            mz_specific_long: load_payload(locale, provider)?,
+            mz_specific_short: load_payload(locale, provider)?,
+        })
+    }
+
+    /// Parses a localized time zone name at the start of `input`.
+    ///
+    /// Returns the parsed time zone together with the number of bytes of `input` that were
+    /// consumed, or `None` if `input` does not start with a time zone name known to this
+    /// parser. If several names match, the longest one is used.
+    ///
+    /// See [`TimeZoneParser`] for an example.
+    pub fn parse(&self, input: &str) -> Option<(CustomTimeZone, usize)> {
+        let mut longest = LongestMatch::default();
+
+        if let Some((gmt_offset, len)) = self.parse_localized_gmt(input) {
+            longest.update(len, || CustomTimeZone::new_with_offset(gmt_offset));
+        }
+
+        for names in [
+            &self.mz_specific_long.get().overrides,
+            &self.mz_specific_short.get().overrides,
+        ] {
+            for cursor in names.iter0() {
+                for (variant, name) in cursor.iter1() {
+                    if input.starts_with(name) {
+                        longest.update(name.len(), || CustomTimeZone {
+                            time_zone_id: Some(*cursor.key0()),
+                            zone_variant: Some(*variant),
+                            ..CustomTimeZone::new_empty()
+                        });
+                    }
+                }
+            }
+        }
+        for names in [
+            &self.mz_specific_long.get().defaults,
+            &self.mz_specific_short.get().defaults,
+        ] {
+            for cursor in names.iter0() {
+                for (variant, name) in cursor.iter1() {
+                    if input.starts_with(name) {
+                        longest.update(name.len(), || CustomTimeZone {
+                            metazone_id: Some(*cursor.key0()),
+                            zone_variant: Some(*variant),
+                            ..CustomTimeZone::new_empty()
+                        });
+                    }
+                }
+            }
+        }
+
+        for names in [
+            &self.mz_generic_long.get().overrides,
+            &self.mz_generic_short.get().overrides,
+        ] {
+            for (time_zone_id, name) in names.iter() {
+                if input.starts_with(name) {
+                    longest.update(name.len(), || CustomTimeZone {
+                        time_zone_id: Some(*time_zone_id),
+                        ..CustomTimeZone::new_empty()
+                    });
+                }
+            }
+        }
+        for names in [
+            &self.mz_generic_long.get().defaults,
+            &self.mz_generic_short.get().defaults,
+        ] {
+            for (metazone_id, name) in names.iter() {
+                if input.starts_with(name) {
+                    longest.update(name.len(), || CustomTimeZone {
+                        metazone_id: Some(*metazone_id),
+                        ..CustomTimeZone::new_empty()
+                    });
+                }
+            }
+        }
+
+        let zone_formats = self.zone_formats.get();
+        let region_formats = core::iter::once((&*zone_formats.region_format, None)).chain(
+            zone_formats
+                .region_format_variants
+                .iter()
+                .filter_map(|(variant, format)| {
+                    if *variant == tinystr!(8, "daylight") {
+                        Some((format, Some(ZoneVariant::daylight())))
+                    } else if *variant == tinystr!(8, "standard") {
+                        Some((format, Some(ZoneVariant::standard())))
+                    } else {
+                        None
+                    }
+                }),
+        );
+        for (region_format, zone_variant) in region_formats {
+            let (prefix, rest, suffix) = match region_format
+                .split_once("{0}")
+                .and_then(|(prefix, suffix)| Some((prefix, input.strip_prefix(prefix)?, suffix)))
+            {
+                Some(parts) => parts,
+                None => continue,
+            };
+            for (time_zone_id, city) in self.exemplar_cities.get().0.iter() {
+                if rest
+                    .strip_prefix(city)
+                    .map_or(false, |rest| rest.starts_with(suffix))
+                {
+                    longest.update(prefix.len() + city.len() + suffix.len(), || {
+                        CustomTimeZone {
+                            time_zone_id: Some(*time_zone_id),
+                            zone_variant,
+                            ..CustomTimeZone::new_empty()
+                        }
+                    });
+                }
+            }
+        }
+
+        longest.0
+    }
+
+    /// Parses a localized GMT offset, e.g. "GMT+05:30" or "GMT", at the start of `input`.
+    fn parse_localized_gmt(&self, input: &str) -> Option<(GmtOffset, usize)> {
+        let zone_formats = self.zone_formats.get();
+
+        let mut result = None;
+        if input.starts_with(&*zone_formats.gmt_zero_format) {
+            result = Some((GmtOffset::utc(), zone_formats.gmt_zero_format.len()));
+        }
+
+        let (prefix, rest, suffix) = match zone_formats
+            .gmt_format
+            .split_once("{0}")
+            .and_then(|(prefix, suffix)| Some((prefix, input.strip_prefix(prefix)?, suffix)))
+        {
+            Some(parts) => parts,
+            None => return result,
+        };
+        for (hour_format, sign) in [
+            (&zone_formats.hour_format.0, 1),
+            (&zone_formats.hour_format.1, -1),
+        ] {
+            if let Some((seconds, len)) = parse_hour_format(hour_format, rest) {
+                if !rest[len..].starts_with(suffix) {
+                    continue;
+                }
+                if let Ok(offset) = GmtOffset::try_from_offset_seconds(sign * seconds) {
+                    return Some((offset, prefix.len() + len + suffix.len()));
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Keeps track of the longest match seen so far.
+#[derive(Default)]
+struct LongestMatch(Option<(CustomTimeZone, usize)>);
+
+impl LongestMatch {
+    /// Replaces the current match if a match of length `len` is longer. Earlier matches take
+    /// precedence over later matches of the same length.
+    fn update(&mut self, len: usize, time_zone: impl FnOnce() -> CustomTimeZone) {
+        if len > 0 && self.0.as_ref().map_or(true, |(_, l)| len > *l) {
+            self.0 = Some((time_zone(), len));
+        }
+    }
+}
+
+/// Parses an offset according to a CLDR hour format like "+HH:mm", returning the offset in
+/// seconds and the number of bytes consumed.
+///
+/// Parsing is lenient: hours may have one or two digits, the minutes may be omitted, and so
+/// may the separator between the hours and the minutes.
+fn parse_hour_format(hour_format: &str, input: &str) -> Option<(i32, usize)> {
+    let mut pattern = hour_format.chars().peekable();
+    let mut pos = 0;
+    let mut hours = None;
+    let mut minutes = None;
+    // The position after the hours, used if the minutes are omitted.
+    let mut after_hours = None;
+
+    while let Some(c) = pattern.next() {
+        let parsed = match c {
+            'H' => {
+                if pattern.peek() == Some(&'H') {
+                    pattern.next();
+                }
+                parse_digits(&input[pos..], 1, 2).map(|(value, len)| {
+                    hours = Some(value);
+                    len
+                })
+            }
+            'm' => {
+                if pattern.peek() == Some(&'m') {
+                    pattern.next();
+                }
+                parse_digits(&input[pos..], 2, 2)
+                    .filter(|&(value, _)| value < 60)
+                    .map(|(value, len)| {
+                        minutes = Some(value);
+                        len
+                    })
+            }
+            c if input[pos..].starts_with(c) => Some(c.len_utf8()),
+            // The separator between the hours and the minutes may be omitted, as in the basic
+            // format "+HHmm".
+            _ if after_hours == Some(pos)
+                && pattern.peek() == Some(&'m')
+                && input[pos..].starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                Some(0)
+            }
+            _ => None,
+        };
+        match parsed {
+            Some(len) => {
+                pos += len;
+                if c == 'H' {
+                    after_hours = Some(pos);
+                }
+            }
+            None if minutes.is_none() => {
+                return hours.zip(after_hours).map(|(h, pos)| (h * 3600, pos));
+            }
+            None => return None,
+        }
+    }
+
+    Some((hours? * 3600 + minutes.unwrap_or(0) * 60, pos))
+}
+
+/// Parses between `min` and `max` ASCII digits at the start of `input`.
+fn parse_digits(input: &str, min: usize, max: usize) -> Option<(i32, usize)> {
+    let len = input
+        .bytes()
+        .take(max)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if len < min {
+        return None;
+    }
+    let value = input
+        .bytes()
+        .take(len)
+        .fold(0, |acc, b| acc * 10 + i32::from(b - b'0'));
+    Some((value, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hour_format() {
+        assert_eq!(parse_hour_format("+HH:mm", "+05:30"), Some((19800, 6)));
+        assert_eq!(parse_hour_format("+HH:mm", "+5:30"), Some((19800, 5)));
+        assert_eq!(parse_hour_format("+HH:mm", "+05"), Some((18000, 3)));
+        assert_eq!(parse_hour_format("+HH:mm", "+05:3"), Some((18000, 3)));
+        assert_eq!(parse_hour_format("+HH:mm", "+05:30 PM"), Some((19800, 6)));
+        assert_eq!(parse_hour_format("+HH:mm", "-05:30"), None);
+        assert_eq!(parse_hour_format("−HH:mm", "−05:30"), Some((19800, 8)));
+        assert_eq!(parse_hour_format("+H.mm", "+5.30"), Some((19800, 5)));
+        assert_eq!(parse_hour_format("+HHmm", "+0530"), Some((19800, 5)));
+        assert_eq!(parse_hour_format("+HH:mm", "+05:75"), Some((18000, 3)));
+        assert_eq!(parse_hour_format("+HH:mm", "+0530"), Some((19800, 5)));
+        assert_eq!(parse_hour_format("+HH:mm", "+053"), Some((18000, 3)));
+    }
+
+    #[test]
+    fn test_parse_hour_format_shapes() {
+        // Bidi marks, as used by e.g. Persian and Hebrew
+        assert_eq!(
+            parse_hour_format("\u{200e}+HH:mm", "\u{200e}+05:30"),
+            Some((19800, 9))
+        );
+        assert_eq!(parse_hour_format("\u{200e}+HH:mm", "+05:30"), None);
+        // Single-letter hours still accept two digits
+        assert_eq!(parse_hour_format("+H:mm", "+5:30"), Some((19800, 5)));
+        assert_eq!(parse_hour_format("+H:mm", "+12:00"), Some((43200, 6)));
+        // A different separator than the pattern's ends the match after the hours
+        assert_eq!(parse_hour_format("+HH.mm", "+05:30"), Some((18000, 3)));
+        // Without a separator, hours take at most two digits
+        assert_eq!(parse_hour_format("+HHmm", "+05"), Some((18000, 3)));
+        assert_eq!(parse_hour_format("+HHmm", "+053"), Some((18000, 3)));
+        assert_eq!(parse_hour_format("+HHmm", "+053012"), Some((19800, 5)));
+        // The sign of the negative format is applied by the caller
+        assert_eq!(parse_hour_format("-HH:mm", "-05:30"), Some((19800, 6)));
+        assert_eq!(parse_hour_format("-HH:mm", "+05:30"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "compiled_data")]
+    fn test_parse_localized_gmt() {
+        use icu_locid::locale;
+
+        let parser = TimeZoneParser::try_new(&locale!("en").into()).unwrap();
+        let offset = |input| {
+            parser
+                .parse_localized_gmt(input)
+                .map(|(offset, len)| (offset.offset_seconds(), len))
+        };
+        assert_eq!(offset("GMT"), Some((0, 3)));
+        assert_eq!(offset("GMT+05:30"), Some((19800, 9)));
+        assert_eq!(offset("GMT-05:30"), Some((-19800, 9)));
+        assert_eq!(offset("GMT+5"), Some((18000, 5)));
+        assert_eq!(offset("GMT-0530"), Some((-19800, 8)));
+        assert_eq!(offset("UTC+05:30"), None);
+    }
+}
@@ -36,7 +36,7 @@ use icu_datetime::{
     CldrCalendar, DateTimeFormatter, DateTimeFormatterOptions, TimeFormatter, TypedDateFormatter,
     TypedDateTimeFormatter, TypedZonedDateTimeFormatter,
 };
-use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
 use icu_locid::{
     extensions::unicode::{key, value},
     langid, locale, LanguageIdentifier, Locale,
@@ -442,7 +442,7 @@ fn test_dayperiod_patterns() {
             .take_payload()
             .unwrap();
         data_locale.retain_unicode_ext(|_| false);
-        let decimal_data: DataPayload<DecimalSymbolsV1Marker> = icu_decimal::provider::Baked
+        let decimal_data: DataPayload<DecimalSymbolsV2Marker> = icu_decimal::provider::Baked
             .load(DataRequest {
                 locale: &data_locale,
                 metadata: Default::default(),
@@ -481,7 +481,7 @@ fn test_dayperiod_patterns() {
                             AnyPayloadProvider::from_payload::<WeekDataV1Marker>(
                                 week_data.clone(), //
                             ),
-                            AnyPayloadProvider::from_payload::<DecimalSymbolsV1Marker>(
+                            AnyPayloadProvider::from_payload::<DecimalSymbolsV2Marker>(
                                 decimal_data.clone(), //
                             ),
                         ]);
@@ -635,7 +635,7 @@ fn test_time_zone_patterns() {
             .unwrap()
             .take_payload()
             .unwrap();
-        let decimal_data: DataPayload<DecimalSymbolsV1Marker> = icu_decimal::provider::Baked
+        let decimal_data: DataPayload<DecimalSymbolsV2Marker> = icu_decimal::provider::Baked
             .load(req)
             .unwrap()
             .take_payload()
@@ -712,7 +712,7 @@ fn test_time_zone_patterns() {
                     AnyPayloadProvider::from_payload::<WeekDataV1Marker>(
                         week_data.clone(), //
                     ),
-                    AnyPayloadProvider::from_payload::<DecimalSymbolsV1Marker>(
+                    AnyPayloadProvider::from_payload::<DecimalSymbolsV2Marker>(
                         decimal_data.clone(), //
                     ),
                     AnyPayloadProvider::from_payload::<TimeZoneFormatsV1Marker>(
@@ -9,7 +9,7 @@ use rand_pcg::Lcg64Xsh32;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 use fixed_decimal::FixedDecimal;
-use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
 use icu_decimal::FixedDecimalFormatter;
 use icu_locid::Locale;
 use icu_provider_adapters::any_payload::AnyPayloadProvider;
@@ -27,7 +27,7 @@ fn triangular_nums(range: f64) -> Vec<isize> {
 
 fn overview_bench(c: &mut Criterion) {
     let nums = triangular_nums(1e9);
-    let provider = AnyPayloadProvider::new_default::<DecimalSymbolsV1Marker>();
+    let provider = AnyPayloadProvider::new_default::<DecimalSymbolsV2Marker>();
     c.bench_function("icu_decimal/overview", |b| {
         b.iter(|| {
             // This benchmark demonstrates the performance of the format function on 1000 numbers
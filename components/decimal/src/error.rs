@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Error types for decimal formatting.
+//! Error types for decimal formatting and parsing.
 
 use displaydoc::Display;
 
@@ -25,3 +25,19 @@ impl From<icu_provider::DataError> for DecimalError {
         DecimalError::Data(e)
     }
 }
+
+/// A list of error outcomes for [`FixedDecimalParser`](crate::FixedDecimalParser).
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecimalParseError {
+    /// The input has a character that cannot be part of the number at the given byte index,
+    /// or it ends where a digit is expected.
+    #[displaydoc("unexpected character at index {0}")]
+    Syntax(usize),
+    /// The number has too many digits to be represented by a [`FixedDecimal`](fixed_decimal::FixedDecimal).
+    #[displaydoc("the number has too many digits")]
+    Limit,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecimalParseError {}
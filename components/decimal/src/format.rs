@@ -7,57 +7,249 @@
 use crate::grouper;
 use crate::options::*;
 use crate::provider::*;
+use crate::rounding;
+use alloc::borrow::Cow;
+use core::fmt::{self, Write};
+use fixed_decimal::ExtendedDecimal;
 use fixed_decimal::FixedDecimal;
 use fixed_decimal::Sign;
-use writeable::Writeable;
+use writeable::{Part, PartsWrite, Writeable};
+
+/// The [`Part`]s used by the formatted decimals of this crate.
+///
+/// The values follow the part types of `Intl.NumberFormat.prototype.formatToParts` in ECMA-402.
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the integer digits of a number, between grouping separators.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"integer"`
+    pub const INTEGER: Part = Part {
+        category: "decimal",
+        value: "integer",
+    };
+
+    /// The [`Part`] used to mark a grouping separator, such as "," in "1,000".
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"group"`
+    pub const GROUP: Part = Part {
+        category: "decimal",
+        value: "group",
+    };
+
+    /// The [`Part`] used to mark the decimal separator, such as "." in "1.5".
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"decimal"`
+    pub const DECIMAL: Part = Part {
+        category: "decimal",
+        value: "decimal",
+    };
+
+    /// The [`Part`] used to mark the fraction digits of a number.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"fraction"`
+    pub const FRACTION: Part = Part {
+        category: "decimal",
+        value: "fraction",
+    };
+
+    /// The [`Part`] used to mark the minus sign of a negative number.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"minusSign"`
+    pub const MINUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "minusSign",
+    };
+
+    /// The [`Part`] used to mark the plus sign of a number displayed with an explicit sign.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"plusSign"`
+    pub const PLUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "plusSign",
+    };
+
+    /// The [`Part`] used to mark the symbol that separates the mantissa from the exponent,
+    /// such as "E" in "1.2E3" or "×10" in "1.2×10³".
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"exponentSeparator"`
+    pub const EXPONENT_SEPARATOR: Part = Part {
+        category: "decimal",
+        value: "exponentSeparator",
+    };
+
+    /// The [`Part`] used to mark the minus sign of the exponent of a number in scientific
+    /// notation, such as "-" in "1.2E-3" or "⁻" in "1.2×10⁻³".
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"exponentMinusSign"`
+    pub const EXPONENT_MINUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "exponentMinusSign",
+    };
+
+    /// The [`Part`] used to mark the digits of the exponent of a number in scientific
+    /// notation, such as "3" in "1.2E-3" or "³" in "1.2×10⁻³".
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"exponentInteger"`
+    pub const EXPONENT_INTEGER: Part = Part {
+        category: "decimal",
+        value: "exponentInteger",
+    };
+
+    /// The [`Part`] used to mark the infinity symbol.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"infinity"`
+    pub const INFINITY: Part = Part {
+        category: "decimal",
+        value: "infinity",
+    };
+
+    /// The [`Part`] used to mark the NaN symbol.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"nan"`
+    pub const NAN: Part = Part {
+        category: "decimal",
+        value: "nan",
+    };
+
+    /// The [`Part`] used to mark the literal text of a sign affix that is not the sign
+    /// itself, such as a bidi mark.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"literal"`
+    pub const LITERAL: Part = Part {
+        category: "decimal",
+        value: "literal",
+    };
+}
 
 /// An intermediate structure returned by [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+///
+/// The [`Writeable`] is annotated with the [`parts`] of the number.
 #[derive(Debug, PartialEq, Clone)]
 pub struct FormattedFixedDecimal<'l> {
-    pub(crate) value: &'l FixedDecimal,
+    pub(crate) value: Cow<'l, FixedDecimal>,
     pub(crate) options: &'l FixedDecimalFormatterOptions,
-    pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+    pub(crate) symbols: &'l DecimalSymbolsV2<'l>,
 }
 
 impl<'l> FormattedFixedDecimal<'l> {
-    fn get_affixes(&self) -> Option<&AffixesV1> {
-        match self.value.sign() {
-            Sign::None => None,
-            Sign::Negative => Some(&self.symbols.minus_sign_affixes),
-            Sign::Positive => Some(&self.symbols.plus_sign_affixes),
+    /// Returns the number that is formatted, after applying the digit and sign options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu::decimal::options::FixedDecimalFormatterOptions;
+    /// use icu::decimal::FixedDecimalFormatter;
+    /// use icu::locid::locale;
+    ///
+    /// let mut options = FixedDecimalFormatterOptions::default();
+    /// options.maximum_fraction_digits = Some(0);
+    /// let fdf = FixedDecimalFormatter::try_new(&locale!("en").into(), options)
+    ///     .expect("locale should be present");
+    ///
+    /// let value = "1.5".parse().unwrap();
+    /// assert_eq!(fdf.format(&value).value(), &FixedDecimal::from(2));
+    /// ```
+    pub fn value(&self) -> &FixedDecimal {
+        &self.value
+    }
+}
+
+fn get_affixes<'l>(
+    sign: Sign,
+    symbols: &'l DecimalSymbolsV2<'l>,
+) -> Option<(&'l AffixesV1<'l>, Part)> {
+    match sign {
+        Sign::None => None,
+        Sign::Negative => Some((&symbols.minus_sign_affixes, parts::MINUS_SIGN)),
+        Sign::Positive => Some((&symbols.plus_sign_affixes, parts::PLUS_SIGN)),
+    }
+}
+
+/// Writes a prefix or suffix of the sign affixes, marking the sign with `sign_part` and the
+/// bidi marks and spaces around it with [`parts::LITERAL`].
+pub(crate) fn write_affix<W: PartsWrite + ?Sized>(
+    sink: &mut W,
+    affix: &str,
+    sign_part: Part,
+) -> fmt::Result {
+    let is_literal =
+        |c: char| c.is_whitespace() || matches!(c, '\u{200e}' | '\u{200f}' | '\u{61c}');
+    let (before, rest) = affix.split_at(affix.len() - affix.trim_start_matches(is_literal).len());
+    let (sign, after) = rest.split_at(rest.trim_end_matches(is_literal).len());
+    for (text, part) in [
+        (before, parts::LITERAL),
+        (sign, sign_part),
+        (after, parts::LITERAL),
+    ] {
+        if !text.is_empty() {
+            sink.with_part(part, |w| w.write_str(text))?;
         }
     }
+    Ok(())
 }
 
 impl<'l> Writeable for FormattedFixedDecimal<'l> {
-    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
-    where
-        W: core::fmt::Write + ?Sized,
-    {
-        let affixes = self.get_affixes();
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.prefix)?;
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let affixes = get_affixes(self.value.sign(), self.symbols);
+        if let Some((affixes, sign_part)) = affixes {
+            write_affix(sink, &affixes.prefix, sign_part)?;
         }
         let range = self.value.magnitude_range();
         let upper_magnitude = *range.end();
-        for m in range.rev() {
-            if m == -1 {
-                sink.write_str(&self.symbols.decimal_separator)?;
-            }
+        let mut range = range.rev().peekable();
+        let write_digit = |w: &mut W::SubPartsWrite, m: i16| {
             #[allow(clippy::indexing_slicing)] // digit_at in 0..=9
-            sink.write_char(self.symbols.digits[self.value.digit_at(m) as usize])?;
-            if grouper::check(
-                upper_magnitude,
-                m,
-                self.options.grouping_strategy,
-                &self.symbols.grouping_sizes,
-            ) {
-                sink.write_str(&self.symbols.grouping_separator)?;
+            w.write_char(self.symbols.digits[self.value.digit_at(m) as usize])
+        };
+        // The integer digits, in runs between the grouping separators.
+        while range.peek().map_or(false, |&m| m >= 0) {
+            let mut grouping = false;
+            sink.with_part(parts::INTEGER, |w| {
+                while let Some(m) = range.next_if(|&m| m >= 0) {
+                    write_digit(w, m)?;
+                    if grouper::check(
+                        upper_magnitude,
+                        m,
+                        self.options.grouping_strategy,
+                        &self.symbols.grouping_sizes,
+                    ) {
+                        grouping = true;
+                        break;
+                    }
+                }
+                Ok(())
+            })?;
+            if grouping {
+                sink.with_part(parts::GROUP, |w| {
+                    w.write_str(&self.symbols.grouping_separator)
+                })?;
             }
         }
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.suffix)?;
+        if range.peek().is_some() {
+            sink.with_part(parts::DECIMAL, |w| {
+                w.write_str(&self.symbols.decimal_separator)
+            })?;
+            sink.with_part(parts::FRACTION, |w| {
+                range.try_for_each(|m| write_digit(w, m))
+            })?;
+        }
+        if let Some((affixes, sign_part)) = affixes {
+            write_affix(sink, &affixes.suffix, sign_part)?;
         }
         Ok(())
     }
@@ -65,11 +257,57 @@ impl<'l> Writeable for FormattedFixedDecimal<'l> {
 
 writeable::impl_display_with_writeable!(FormattedFixedDecimal<'_>);
 
+/// An intermediate structure returned by
+/// [`FixedDecimalFormatter::format_extended`](crate::FixedDecimalFormatter::format_extended).
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+///
+/// The [`Writeable`] is annotated with the [`parts`] of the number.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedExtendedDecimal<'l> {
+    pub(crate) value: &'l ExtendedDecimal,
+    pub(crate) options: &'l FixedDecimalFormatterOptions,
+    pub(crate) symbols: &'l DecimalSymbolsV2<'l>,
+}
+
+impl<'l> Writeable for FormattedExtendedDecimal<'l> {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self.value {
+            ExtendedDecimal::Finite(value) => FormattedFixedDecimal {
+                value: rounding::round(self.options, value),
+                options: self.options,
+                symbols: self.symbols,
+            }
+            .write_to_parts(sink),
+            ExtendedDecimal::Infinity(sign) => {
+                let sign = match self.options.sign_display {
+                    Some(sign_display) => ExtendedDecimal::Infinity(*sign)
+                        .with_sign_display(sign_display)
+                        .sign(),
+                    None => *sign,
+                };
+                let affixes = get_affixes(sign, self.symbols);
+                if let Some((affixes, sign_part)) = affixes {
+                    write_affix(sink, &affixes.prefix, sign_part)?;
+                }
+                sink.with_part(parts::INFINITY, |w| w.write_str(&self.symbols.infinity))?;
+                if let Some((affixes, sign_part)) = affixes {
+                    write_affix(sink, &affixes.suffix, sign_part)?;
+                }
+                Ok(())
+            }
+            ExtendedDecimal::NaN => sink.with_part(parts::NAN, |w| w.write_str(&self.symbols.nan)),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedExtendedDecimal<'_>);
+
 #[cfg(test)]
 mod tests {
     use icu_locid::locale;
-    use writeable::assert_writeable_eq;
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
 
+    use super::parts;
     use crate::FixedDecimalFormatter;
 
     #[test]
@@ -79,4 +317,71 @@ mod tests {
         let fd = "12345.67".parse().unwrap();
         assert_writeable_eq!(fmt.format(&fd), "12,345.67");
     }
+
+    #[test]
+    pub fn test_extended() {
+        use fixed_decimal::{ExtendedDecimal, Sign, SignDisplay};
+
+        let locale = locale!("en").into();
+        let fmt = FixedDecimalFormatter::try_new(&locale, Default::default()).unwrap();
+
+        let cases = [
+            ("1234.5", "1,234.5"),
+            ("-0", "-0"),
+            ("inf", "∞"),
+            ("-inf", "-∞"),
+            ("+inf", "+∞"),
+            ("NaN", "NaN"),
+        ];
+        for (input, expected) in cases {
+            let decimal: ExtendedDecimal = input.parse().unwrap();
+            assert_writeable_eq!(fmt.format_extended(&decimal), expected, "{input}");
+        }
+
+        let decimal = ExtendedDecimal::Infinity(Sign::None).with_sign_display(SignDisplay::Always);
+        assert_eq!(fmt.format_extended_to_string(&decimal), "+∞");
+    }
+
+    #[test]
+    pub fn test_parts() {
+        let locale = locale!("ar-EG").into();
+        let fmt = FixedDecimalFormatter::try_new(&locale, Default::default()).unwrap();
+        let fd = "-1234.5".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fmt.format(&fd),
+            "\u{61c}-١٬٢٣٤٫٥",
+            [
+                (0, 2, parts::LITERAL),
+                (2, 3, parts::MINUS_SIGN),
+                (3, 5, parts::INTEGER),
+                (5, 7, parts::GROUP),
+                (7, 13, parts::INTEGER),
+                (13, 15, parts::DECIMAL),
+                (15, 17, parts::FRACTION),
+            ]
+        );
+
+        let locale = locale!("en").into();
+        let fmt = FixedDecimalFormatter::try_new(&locale, Default::default()).unwrap();
+        let fd = "+0.05".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fmt.format(&fd),
+            "+0.05",
+            [
+                (0, 1, parts::PLUS_SIGN),
+                (1, 2, parts::INTEGER),
+                (2, 3, parts::DECIMAL),
+                (3, 5, parts::FRACTION),
+            ]
+        );
+
+        let decimal = "-inf".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fmt.format_extended(&decimal),
+            "-∞",
+            [(0, 1, parts::MINUS_SIGN), (1, 4, parts::INFINITY)]
+        );
+        let decimal = "NaN".parse().unwrap();
+        assert_writeable_parts_eq!(fmt.format_extended(&decimal), "NaN", [(0, 3, parts::NAN)]);
+    }
 }
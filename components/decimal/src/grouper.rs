@@ -155,8 +155,8 @@ fn test_grouper() {
     for cas in &cases {
         for i in 0..4 {
             let dec = FixedDecimal::from(1).multiplied_pow10((i as i16) + 3);
-            let provider = AnyPayloadProvider::from_owned::<DecimalSymbolsV1Marker>(
-                crate::provider::DecimalSymbolsV1 {
+            let provider = AnyPayloadProvider::from_owned::<DecimalSymbolsV2Marker>(
+                crate::provider::DecimalSymbolsV2 {
                     grouping_sizes: cas.sizes,
                     ..Default::default()
                 },
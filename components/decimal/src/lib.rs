@@ -9,6 +9,9 @@
 //! This module is published as its own crate ([`icu_decimal`](https://docs.rs/icu_decimal/latest/icu_decimal/))
 //! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
 //!
+//! Numbers can also be formatted in scientific or engineering notation with
+//! [`ScientificDecimalFormatter`], and parsed back with [`FixedDecimalParser`].
+//!
 //! Support for currencies, measurement units, and compact notation is planned. To track progress,
 //! follow [icu4x#275](https://github.com/unicode-org/icu4x/issues/275).
 //!
@@ -72,6 +75,27 @@
 //! assert_writeable_eq!(fdf.format(&fixed_decimal), "๑,๐๐๐,๐๐๗");
 //! ```
 //!
+//! Algorithmic numbering systems, such as `roman`, have no digits, so the default numbering
+//! system of the locale is used instead. Numbers can be formatted in these numbering systems
+//! with the `RuleBasedNumberFormatter` of the experimental `icu_experimental` crate.
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu::decimal::FixedDecimalFormatter;
+//! use icu::locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let fdf = FixedDecimalFormatter::try_new(
+//!     &locale!("en-u-nu-roman").into(),
+//!     Default::default(),
+//! )
+//! .expect("locale should be present");
+//!
+//! let fixed_decimal = FixedDecimal::from(1999);
+//!
+//! assert_writeable_eq!(fdf.format(&fixed_decimal), "1,999");
+//! ```
+//!
 //! [`FixedDecimalFormatter`]: FixedDecimalFormatter
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
@@ -96,15 +120,26 @@ mod error;
 mod format;
 mod grouper;
 pub mod options;
+mod parser;
 pub mod provider;
+mod rounding;
+mod scientific;
 
 pub use error::DecimalError;
+pub use error::DecimalParseError;
+pub use format::parts;
+pub use format::FormattedExtendedDecimal;
 pub use format::FormattedFixedDecimal;
+pub use parser::FixedDecimalParser;
+pub use parser::ParsedFixedDecimal;
+pub use scientific::FormattedScientificDecimal;
+pub use scientific::ScientificDecimalFormatter;
 
 #[doc(no_inline)]
 pub use DecimalError as Error;
 
 use alloc::string::String;
+use fixed_decimal::ExtendedDecimal;
 use fixed_decimal::FixedDecimal;
 use icu_provider::prelude::*;
 use writeable::Writeable;
@@ -116,6 +151,8 @@ use writeable::Writeable;
 /// 1. Rendering in the local numbering system
 /// 2. Locale-sensitive grouping separator positions
 /// 3. Locale-sensitive plus and minus signs
+/// 4. Locale-sensitive symbols for infinity and NaN, when formatting an [`ExtendedDecimal`]
+/// 5. Rounding and padding to a number of fraction or significant digits
 ///
 /// Read more about the options in the [`options`] module.
 ///
@@ -123,7 +160,7 @@ use writeable::Writeable;
 #[derive(Debug)]
 pub struct FixedDecimalFormatter {
     options: options::FixedDecimalFormatterOptions,
-    symbols: DataPayload<provider::DecimalSymbolsV1Marker>,
+    symbols: DataPayload<provider::DecimalSymbolsV2Marker>,
 }
 
 impl FixedDecimalFormatter {
@@ -133,13 +170,17 @@ impl FixedDecimalFormatter {
         error: DecimalError,
         /// Creates a new [`FixedDecimalFormatter`] from compiled locale data and an options bag.
         ///
+        /// The digits follow the `-u-nu` keyword of the locale if it names a numbering system
+        /// with decimal digits. For algorithmic numbering systems, such as `roman`, the default
+        /// numbering system of the locale is used; see the crate-level documentation.
+        ///
         /// ✨ *Enabled with the `compiled_data` Cargo feature.*
         ///
         /// [📚 Help choosing a constructor](icu_provider::constructors)
     );
 
     #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
-    pub fn try_new_unstable<D: DataProvider<provider::DecimalSymbolsV1Marker> + ?Sized>(
+    pub fn try_new_unstable<D: DataProvider<provider::DecimalSymbolsV2Marker> + ?Sized>(
         provider: &D,
         locale: &DataLocale,
         options: options::FixedDecimalFormatterOptions,
@@ -154,9 +195,39 @@ impl FixedDecimalFormatter {
     }
 
     /// Formats a [`FixedDecimal`], returning a [`FormattedFixedDecimal`].
+    ///
+    /// The [`Writeable`] is annotated with the [`parts`] of the number, such as
+    /// [`parts::INTEGER`], [`parts::GROUP`] and [`parts::FRACTION`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::parts;
+    /// use icu::decimal::FixedDecimalFormatter;
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_parts_eq;
+    ///
+    /// let fdf = FixedDecimalFormatter::try_new(&locale!("en").into(), Default::default())
+    ///     .expect("locale should be present");
+    ///
+    /// let fixed_decimal = "-12345.67".parse().unwrap();
+    ///
+    /// assert_writeable_parts_eq!(
+    ///     fdf.format(&fixed_decimal),
+    ///     "-12,345.67",
+    ///     [
+    ///         (0, 1, parts::MINUS_SIGN),
+    ///         (1, 3, parts::INTEGER),
+    ///         (3, 4, parts::GROUP),
+    ///         (4, 7, parts::INTEGER),
+    ///         (7, 8, parts::DECIMAL),
+    ///         (8, 10, parts::FRACTION),
+    ///     ]
+    /// );
+    /// ```
     pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedFixedDecimal<'l> {
         FormattedFixedDecimal {
-            value,
+            value: rounding::round(&self.options, value),
             options: &self.options,
             symbols: self.symbols.get(),
         }
@@ -166,4 +237,46 @@ impl FixedDecimalFormatter {
     pub fn format_to_string(&self, value: &FixedDecimal) -> String {
         self.format(value).write_to_string().into_owned()
     }
+
+    /// Formats an [`ExtendedDecimal`], which may be infinite or NaN, returning a
+    /// [`FormattedExtendedDecimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ExtendedDecimal;
+    /// use fixed_decimal::Sign;
+    /// use icu::decimal::FixedDecimalFormatter;
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let fdf = FixedDecimalFormatter::try_new(
+    ///     &locale!("ar-EG").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// let decimal = ExtendedDecimal::Infinity(Sign::Negative);
+    /// assert_writeable_eq!(fdf.format_extended(&decimal), "\u{61c}-∞");
+    ///
+    /// assert_writeable_eq!(
+    ///     fdf.format_extended(&ExtendedDecimal::NaN),
+    ///     "ليس\u{a0}رقم"
+    /// );
+    /// ```
+    pub fn format_extended<'l>(
+        &'l self,
+        value: &'l ExtendedDecimal,
+    ) -> FormattedExtendedDecimal<'l> {
+        FormattedExtendedDecimal {
+            value,
+            options: &self.options,
+            symbols: self.symbols.get(),
+        }
+    }
+
+    /// Formats an [`ExtendedDecimal`], which may be infinite or NaN, returning a [`String`].
+    pub fn format_extended_to_string(&self, value: &ExtendedDecimal) -> String {
+        self.format_extended(value).write_to_string().into_owned()
+    }
 }
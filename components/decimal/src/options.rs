@@ -4,18 +4,111 @@
 
 //! Options for [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
 
+pub use fixed_decimal::RoundingIncrement;
+pub use fixed_decimal::SignDisplay;
+
 /// A bag of options defining how numbers will be formatted by
 /// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
+///
+/// The digit options follow the semantics of the corresponding
+/// [ECMA-402 options](https://tc39.es/ecma402/#sec-setnfdigitoptions). They are all unset by
+/// default, in which case the digits of the [`FixedDecimal`](fixed_decimal::FixedDecimal) are
+/// rendered as they are.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::Locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.minimum_fraction_digits = Some(2);
+/// options.maximum_fraction_digits = Some(2);
+/// let fdf = FixedDecimalFormatter::try_new(&Locale::UND.into(), options)
+///     .expect("locale should be present");
+///
+/// let value = "1234.5".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "1,234.50");
+/// let value = "0.125".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "0.13");
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
 #[non_exhaustive]
 pub struct FixedDecimalFormatterOptions {
     /// When to render grouping separators.
     pub grouping_strategy: GroupingStrategy,
+
+    /// The minimum number of integer digits to render, padding with zeros if necessary.
+    pub minimum_integer_digits: Option<u8>,
+
+    /// The minimum number of fraction digits to render, padding with zeros if necessary.
+    pub minimum_fraction_digits: Option<u8>,
+
+    /// The maximum number of fraction digits to render, rounding if necessary.
+    ///
+    /// Trailing zeros beyond [`minimum_fraction_digits`](Self::minimum_fraction_digits)
+    /// are removed.
+    pub maximum_fraction_digits: Option<u8>,
+
+    /// The minimum number of significant digits to render, padding with zeros if necessary.
+    pub minimum_significant_digits: Option<u8>,
+
+    /// The maximum number of significant digits to render, rounding if necessary.
+    ///
+    /// Trailing zeros beyond [`minimum_significant_digits`](Self::minimum_significant_digits)
+    /// are removed.
+    pub maximum_significant_digits: Option<u8>,
+
+    /// How to resolve conflicts between the fraction digit and significant digit options.
+    pub rounding_priority: RoundingPriority,
+
+    /// The increment to which the last fraction digit is rounded.
+    ///
+    /// Only applies when rounding to fraction digits.
+    pub rounding_increment: RoundingIncrement,
+
+    /// How to round when digits need to be removed.
+    pub rounding_mode: RoundingMode,
+
+    /// When to render the sign of the number.
+    ///
+    /// The sign is chosen after rounding, so that a number rounded to zero is treated as zero.
+    /// When unset, the sign of the [`FixedDecimal`](fixed_decimal::FixedDecimal) is rendered
+    /// as it is.
+    ///
+    /// There is no accounting style, such as "(1,234.50)": CLDR only defines accounting
+    /// patterns for currency amounts, so it is available as `CurrencySign::Accounting` of the
+    /// experimental `CurrencyFormatter` instead. Like ICU, plain numbers use the minus sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_decimal::options;
+    /// use icu_decimal::FixedDecimalFormatter;
+    /// use icu_locid::Locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+    /// options.sign_display = Some(options::SignDisplay::ExceptZero);
+    /// let fdf = FixedDecimalFormatter::try_new(&Locale::UND.into(), options)
+    ///     .expect("locale should be present");
+    ///
+    /// let value = "1234.5".parse().unwrap();
+    /// assert_writeable_eq!(fdf.format(&value), "+1,234.5");
+    /// let value = "-0".parse().unwrap();
+    /// assert_writeable_eq!(fdf.format(&value), "0");
+    /// ```
+    pub sign_display: Option<SignDisplay>,
 }
 
 impl From<GroupingStrategy> for FixedDecimalFormatterOptions {
     fn from(grouping_strategy: GroupingStrategy) -> Self {
-        Self { grouping_strategy }
+        Self {
+            grouping_strategy,
+            ..Default::default()
+        }
     }
 }
 
@@ -67,3 +160,256 @@ impl Default for GroupingStrategy {
         Self::Auto
     }
 }
+
+/// Configuration for whether the fraction digit or the significant digit options take
+/// precedence when both are set.
+///
+/// When set to anything other than [`RoundingPriority::Auto`], the unset fraction and significant
+/// digit options take their ECMA-402 default values: 1 to 21 significant digits, and 0 to 3
+/// fraction digits.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::Locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.maximum_fraction_digits = Some(1);
+/// options.maximum_significant_digits = Some(2);
+///
+/// options.rounding_priority = options::RoundingPriority::Auto;
+/// let fdf = FixedDecimalFormatter::try_new(&Locale::UND.into(), options)
+///     .expect("locale should be present");
+/// let value = "4.321".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "4.3");
+/// let value = "0.04321".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "0.043");
+///
+/// options.rounding_priority = options::RoundingPriority::LessPrecision;
+/// let fdf = FixedDecimalFormatter::try_new(&Locale::UND.into(), options)
+///     .expect("locale should be present");
+/// let value = "4.321".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "4.3");
+/// let value = "0.04321".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "0");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum RoundingPriority {
+    /// Use the significant digit options if any of them are set, and the fraction digit
+    /// options otherwise.
+    #[default]
+    Auto,
+
+    /// Use whichever of the fraction digit and significant digit options results in more
+    /// digits being kept.
+    MorePrecision,
+
+    /// Use whichever of the fraction digit and significant digit options results in fewer
+    /// digits being kept.
+    LessPrecision,
+}
+
+/// Configuration for how to round a number when digits need to be removed.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::Locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.maximum_fraction_digits = Some(0);
+///
+/// options.rounding_mode = options::RoundingMode::HalfEven;
+/// let fdf = FixedDecimalFormatter::try_new(&Locale::UND.into(), options)
+///     .expect("locale should be present");
+/// let value = "2.5".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "2");
+///
+/// options.rounding_mode = options::RoundingMode::Ceil;
+/// let fdf = FixedDecimalFormatter::try_new(&Locale::UND.into(), options)
+///     .expect("locale should be present");
+/// let value = "2.1".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "3");
+/// let value = "-2.9".parse().unwrap();
+/// assert_writeable_eq!(fdf.format(&value), "-2");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum RoundingMode {
+    /// Round towards positive infinity.
+    Ceil,
+
+    /// Round towards negative infinity.
+    Floor,
+
+    /// Round away from zero.
+    Expand,
+
+    /// Round towards zero.
+    Trunc,
+
+    /// Round to the nearest value, resolving ties towards positive infinity.
+    HalfCeil,
+
+    /// Round to the nearest value, resolving ties towards negative infinity.
+    HalfFloor,
+
+    /// Round to the nearest value, resolving ties away from zero.
+    #[default]
+    HalfExpand,
+
+    /// Round to the nearest value, resolving ties towards zero.
+    HalfTrunc,
+
+    /// Round to the nearest value, resolving ties towards the even neighbor.
+    HalfEven,
+}
+
+/// A bag of options defining how numbers will be formatted by
+/// [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[non_exhaustive]
+pub struct ScientificDecimalFormatterOptions {
+    /// Options for rendering the mantissa, such as the number of fraction or significant digits.
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+
+    /// Which powers of ten the exponent may take.
+    pub notation: ScientificNotation,
+
+    /// How to render the exponent.
+    pub exponent_style: ExponentStyle,
+}
+
+impl From<FixedDecimalFormatterOptions> for ScientificDecimalFormatterOptions {
+    fn from(fixed_decimal_formatter_options: FixedDecimalFormatterOptions) -> Self {
+        Self {
+            fixed_decimal_formatter_options,
+            ..Default::default()
+        }
+    }
+}
+
+/// Configuration for which powers of ten a number in scientific notation is expressed with.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::ScientificDecimalFormatter;
+/// use icu_locid::Locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::ScientificDecimalFormatterOptions = Default::default();
+/// options.notation = options::ScientificNotation::Engineering;
+/// let sdf = ScientificDecimalFormatter::try_new(&Locale::UND.into(), options)
+///     .expect("locale should be present");
+///
+/// assert_writeable_eq!(sdf.format(&"12345".parse().unwrap()), "12.345E3");
+/// assert_writeable_eq!(sdf.format(&"0.0012".parse().unwrap()), "1.2E-3");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ScientificNotation {
+    /// Render the number with a single nonzero integer digit, such as 1.2345E4.
+    #[default]
+    Scientific,
+
+    /// Render the number with an exponent that is a multiple of three, and one to three
+    /// integer digits, such as 12.345E3.
+    Engineering,
+}
+
+/// Configuration for how to render the exponent of a number in scientific notation.
+///
+/// # Examples
+///
+/// ```
+/// use icu_decimal::options;
+/// use icu_decimal::ScientificDecimalFormatter;
+/// use icu_locid::Locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::ScientificDecimalFormatterOptions = Default::default();
+/// options.exponent_style = options::ExponentStyle::Superscript;
+/// let sdf = ScientificDecimalFormatter::try_new(&Locale::UND.into(), options)
+///     .expect("locale should be present");
+///
+/// assert_writeable_eq!(sdf.format(&"12345".parse().unwrap()), "1.2345×10⁴");
+/// assert_writeable_eq!(sdf.format(&"0.0012".parse().unwrap()), "1.2×10⁻³");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ExponentStyle {
+    /// Separate the exponent from the mantissa with the locale's exponential symbol, such as
+    /// "E" in 1.2E3.
+    #[default]
+    Exponential,
+
+    /// Multiply the mantissa with a power of ten, rendering the exponent with superscript
+    /// digits, such as 1.2×10³.
+    ///
+    /// The exponent always uses the superscript forms of the Latin digits.
+    Superscript,
+}
+
+/// A bag of options defining how strings are parsed by
+/// [`FixedDecimalParser`](crate::FixedDecimalParser).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[non_exhaustive]
+pub struct FixedDecimalParserOptions {
+    /// How closely the input has to follow the symbols of the locale.
+    pub strictness: ParseStrictness,
+}
+
+impl From<ParseStrictness> for FixedDecimalParserOptions {
+    fn from(strictness: ParseStrictness) -> Self {
+        Self { strictness }
+    }
+}
+
+/// Configuration for how closely a string has to follow the symbols of the locale to be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalParser;
+/// use icu_locid::locale;
+///
+/// let lenient = FixedDecimalParser::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+/// let strict = FixedDecimalParser::try_new(
+///     &locale!("en").into(),
+///     options::ParseStrictness::Strict.into(),
+/// )
+/// .expect("locale should be present");
+///
+/// assert_eq!(lenient.parse(" 12,34 ").unwrap().value, FixedDecimal::from(1234));
+/// assert!(strict.parse(" 12,34 ").is_err());
+/// assert_eq!(strict.parse("-1,234").unwrap().value, FixedDecimal::from(-1234));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ParseStrictness {
+    /// Parse the number at the start of the input, after any whitespace, and stop at the first
+    /// character that cannot be part of it.
+    ///
+    /// Grouping separators may be placed anywhere in the integer part, and common variants of
+    /// the symbols are accepted: ASCII digits and signs, Unicode minus signs, any kind of space
+    /// as the grouping separator of locales that group with a space, and parentheses around
+    /// negative numbers.
+    #[default]
+    Lenient,
+
+    /// Only accept input that consists of exactly one number written with the symbols of the
+    /// locale, with grouping separators at the positions given by its grouping sizes.
+    Strict,
+}
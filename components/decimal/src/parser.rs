@@ -0,0 +1,478 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing numbers written with the symbols of a locale.
+
+use crate::options::*;
+use crate::provider::*;
+use crate::{DecimalError, DecimalParseError};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+use core::str::FromStr;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_provider::prelude::*;
+
+/// A parser for numbers written with the symbols of a locale, such as "1.234,56" in Spanish
+/// or "١٢٣" in Arabic, producing a [`FixedDecimal`].
+///
+/// [`FixedDecimalParser`] is the inverse of [`FixedDecimalFormatter`](crate::FixedDecimalFormatter),
+/// and uses the same digits, separators and sign affixes.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu::decimal::FixedDecimalParser;
+/// use icu::locid::locale;
+///
+/// let parser = FixedDecimalParser::try_new(&locale!("es").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// let parsed = parser.parse("1.234,56").unwrap();
+/// assert_eq!(parsed.value, "1234.56".parse::<FixedDecimal>().unwrap());
+/// assert_eq!(parsed.span, 0..8);
+/// ```
+///
+/// In lenient mode, parsing stops at the end of the number, and the span tells where it ends:
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu::decimal::FixedDecimalParser;
+/// use icu::locid::locale;
+///
+/// let parser = FixedDecimalParser::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// let parsed = parser.parse("(1,000)").unwrap();
+/// assert_eq!(parsed.value, FixedDecimal::from(-1000));
+///
+/// let parsed = parser.parse(" 12 %").unwrap();
+/// assert_eq!(parsed.value, FixedDecimal::from(12));
+/// assert_eq!(parsed.span, 1..3);
+/// ```
+#[derive(Debug)]
+pub struct FixedDecimalParser {
+    options: FixedDecimalParserOptions,
+    symbols: DataPayload<DecimalSymbolsV2Marker>,
+}
+
+/// A number returned by [`FixedDecimalParser`], along with where it was found in the input.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ParsedFixedDecimal {
+    /// The parsed number.
+    ///
+    /// Leading zeros are removed, but trailing zeros in the fraction part are kept, so that
+    /// "1.50" is parsed with two fraction digits.
+    pub value: FixedDecimal,
+
+    /// The byte range of the input that the number was parsed from, including its signs.
+    pub span: Range<usize>,
+}
+
+impl FixedDecimalParser {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: FixedDecimalParserOptions,
+        error: DecimalError,
+        /// Creates a new [`FixedDecimalParser`] from compiled locale data and an options bag.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D: DataProvider<DecimalSymbolsV2Marker> + ?Sized>(
+        provider: &D,
+        locale: &DataLocale,
+        options: FixedDecimalParserOptions,
+    ) -> Result<Self, DecimalError> {
+        let symbols = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { options, symbols })
+    }
+
+    /// Parses a number from a string.
+    ///
+    /// In [`ParseStrictness::Lenient`] mode, the number may be surrounded by whitespace and
+    /// followed by other text; in [`ParseStrictness::Strict`] mode, the whole input has to be
+    /// the number.
+    pub fn parse(&self, input: &str) -> Result<ParsedFixedDecimal, DecimalParseError> {
+        Cursor {
+            input,
+            index: 0,
+            symbols: self.symbols.get(),
+            lenient: self.options.strictness == ParseStrictness::Lenient,
+        }
+        .parse()
+    }
+}
+
+/// Minus signs accepted in lenient mode, in addition to the ones of the locale.
+const MINUS_SIGNS: [char; 4] = ['-', '\u{2212}', '\u{fe63}', '\u{ff0d}'];
+
+/// Plus signs accepted in lenient mode, in addition to the ones of the locale.
+const PLUS_SIGNS: [char; 3] = ['+', '\u{fb29}', '\u{ff0b}'];
+
+/// The zero digits of numbering systems accepted in lenient mode, in addition to the digits of
+/// the locale. The other digits follow each of them contiguously.
+const ZERO_DIGITS: [char; 7] = [
+    '0', '\u{660}', '\u{6f0}', '\u{966}', '\u{9e6}', '\u{e50}', '\u{ff10}',
+];
+
+/// Spaces accepted in lenient mode as the grouping separator of locales that group with a space.
+const GROUPING_SPACES: [char; 4] = [' ', '\u{a0}', '\u{2009}', '\u{202f}'];
+
+/// Returns whether a character is skipped around the number and its signs in lenient mode.
+fn is_ignorable(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '\u{61c}' | '\u{200e}' | '\u{200f}')
+}
+
+struct Cursor<'a> {
+    input: &'a str,
+    /// The byte index of the next character to parse.
+    index: usize,
+    symbols: &'a DecimalSymbolsV2<'a>,
+    lenient: bool,
+}
+
+impl<'a> Cursor<'a> {
+    fn rest(&self) -> &'a str {
+        self.input.get(self.index..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.index += rest.len() - rest.trim_start_matches(char::is_whitespace).len();
+    }
+
+    fn skip_ignorable(&mut self) {
+        let rest = self.rest();
+        self.index += rest.len() - rest.trim_start_matches(is_ignorable).len();
+    }
+
+    /// Consumes `symbol` if the input continues with it. Empty symbols are never consumed.
+    ///
+    /// In lenient mode, the bidi marks around the symbol are optional.
+    fn eat(&mut self, symbol: &str) -> bool {
+        let trimmed = symbol.trim_matches(is_ignorable);
+        for symbol in [symbol, trimmed] {
+            if !symbol.is_empty() && self.rest().starts_with(symbol) {
+                self.index += symbol.len();
+                return true;
+            }
+            if !self.lenient {
+                break;
+            }
+        }
+        false
+    }
+
+    /// Consumes a character if the input continues with one of `chars`.
+    fn eat_any(&mut self, chars: &[char]) -> bool {
+        match self.peek() {
+            Some(c) if chars.contains(&c) => {
+                self.index += c.len_utf8();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the value of a digit, if `c` is one.
+    fn digit_value(&self, c: char) -> Option<u8> {
+        if let Some(i) = self.symbols.digits.iter().position(|&d| d == c) {
+            return Some(i as u8);
+        }
+        if !self.lenient {
+            return None;
+        }
+        ZERO_DIGITS
+            .iter()
+            .map(|&zero| (c as u32).wrapping_sub(zero as u32))
+            .find(|&value| value < 10)
+            .map(|value| value as u8)
+    }
+
+    /// Returns whether the input at `index` starts with a digit.
+    fn is_digit_at(&self, index: usize) -> bool {
+        self.input
+            .get(index..)
+            .and_then(|s| s.chars().next())
+            .and_then(|c| self.digit_value(c))
+            .is_some()
+    }
+
+    /// Consumes a separator if it is followed by a digit, and returns whether it did.
+    fn eat_separator(&mut self, separator: &str, alternatives: &[char]) -> bool {
+        let len = if !separator.is_empty() && self.rest().starts_with(separator) {
+            separator.len()
+        } else {
+            match self.peek() {
+                Some(c) if alternatives.contains(&c) => c.len_utf8(),
+                _ => return false,
+            }
+        };
+        if self.is_digit_at(self.index + len) {
+            self.index += len;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the characters that are accepted in place of the grouping separator.
+    fn grouping_alternatives(&self) -> &'static [char] {
+        if !self.lenient {
+            return &[];
+        }
+        match self.symbols.grouping_separator.chars().next() {
+            Some(c) if GROUPING_SPACES.contains(&c) => &GROUPING_SPACES,
+            Some('\'' | '’') => &['\'', '’'],
+            _ => &[],
+        }
+    }
+
+    fn error(&self) -> DecimalParseError {
+        DecimalParseError::Syntax(self.index)
+    }
+
+    fn parse(mut self) -> Result<ParsedFixedDecimal, DecimalParseError> {
+        let symbols = self.symbols;
+        if self.lenient {
+            self.skip_whitespace();
+        }
+        let start = self.index;
+
+        // The sign before the number, and the suffix of its affixes that has to follow the number.
+        let mut sign = Sign::None;
+        let mut sign_suffix = "";
+        let mut parenthesized = false;
+        if self.eat(&symbols.minus_sign_affixes.prefix) {
+            sign = Sign::Negative;
+            sign_suffix = &*symbols.minus_sign_affixes.suffix;
+        } else if self.eat(&symbols.plus_sign_affixes.prefix) {
+            sign = Sign::Positive;
+            sign_suffix = &*symbols.plus_sign_affixes.suffix;
+        } else if self.lenient {
+            if self.eat_any(&MINUS_SIGNS) {
+                sign = Sign::Negative;
+            } else if self.eat_any(&PLUS_SIGNS) {
+                sign = Sign::Positive;
+            } else if self.eat_any(&['(']) {
+                sign = Sign::Negative;
+                parenthesized = true;
+            }
+        }
+        if self.lenient {
+            self.skip_ignorable();
+        }
+
+        // The digits of the number, as ASCII, and the sizes of the groups of its integer part.
+        let mut digits = String::new();
+        let mut groups = Vec::new();
+        let mut separators = Vec::new();
+        let mut group_size: usize = 0;
+        let grouping_alternatives = self.grouping_alternatives();
+        loop {
+            let index = self.index;
+            if let Some(value) = self.peek().and_then(|c| self.digit_value(c)) {
+                digits.push(char::from(b'0' + value));
+                group_size += 1;
+                self.index += self.peek().map_or(0, char::len_utf8);
+            } else if group_size > 0
+                && self.eat_separator(&symbols.grouping_separator, grouping_alternatives)
+            {
+                groups.push(group_size);
+                separators.push(index);
+                group_size = 0;
+            } else {
+                break;
+            }
+        }
+        groups.push(group_size);
+
+        // A number has to start with a digit, except for ".5" in lenient mode.
+        if group_size == 0 && !self.lenient {
+            return Err(self.error());
+        }
+        if !self.lenient {
+            self.check_grouping(&groups, &separators)?;
+        }
+
+        if self.eat_separator(&symbols.decimal_separator, &[]) {
+            digits.push('.');
+            while let Some(value) = self.peek().and_then(|c| self.digit_value(c)) {
+                digits.push(char::from(b'0' + value));
+                self.index += self.peek().map_or(0, char::len_utf8);
+            }
+        } else if group_size == 0 {
+            return Err(self.error());
+        }
+
+        // The sign after the number.
+        if sign == Sign::None {
+            if self.eat(&symbols.minus_sign_affixes.suffix) {
+                sign = Sign::Negative;
+            } else if self.eat(&symbols.plus_sign_affixes.suffix) {
+                sign = Sign::Positive;
+            }
+        } else if !self.eat(sign_suffix) && !self.lenient && !sign_suffix.is_empty() {
+            return Err(self.error());
+        }
+        if parenthesized {
+            self.skip_ignorable();
+            if !self.eat_any(&[')']) {
+                return Err(self.error());
+            }
+        }
+
+        if !self.lenient && self.index != self.input.len() {
+            return Err(self.error());
+        }
+
+        let mut value = FixedDecimal::from_str(&digits).map_err(|_| DecimalParseError::Limit)?;
+        value.trim_start();
+        value.set_sign(sign);
+        Ok(ParsedFixedDecimal {
+            value,
+            span: start..self.index,
+        })
+    }
+
+    /// Checks that the groups of the integer part have the sizes that the formatter uses, such
+    /// as "12,34,567" in Bangla.
+    ///
+    /// `separators` has the byte index of the grouping separator after each group but the last.
+    fn check_grouping(
+        &self,
+        groups: &[usize],
+        separators: &[usize],
+    ) -> Result<(), DecimalParseError> {
+        let sizes = &self.symbols.grouping_sizes;
+        let primary = usize::from(sizes.primary);
+        let secondary = if sizes.secondary == 0 {
+            primary
+        } else {
+            usize::from(sizes.secondary)
+        };
+        let last = groups.len() - 1;
+        for (i, &size) in groups.iter().enumerate() {
+            let valid = if i == last {
+                last == 0 || size == primary
+            } else if i == 0 {
+                // The first group may be shorter than the others.
+                size <= if last == 1 { primary } else { secondary }
+            } else {
+                size == secondary
+            };
+            if !valid {
+                let separator = separators.get(i.min(last - 1)).copied();
+                return Err(DecimalParseError::Syntax(separator.unwrap_or_default()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn test_parse() {
+        let cases = [
+            (locale!("en"), "1,234.56", "1234.56", 0..8),
+            (locale!("en"), "-0.50", "-0.50", 0..5),
+            (locale!("en"), "+7", "+7", 0..2),
+            (locale!("en"), "  \u{2212}12 kg", "-12", 2..7),
+            (locale!("en"), "(1,000)", "-1000", 0..7),
+            (locale!("en"), "( 5 )", "-5", 0..5),
+            (locale!("en"), "12 %", "12", 0..2),
+            (locale!("en"), ".5", "0.5", 0..2),
+            (locale!("en"), "007", "7", 0..3),
+            (locale!("en"), "1,2,3", "123", 0..5),
+            (locale!("en"), "1, 2", "1", 0..1),
+            (locale!("en"), "3.", "3", 0..1),
+            (locale!("es"), "1.234,56", "1234.56", 0..8),
+            (locale!("fr"), "1 234,5", "1234.5", 0..7),
+            (locale!("fr"), "1\u{a0}234,5", "1234.5", 0..8),
+            (locale!("ar-EG"), "١٢٣", "123", 0..6),
+            (locale!("ar-EG"), "\u{61c}-١٬٢٣٤٫٥", "-1234.5", 0..17),
+            (locale!("ar-EG"), "-12", "-12", 0..3),
+            (locale!("bn"), "১২,৩৪,৫৬৭", "1234567", 0..23),
+        ];
+        for (locale, input, expected, span) in cases {
+            let parser =
+                FixedDecimalParser::try_new(&(&locale).into(), Default::default()).unwrap();
+            let parsed = parser.parse(input).unwrap();
+            assert_eq!(
+                parsed.value,
+                expected.parse().unwrap(),
+                "{locale} {input:?}"
+            );
+            assert_eq!(parsed.span, span, "{locale} {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        use DecimalParseError::Syntax;
+
+        let cases = [
+            (locale!("en"), "1,234.56", Ok("1234.56")),
+            (locale!("en"), "1234", Ok("1234")),
+            (locale!("en"), "-1,234,567", Ok("-1234567")),
+            (locale!("en"), "12,34", Err(Syntax(2))),
+            (locale!("en"), "1,2345", Err(Syntax(1))),
+            (locale!("en"), "1,234,56", Err(Syntax(5))),
+            (locale!("en"), " 1", Err(Syntax(0))),
+            (locale!("en"), "1 ", Err(Syntax(1))),
+            (locale!("en"), "(1)", Err(Syntax(0))),
+            (locale!("en"), "\u{2212}1", Err(Syntax(0))),
+            (locale!("en"), ".5", Err(Syntax(0))),
+            (locale!("en"), "", Err(Syntax(0))),
+            (locale!("fr"), "1\u{202f}234,5", Ok("1234.5")),
+            (locale!("fr"), "1 234,5", Err(Syntax(1))),
+            (locale!("ar-EG"), "\u{61c}-١٢", Ok("-12")),
+            (locale!("ar-EG"), "12", Err(Syntax(0))),
+            (locale!("bn"), "১২,৩৪,৫৬৭", Ok("1234567")),
+            (locale!("bn"), "১,২৩৪,৫৬৭", Err(Syntax(13))),
+        ];
+        for (locale, input, expected) in cases {
+            let parser =
+                FixedDecimalParser::try_new(&(&locale).into(), ParseStrictness::Strict.into())
+                    .unwrap();
+            let parsed = parser.parse(input).map(|parsed| parsed.value);
+            let expected = expected.map(|expected| expected.parse().unwrap());
+            assert_eq!(parsed, expected, "{locale} {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_long_integer() {
+        // More digits than fit in a `u8` group size.
+        let input = "1".repeat(300);
+        for strictness in [ParseStrictness::Lenient, ParseStrictness::Strict] {
+            let parser =
+                FixedDecimalParser::try_new(&locale!("en").into(), strictness.into()).unwrap();
+            let parsed = parser.parse(&input).unwrap();
+            assert_eq!(parsed.value, input.parse().unwrap(), "{strictness:?}");
+            assert_eq!(parsed.span, 0..300, "{strictness:?}");
+        }
+    }
+}
@@ -39,11 +39,13 @@ const _: () = {
     }
     icu_decimal_data::make_provider!(Baked);
     icu_decimal_data::impl_decimal_symbols_v1!(Baked);
+    icu_decimal_data::impl_decimal_symbols_v2!(Baked);
+    icu_decimal_data::impl_decimal_scientific_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
 /// The latest minimum set of keys required by this component.
-pub const KEYS: &[DataKey] = &[DecimalSymbolsV1Marker::KEY];
+pub const KEYS: &[DataKey] = &[DecimalSymbolsV2Marker::KEY, ScientificSymbolsV1Marker::KEY];
 
 /// A collection of strings to affix to a decimal number.
 ///
@@ -166,3 +168,120 @@ impl Default for DecimalSymbolsV1<'static> {
         }
     }
 }
+
+/// Symbols and metadata required for formatting a [`FixedDecimal`](crate::FixedDecimal) or an
+/// [`ExtendedDecimal`](fixed_decimal::ExtendedDecimal).
+///
+/// This extends [`DecimalSymbolsV1`] with the symbols for infinity and NaN.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    DecimalSymbolsV2Marker,
+    "decimal/symbols@2",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct DecimalSymbolsV2<'data> {
+    /// Prefix and suffix to apply when a negative sign is needed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minus_sign_affixes: AffixesV1<'data>,
+
+    /// Prefix and suffix to apply when a plus sign is needed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub plus_sign_affixes: AffixesV1<'data>,
+
+    /// Character used to separate the integer and fraction parts of the number.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub decimal_separator: Cow<'data, str>,
+
+    /// Character used to separate groups in the integer part of the number.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub grouping_separator: Cow<'data, str>,
+
+    /// Settings used to determine where to place groups in the integer part of the number.
+    pub grouping_sizes: GroupingSizesV1,
+
+    /// Digit characters for the current numbering system. In most systems, these digits are
+    /// contiguous, but in some systems, such as *hanidec*, they are not contiguous.
+    pub digits: [char; 10],
+
+    /// Symbol used for infinity, to which the sign affixes are applied.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub infinity: Cow<'data, str>,
+
+    /// Symbol used for values that are not a number.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub nan: Cow<'data, str>,
+}
+
+impl Default for DecimalSymbolsV2<'static> {
+    fn default() -> Self {
+        let DecimalSymbolsV1 {
+            minus_sign_affixes,
+            plus_sign_affixes,
+            decimal_separator,
+            grouping_separator,
+            grouping_sizes,
+            digits,
+        } = DecimalSymbolsV1::default();
+        Self {
+            minus_sign_affixes,
+            plus_sign_affixes,
+            decimal_separator,
+            grouping_separator,
+            grouping_sizes,
+            digits,
+            infinity: Cow::Borrowed("∞"),
+            nan: Cow::Borrowed("NaN"),
+        }
+    }
+}
+
+/// Symbols required for formatting a number in scientific notation.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    ScientificSymbolsV1Marker,
+    "decimal/scientific@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ScientificSymbolsV1<'data> {
+    /// Symbol separating the mantissa from the exponent, such as "E" in "1.2E3".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential: Cow<'data, str>,
+
+    /// Symbol multiplying the mantissa with a power of ten when the exponent is rendered as a
+    /// superscript, such as "×" in "1.2×10³".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: Cow<'data, str>,
+}
+
+impl Default for ScientificSymbolsV1<'static> {
+    fn default() -> Self {
+        Self {
+            exponential: Cow::Borrowed("E"),
+            superscripting_exponent: Cow::Borrowed("×"),
+        }
+    }
+}
@@ -0,0 +1,310 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Algorithms to apply the digit options to a number before rendering it.
+
+use crate::options::{
+    FixedDecimalFormatterOptions, RoundingIncrement, RoundingMode, RoundingPriority,
+};
+use alloc::borrow::Cow;
+use core::cmp;
+use fixed_decimal::FixedDecimal;
+
+/// The ECMA-402 default for the maximum number of significant digits.
+const DEFAULT_MAX_SIGNIFICANT_DIGITS: u8 = 21;
+
+/// The ECMA-402 default for the maximum number of fraction digits.
+const DEFAULT_MAX_FRACTION_DIGITS: u8 = 3;
+
+/// Returns the number with the digit and sign options applied, borrowing it if they do not
+/// change it.
+pub fn round<'a>(
+    options: &FixedDecimalFormatterOptions,
+    value: &'a FixedDecimal,
+) -> Cow<'a, FixedDecimal> {
+    let significant = (
+        options.minimum_significant_digits,
+        options.maximum_significant_digits,
+    );
+    let fraction = (
+        options.minimum_fraction_digits,
+        options.maximum_fraction_digits,
+    );
+    let significant_set = significant != (None, None);
+    let fraction_set = fraction != (None, None);
+
+    if !significant_set
+        && !fraction_set
+        && options.minimum_integer_digits.is_none()
+        && options.sign_display.is_none()
+    {
+        return Cow::Borrowed(value);
+    }
+
+    let original = value;
+    let mut value = value.clone();
+    match options.rounding_priority {
+        RoundingPriority::Auto if significant_set => {
+            round_significant(&mut value, significant, options.rounding_mode);
+        }
+        RoundingPriority::Auto if fraction_set => {
+            round_fraction(
+                &mut value,
+                fraction,
+                options.rounding_mode,
+                options.rounding_increment,
+            );
+        }
+        RoundingPriority::Auto => (),
+        priority => {
+            let mut by_fraction = value.clone();
+            let fraction_magnitude = round_fraction(
+                &mut by_fraction,
+                fraction,
+                options.rounding_mode,
+                options.rounding_increment,
+            );
+            let significant_magnitude =
+                round_significant(&mut value, significant, options.rounding_mode);
+            let prefer_significant = match priority {
+                RoundingPriority::LessPrecision => significant_magnitude > fraction_magnitude,
+                _ => significant_magnitude <= fraction_magnitude,
+            };
+            if !prefer_significant {
+                value = by_fraction;
+            }
+        }
+    }
+    if let Some(minimum_integer_digits) = options.minimum_integer_digits {
+        value.pad_start(minimum_integer_digits as i16);
+    }
+    if let Some(sign_display) = options.sign_display {
+        value.apply_sign_display(sign_display);
+    }
+    if value == *original {
+        Cow::Borrowed(original)
+    } else {
+        Cow::Owned(value)
+    }
+}
+
+/// Rounds to the given minimum and maximum number of fraction digits, returning the
+/// magnitude that was rounded to.
+fn round_fraction(
+    value: &mut FixedDecimal,
+    (minimum, maximum): (Option<u8>, Option<u8>),
+    mode: RoundingMode,
+    increment: RoundingIncrement,
+) -> i16 {
+    let minimum = minimum.unwrap_or(0);
+    let maximum = maximum.unwrap_or(cmp::max(minimum, DEFAULT_MAX_FRACTION_DIGITS));
+    let position = -(cmp::max(minimum, maximum) as i16);
+    round_to_increment(value, position, mode, increment);
+    value.trim_end();
+    value.pad_end(-(minimum as i16));
+    position
+}
+
+/// Rounds to the given minimum and maximum number of significant digits, returning the
+/// magnitude that was rounded to.
+fn round_significant(
+    value: &mut FixedDecimal,
+    (minimum, maximum): (Option<u8>, Option<u8>),
+    mode: RoundingMode,
+) -> i16 {
+    let minimum = cmp::max(minimum.unwrap_or(1), 1);
+    let maximum = maximum.unwrap_or(DEFAULT_MAX_SIGNIFICANT_DIGITS);
+    let position = value.nonzero_magnitude_start() - cmp::max(minimum, maximum) as i16 + 1;
+    round_to_increment(value, position, mode, RoundingIncrement::MultiplesOf1);
+    value.trim_end();
+    // Rounding may have carried into a new leading digit.
+    value.pad_end(value.nonzero_magnitude_start() - minimum as i16 + 1);
+    position
+}
+
+fn round_to_increment(
+    value: &mut FixedDecimal,
+    position: i16,
+    mode: RoundingMode,
+    increment: RoundingIncrement,
+) {
+    match mode {
+        RoundingMode::Ceil => value.ceil_to_increment(position, increment),
+        RoundingMode::Floor => value.floor_to_increment(position, increment),
+        RoundingMode::Expand => value.expand_to_increment(position, increment),
+        RoundingMode::Trunc => value.trunc_to_increment(position, increment),
+        RoundingMode::HalfCeil => value.half_ceil_to_increment(position, increment),
+        RoundingMode::HalfFloor => value.half_floor_to_increment(position, increment),
+        RoundingMode::HalfExpand => value.half_expand_to_increment(position, increment),
+        RoundingMode::HalfTrunc => value.half_trunc_to_increment(position, increment),
+        RoundingMode::HalfEven => value.half_even_to_increment(position, increment),
+    }
+}
+
+#[test]
+fn test_round() {
+    use crate::options::*;
+
+    #[derive(Debug)]
+    struct TestCase {
+        input: &'static str,
+        options: FixedDecimalFormatterOptions,
+        expected: &'static str,
+    }
+    let options = |f: fn(&mut FixedDecimalFormatterOptions)| {
+        let mut options = FixedDecimalFormatterOptions::default();
+        f(&mut options);
+        options
+    };
+    let cases = [
+        TestCase {
+            input: "1.2300",
+            options: Default::default(),
+            expected: "1.2300",
+        },
+        TestCase {
+            input: "1.2",
+            options: options(|o| o.minimum_integer_digits = Some(3)),
+            expected: "001.2",
+        },
+        TestCase {
+            input: "1.2300",
+            options: options(|o| o.maximum_fraction_digits = Some(3)),
+            expected: "1.23",
+        },
+        TestCase {
+            input: "1.2",
+            options: options(|o| o.minimum_fraction_digits = Some(2)),
+            expected: "1.20",
+        },
+        TestCase {
+            input: "1.23456",
+            options: options(|o| o.minimum_fraction_digits = Some(2)),
+            expected: "1.235",
+        },
+        TestCase {
+            input: "-0.001",
+            options: options(|o| o.maximum_fraction_digits = Some(2)),
+            expected: "-0",
+        },
+        TestCase {
+            input: "1.27",
+            options: options(|o| {
+                o.minimum_fraction_digits = Some(1);
+                o.maximum_fraction_digits = Some(1);
+                o.rounding_increment = RoundingIncrement::MultiplesOf5;
+            }),
+            expected: "1.5",
+        },
+        TestCase {
+            input: "1.27",
+            options: options(|o| {
+                o.maximum_fraction_digits = Some(1);
+                o.rounding_mode = RoundingMode::Trunc;
+            }),
+            expected: "1.2",
+        },
+        TestCase {
+            input: "98765",
+            options: options(|o| o.maximum_significant_digits = Some(2)),
+            expected: "99000",
+        },
+        TestCase {
+            input: "9.96",
+            options: options(|o| o.maximum_significant_digits = Some(2)),
+            expected: "10",
+        },
+        TestCase {
+            input: "9.96",
+            options: options(|o| {
+                o.minimum_significant_digits = Some(3);
+                o.maximum_significant_digits = Some(3);
+            }),
+            expected: "9.96",
+        },
+        TestCase {
+            input: "5",
+            options: options(|o| o.minimum_significant_digits = Some(3)),
+            expected: "5.00",
+        },
+        TestCase {
+            input: "0",
+            options: options(|o| o.minimum_significant_digits = Some(2)),
+            expected: "0.0",
+        },
+        TestCase {
+            input: "1.23456",
+            options: options(|o| {
+                o.maximum_fraction_digits = Some(1);
+                o.maximum_significant_digits = Some(3);
+            }),
+            expected: "1.23",
+        },
+        TestCase {
+            input: "1.23456",
+            options: options(|o| {
+                o.maximum_fraction_digits = Some(1);
+                o.maximum_significant_digits = Some(3);
+                o.rounding_priority = RoundingPriority::MorePrecision;
+            }),
+            expected: "1.23",
+        },
+        TestCase {
+            input: "1.23456",
+            options: options(|o| {
+                o.maximum_fraction_digits = Some(1);
+                o.maximum_significant_digits = Some(3);
+                o.rounding_priority = RoundingPriority::LessPrecision;
+            }),
+            expected: "1.2",
+        },
+        TestCase {
+            input: "123.456",
+            options: options(|o| {
+                o.maximum_fraction_digits = Some(1);
+                o.maximum_significant_digits = Some(2);
+                o.rounding_priority = RoundingPriority::MorePrecision;
+            }),
+            expected: "123.5",
+        },
+        TestCase {
+            input: "1.2",
+            options: options(|o| o.sign_display = Some(SignDisplay::Always)),
+            expected: "+1.2",
+        },
+        TestCase {
+            input: "+1.2",
+            options: options(|o| o.sign_display = Some(SignDisplay::Auto)),
+            expected: "1.2",
+        },
+        TestCase {
+            input: "-1.2",
+            options: options(|o| o.sign_display = Some(SignDisplay::Never)),
+            expected: "1.2",
+        },
+        TestCase {
+            input: "-0.001",
+            options: options(|o| {
+                o.maximum_fraction_digits = Some(2);
+                o.sign_display = Some(SignDisplay::Negative);
+            }),
+            expected: "0",
+        },
+        TestCase {
+            input: "0",
+            options: options(|o| o.sign_display = Some(SignDisplay::ExceptZero)),
+            expected: "0",
+        },
+    ];
+    for cas in &cases {
+        let input: FixedDecimal = cas.input.parse().unwrap();
+        let actual = round(&cas.options, &input);
+        assert_eq!(cas.expected, actual.to_string(), "{cas:?}");
+        assert_eq!(
+            matches!(actual, Cow::Borrowed(_)),
+            cas.input == cas.expected,
+            "{cas:?}"
+        );
+    }
+}
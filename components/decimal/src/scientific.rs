@@ -0,0 +1,439 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting numbers in scientific and engineering notation.
+
+use crate::format::{self, parts, FormattedFixedDecimal};
+use crate::options::*;
+use crate::provider::*;
+use crate::rounding;
+use crate::{DecimalError, FixedDecimalFormatter};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt::{self, Write};
+use fixed_decimal::{FixedDecimal, ScientificDecimal, Sign};
+use icu_provider::prelude::*;
+use writeable::{Part, PartsWrite, Writeable};
+
+/// A formatter for [`FixedDecimal`] and [`ScientificDecimal`], rendering numbers in scientific
+/// or engineering notation, such as "1.2345E4" or "1.2345×10⁴".
+///
+/// The mantissa is rendered like [`FixedDecimalFormatter`] would, and is rounded according to
+/// the [`FixedDecimalFormatterOptions`] in the options bag.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::ScientificDecimalFormatterOptions = Default::default();
+/// options.fixed_decimal_formatter_options.maximum_fraction_digits = Some(2);
+/// let sdf = ScientificDecimalFormatter::try_new(&locale!("fr").into(), options)
+///     .expect("locale should be present");
+///
+/// assert_writeable_eq!(sdf.format(&"-123456".parse().unwrap()), "-1,23E5");
+/// ```
+#[derive(Debug)]
+pub struct ScientificDecimalFormatter {
+    options: ScientificDecimalFormatterOptions,
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    symbols: DataPayload<ScientificSymbolsV1Marker>,
+}
+
+impl ScientificDecimalFormatter {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: ScientificDecimalFormatterOptions,
+        error: DecimalError,
+        /// Creates a new [`ScientificDecimalFormatter`] from compiled locale data and an options bag.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: ScientificDecimalFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<DecimalSymbolsV2Marker> + DataProvider<ScientificSymbolsV1Marker> + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            locale,
+            options.fixed_decimal_formatter_options,
+        )?;
+        let symbols = DataProvider::<ScientificSymbolsV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        Ok(Self {
+            options,
+            fixed_decimal_formatter,
+            symbols,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] in scientific notation, returning a
+    /// [`FormattedScientificDecimal`].
+    ///
+    /// If rounding the mantissa carries into a new digit, the exponent is adjusted, so that
+    /// 9.99 with two significant digits is rendered as "1.0E1" rather than "10E0".
+    pub fn format(&self, value: &FixedDecimal) -> FormattedScientificDecimal<'_> {
+        let step = match self.options.notation {
+            ScientificNotation::Scientific => 1,
+            ScientificNotation::Engineering => 3,
+        };
+        // The exponent may be outside of the magnitude range of `FixedDecimal`, such as -32769
+        // in engineering notation for a value at magnitude -32768.
+        let mut exponent = i32::from(value.nonzero_magnitude_start()).div_euclid(step) * step;
+        let mut mantissa = self.round_mantissa(value, exponent);
+        if i32::from(mantissa.nonzero_magnitude_start()) >= step {
+            exponent += step;
+            mantissa = self.round_mantissa(value, exponent);
+        }
+        self.formatted(mantissa, FixedDecimal::from(exponent))
+    }
+
+    /// Formats a [`FixedDecimal`] in scientific notation, returning a [`String`].
+    pub fn format_to_string(&self, value: &FixedDecimal) -> String {
+        self.format(value).write_to_string().into_owned()
+    }
+
+    /// Formats a [`ScientificDecimal`], returning a [`FormattedScientificDecimal`].
+    ///
+    /// The significand is rounded according to the options, but the exponent is rendered as
+    /// it is, regardless of the [`ScientificNotation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ScientificDecimal;
+    /// use icu::decimal::ScientificDecimalFormatter;
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let sdf = ScientificDecimalFormatter::try_new(
+    ///     &locale!("ar-EG").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// let decimal = "12.5e-4".parse::<ScientificDecimal>().unwrap();
+    /// assert_writeable_eq!(sdf.format_scientific(&decimal), "١٢٫٥أس\u{61c}-٤");
+    /// ```
+    pub fn format_scientific(&self, value: &ScientificDecimal) -> FormattedScientificDecimal<'_> {
+        self.formatted(
+            rounding::round(
+                &self.options.fixed_decimal_formatter_options,
+                value.significand(),
+            )
+            .into_owned(),
+            value.exponent().clone().into(),
+        )
+    }
+
+    /// Formats a [`ScientificDecimal`], returning a [`String`].
+    pub fn format_scientific_to_string(&self, value: &ScientificDecimal) -> String {
+        self.format_scientific(value).write_to_string().into_owned()
+    }
+
+    fn formatted(
+        &self,
+        mantissa: FixedDecimal,
+        exponent: FixedDecimal,
+    ) -> FormattedScientificDecimal<'_> {
+        FormattedScientificDecimal {
+            mantissa,
+            exponent,
+            options: &self.fixed_decimal_formatter.options,
+            exponent_style: self.options.exponent_style,
+            decimal_symbols: self.fixed_decimal_formatter.symbols.get(),
+            symbols: self.symbols.get(),
+        }
+    }
+
+    fn round_mantissa(&self, value: &FixedDecimal, exponent: i32) -> FixedDecimal {
+        let mut mantissa = value.clone();
+        // Shift in steps that fit in an `i16`, trimming the leading zeros of each step so
+        // that they do not overflow the magnitude range.
+        let mut delta = -exponent;
+        while delta != 0 {
+            let step = delta.clamp(i16::MIN.into(), i16::MAX.into());
+            mantissa.multiply_pow10(step as i16);
+            mantissa.trim_start();
+            delta -= step;
+        }
+        rounding::round(&self.options.fixed_decimal_formatter_options, &mantissa).into_owned()
+    }
+}
+
+/// An intermediate structure returned by [`ScientificDecimalFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedScientificDecimal<'l> {
+    mantissa: FixedDecimal,
+    exponent: FixedDecimal,
+    options: &'l FixedDecimalFormatterOptions,
+    exponent_style: ExponentStyle,
+    decimal_symbols: &'l DecimalSymbolsV2<'l>,
+    symbols: &'l ScientificSymbolsV1<'l>,
+}
+
+/// The superscript forms of the digits 0 through 9.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Returns the superscript form of a locale digit. Unicode only has superscript forms of the
+/// ASCII digits, so digits of other numbering systems are returned unchanged.
+fn superscript_digit(digit: char) -> char {
+    digit
+        .to_digit(10)
+        .and_then(|d| SUPERSCRIPT_DIGITS.get(d as usize))
+        .copied()
+        .unwrap_or(digit)
+}
+
+impl<'l> FormattedScientificDecimal<'l> {
+    /// Returns the sign affixes of the exponent, its superscript sign, and the [`Part`] of
+    /// the sign.
+    fn exponent_sign(&self) -> Option<(&'l AffixesV1<'l>, char, Part)> {
+        match self.exponent.sign() {
+            Sign::None => None,
+            Sign::Negative => Some((
+                &self.decimal_symbols.minus_sign_affixes,
+                '⁻',
+                parts::EXPONENT_MINUS_SIGN,
+            )),
+            Sign::Positive => Some((
+                &self.decimal_symbols.plus_sign_affixes,
+                '⁺',
+                parts::PLUS_SIGN,
+            )),
+        }
+    }
+}
+
+impl<'l> Writeable for FormattedScientificDecimal<'l> {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        FormattedFixedDecimal {
+            value: Cow::Borrowed(&self.mantissa),
+            options: self.options,
+            symbols: self.decimal_symbols,
+        }
+        .write_to_parts(sink)?;
+        let sign = self.exponent_sign();
+        let superscript = match self.exponent_style {
+            ExponentStyle::Exponential => {
+                sink.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                    w.write_str(&self.symbols.exponential)
+                })?;
+                if let Some((affixes, _, part)) = sign {
+                    format::write_affix(sink, &affixes.prefix, part)?;
+                }
+                false
+            }
+            ExponentStyle::Superscript => {
+                sink.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                    w.write_str(&self.symbols.superscripting_exponent)?;
+                    w.write_char(self.decimal_symbols.digits[1])?;
+                    w.write_char(self.decimal_symbols.digits[0])
+                })?;
+                if let Some((_, superscript_sign, part)) = sign {
+                    sink.with_part(part, |w| w.write_char(superscript_sign))?;
+                }
+                true
+            }
+        };
+        // The exponent is never grouped, so its digits form a single part.
+        sink.with_part(parts::EXPONENT_INTEGER, |w| {
+            for m in self.exponent.magnitude_range().rev() {
+                #[allow(clippy::indexing_slicing)] // digit_at in 0..=9
+                let digit = self.decimal_symbols.digits[self.exponent.digit_at(m) as usize];
+                w.write_char(if superscript {
+                    superscript_digit(digit)
+                } else {
+                    digit
+                })?;
+            }
+            Ok(())
+        })?;
+        match sign {
+            Some((affixes, _, part)) if !superscript => {
+                format::write_affix(sink, &affixes.suffix, part)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedScientificDecimal<'_>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+    /// An input, a function setting the options, and the expected output.
+    type TestCase = (
+        &'static str,
+        fn(&mut ScientificDecimalFormatterOptions),
+        &'static str,
+    );
+
+    #[test]
+    pub fn test_scientific() {
+        let cases: [TestCase; 9] = [
+            ("12345", |_| (), "1.2345E4"),
+            ("-0.00123", |_| (), "-1.23E-3"),
+            ("0", |_| (), "0E0"),
+            ("00120.0", |_| (), "1.200E2"),
+            (
+                "9.96",
+                |o| o.fixed_decimal_formatter_options.maximum_significant_digits = Some(2),
+                "1E1",
+            ),
+            (
+                "9.96",
+                |o| {
+                    o.fixed_decimal_formatter_options.minimum_significant_digits = Some(2);
+                    o.fixed_decimal_formatter_options.maximum_significant_digits = Some(2);
+                },
+                "1.0E1",
+            ),
+            (
+                "999.9",
+                |o| o.notation = ScientificNotation::Engineering,
+                "999.9E0",
+            ),
+            (
+                "999.9",
+                |o| {
+                    o.notation = ScientificNotation::Engineering;
+                    o.fixed_decimal_formatter_options.maximum_fraction_digits = Some(0);
+                },
+                "1E3",
+            ),
+            (
+                "-0.000012",
+                |o| {
+                    o.notation = ScientificNotation::Engineering;
+                    o.exponent_style = ExponentStyle::Superscript;
+                },
+                "-12×10⁻⁶",
+            ),
+        ];
+        for (input, f, expected) in cases {
+            let mut options = ScientificDecimalFormatterOptions::default();
+            f(&mut options);
+            let sdf = ScientificDecimalFormatter::try_new(&locale!("en").into(), options).unwrap();
+            let decimal = input.parse().unwrap();
+            assert_writeable_eq!(sdf.format(&decimal), expected, "{input} {options:?}");
+        }
+    }
+
+    #[test]
+    pub fn test_magnitude_limits() {
+        let format = |value: FixedDecimal, f: fn(&mut ScientificDecimalFormatterOptions)| {
+            let mut options = ScientificDecimalFormatterOptions::default();
+            f(&mut options);
+            ScientificDecimalFormatter::try_new(&locale!("en").into(), options)
+                .unwrap()
+                .format_to_string(&value)
+        };
+        let engineering = |o: &mut ScientificDecimalFormatterOptions| {
+            o.notation = ScientificNotation::Engineering
+        };
+
+        let smallest = FixedDecimal::from(5).multiplied_pow10(i16::MIN);
+        assert_eq!(format(smallest.clone(), |_| ()), "5E-32768");
+        assert_eq!(format(smallest, engineering), "50E-32769");
+
+        // The integer zeros of the largest value are significant
+        let largest = FixedDecimal::from(5).multiplied_pow10(i16::MAX);
+        assert_eq!(
+            format(largest.clone(), |o| o
+                .fixed_decimal_formatter_options
+                .maximum_fraction_digits = Some(0)),
+            "5E32767"
+        );
+        assert_eq!(
+            format(largest, |o| {
+                o.notation = ScientificNotation::Engineering;
+                o.fixed_decimal_formatter_options.maximum_fraction_digits = Some(0);
+            }),
+            "50E32766"
+        );
+
+        // Rounding carries into an exponent beyond the magnitude range
+        let largest = FixedDecimal::from(999).multiplied_pow10(i16::MAX - 2);
+        assert_eq!(
+            format(largest, |o| o
+                .fixed_decimal_formatter_options
+                .maximum_significant_digits = Some(2)),
+            "1E32768"
+        );
+    }
+
+    #[test]
+    pub fn test_scientific_parts() {
+        let sdf =
+            ScientificDecimalFormatter::try_new(&locale!("en").into(), Default::default()).unwrap();
+        assert_writeable_parts_eq!(
+            sdf.format(&"-0.00123".parse().unwrap()),
+            "-1.23E-3",
+            [
+                (0, 1, parts::MINUS_SIGN),
+                (1, 2, parts::INTEGER),
+                (2, 3, parts::DECIMAL),
+                (3, 5, parts::FRACTION),
+                (5, 6, parts::EXPONENT_SEPARATOR),
+                (6, 7, parts::EXPONENT_MINUS_SIGN),
+                (7, 8, parts::EXPONENT_INTEGER),
+            ]
+        );
+
+        let options = ScientificDecimalFormatterOptions {
+            exponent_style: ExponentStyle::Superscript,
+            ..Default::default()
+        };
+        let sdf = ScientificDecimalFormatter::try_new(&locale!("en").into(), options).unwrap();
+        assert_writeable_parts_eq!(
+            sdf.format(&"0.00012".parse().unwrap()),
+            "1.2×10⁻⁴",
+            [
+                (0, 1, parts::INTEGER),
+                (1, 2, parts::DECIMAL),
+                (2, 3, parts::FRACTION),
+                (3, 7, parts::EXPONENT_SEPARATOR),
+                (7, 10, parts::EXPONENT_MINUS_SIGN),
+                (10, 13, parts::EXPONENT_INTEGER),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_superscript_digits() {
+        let options = ScientificDecimalFormatterOptions {
+            exponent_style: ExponentStyle::Superscript,
+            ..Default::default()
+        };
+        let sdf =
+            ScientificDecimalFormatter::try_new(&locale!("th-u-nu-thai").into(), options).unwrap();
+        // Thai digits have no superscript forms
+        assert_writeable_eq!(sdf.format(&"0.00012".parse().unwrap()), "๑.๒×๑๐⁻๔");
+    }
+}
@@ -12,6 +12,7 @@ use icu_decimal::{
 use icu_plurals::PluralRules;
 use icu_provider::prelude::*;
 use zerovec::maps::ZeroMap2dCursor;
+use zerovec::ZeroMap2d;
 
 use crate::compactdecimal::{
     format::FormattedCompactDecimal,
@@ -162,7 +163,7 @@ impl CompactDecimalFormatter {
     ) -> Result<Self, CompactDecimalError>
     where
         D: DataProvider<ShortCompactDecimalFormatDataV1Marker>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2Marker>
             + DataProvider<icu_plurals::provider::CardinalV1Marker>
             + ?Sized,
     {
@@ -249,7 +250,7 @@ impl CompactDecimalFormatter {
     ) -> Result<Self, CompactDecimalError>
     where
         D: DataProvider<LongCompactDecimalFormatDataV1Marker>
-            + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV2Marker>
             + DataProvider<icu_plurals::provider::CardinalV1Marker>
             + ?Sized,
     {
@@ -680,24 +681,33 @@ impl CompactDecimalFormatter {
         &self,
         magnitude: i16,
     ) -> (Option<ZeroMap2dCursor<i8, Count, PatternULE>>, u8) {
-        let plural_map = self
-            .compact_data
-            .get()
-            .patterns
-            .iter0()
-            .filter(|cursor| i16::from(*cursor.key0()) <= magnitude)
-            .last();
-        let exponent = plural_map
-            .as_ref()
-            .and_then(|map| {
-                map.get1(&Count::Other)
-                    .and_then(|pattern| u8::try_from(pattern.exponent).ok())
-            })
-            .unwrap_or(0);
-        (plural_map, exponent)
+        plural_map_and_exponent_for_magnitude(&self.compact_data.get().patterns, magnitude)
     }
 }
 
+/// Returns the plural map of the largest type in `patterns` that does not exceed
+/// the given magnitude, together with its compact decimal exponent.
+///
+/// This is shared with other formatters whose data is keyed like
+/// [`CompactDecimalPatternDataV1`](crate::compactdecimal::provider::CompactDecimalPatternDataV1).
+pub(crate) fn plural_map_and_exponent_for_magnitude<'l>(
+    patterns: &'l ZeroMap2d<'l, i8, Count, PatternULE>,
+    magnitude: i16,
+) -> (Option<ZeroMap2dCursor<'l, 'l, i8, Count, PatternULE>>, u8) {
+    let plural_map = patterns
+        .iter0()
+        .filter(|cursor| i16::from(*cursor.key0()) <= magnitude)
+        .last();
+    let exponent = plural_map
+        .as_ref()
+        .and_then(|map| {
+            map.get1(&Count::Other)
+                .and_then(|pattern| u8::try_from(pattern.exponent).ok())
+        })
+        .unwrap_or(0);
+    (plural_map, exponent)
+}
+
 #[cfg(feature = "serde")]
 #[cfg(test)]
 mod tests {
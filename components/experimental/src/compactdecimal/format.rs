@@ -4,6 +4,8 @@
 
 use alloc::borrow::Cow;
 use fixed_decimal::{CompactDecimal, FixedDecimal};
+use icu_plurals::rules::RawPluralOperands;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRules};
 use writeable::Writeable;
 use zerovec::maps::ZeroMap2dCursor;
 
@@ -61,20 +63,11 @@ impl<'l> Writeable for FormattedCompactDecimal<'l> {
                 .write_to(sink)
         } else {
             let plural_map = self.plural_map.as_ref().ok_or(core::fmt::Error)?;
-            let chosen_pattern = (|| {
-                if self.value.significand() == &FixedDecimal::from(1) {
-                    if let Some(pattern) = plural_map.get1(&Count::Explicit1) {
-                        return Some(pattern);
-                    }
-                }
-                let plural_category = self
-                    .formatter
-                    .plural_rules
-                    .category_for(self.value.significand());
-                plural_map
-                    .get1(&plural_category.into())
-                    .or_else(|| plural_map.get1(&Count::Other))
-            })()
+            let chosen_pattern = select_pattern(
+                plural_map,
+                self.value.significand(),
+                &self.formatter.plural_rules,
+            )
             .ok_or(core::fmt::Error)?;
             match chosen_pattern.index {
                 u8::MAX => sink.write_str(&chosen_pattern.literal_text),
@@ -103,3 +96,37 @@ impl<'l> Writeable for FormattedCompactDecimal<'l> {
 }
 
 writeable::impl_display_with_writeable!(FormattedCompactDecimal<'_>);
+
+/// Selects the pattern for the given significand in a plural map: the explicit 1 case
+/// if the significand is 1, otherwise the plural category of the significand, falling
+/// back to the other case.
+pub(crate) fn select_pattern<'l>(
+    plural_map: &ZeroMap2dCursor<'l, 'l, i8, Count, PatternULE>,
+    significand: &FixedDecimal,
+    plural_rules: &PluralRules,
+) -> Option<&'l PatternULE> {
+    if significand == &FixedDecimal::from(1) {
+        if let Some(pattern) = plural_map.get1(&Count::Explicit1) {
+            return Some(pattern);
+        }
+    }
+    let plural_category = plural_rules.category_for(significand);
+    plural_map
+        .get1(&plural_category.into())
+        .or_else(|| plural_map.get1(&Count::Other))
+}
+
+/// Returns the plural category of a compact decimal, from the operands of its value
+/// and its exponent `c` as in UTS #35, so that 1.2c6 is `many` in French.
+pub(crate) fn compact_plural_category(
+    value: &CompactDecimal,
+    plural_rules: &PluralRules,
+) -> PluralCategory {
+    let expanded = value
+        .significand()
+        .clone()
+        .multiplied_pow10(value.exponent());
+    let mut operands = RawPluralOperands::from(PluralOperands::from(&expanded));
+    operands.c = usize::try_from(value.exponent()).unwrap_or_default();
+    plural_rules.category_for(PluralOperands::from(operands))
+}
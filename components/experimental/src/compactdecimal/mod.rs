@@ -24,7 +24,9 @@ mod error;
 mod format;
 pub mod provider;
 
+pub(crate) use compactdecimal::plural_map_and_exponent_for_magnitude;
 pub use compactdecimal::CompactDecimalFormatter;
 pub use error::CompactDecimalError;
+pub(crate) use format::{compact_plural_category, select_pattern, FormattedCompactDecimal};
 #[doc(no_inline)]
 pub use CompactDecimalError as Error;
@@ -0,0 +1,160 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Sign;
+use icu_decimal::provider::DecimalSymbolsV2;
+use icu_pattern::{DoublePlaceholderKey, PatternItem};
+use writeable::Writeable;
+
+use crate::compactdecimal::provider::PatternULE;
+use crate::compactdecimal::{
+    plural_map_and_exponent_for_magnitude, select_pattern as select_compact_pattern,
+    FormattedCompactDecimal,
+};
+use crate::dimension::currency::format::{placeholder_and_selection, select_pattern};
+use crate::dimension::currency::options::CompactCurrencyFormatterOptions;
+use crate::dimension::currency::CurrencyCode;
+use crate::dimension::provider::currency::{
+    CurrencyEssentialsV1, PatternSelection, ShortCurrencyCompactV1,
+};
+
+/// An intermediate structure returned by [`CompactCurrencyFormatter`](crate::dimension::currency::CompactCurrencyFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted currency to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedCompactCurrency<'l> {
+    /// The absolute value of the amount, in compact decimal notation.
+    pub(crate) value: FormattedCompactDecimal<'l>,
+    /// The sign of the amount, placed around the whole currency pattern.
+    pub(crate) sign: Sign,
+    pub(crate) currency_code: CurrencyCode,
+    pub(crate) options: &'l CompactCurrencyFormatterOptions,
+    pub(crate) essential: &'l CurrencyEssentialsV1<'l>,
+    pub(crate) compact_data: &'l ShortCurrencyCompactV1<'l>,
+    pub(crate) decimal_symbols: &'l DecimalSymbolsV2<'l>,
+}
+
+impl<'l> FormattedCompactCurrency<'l> {
+    /// Returns the compact currency pattern for the value, or `None` if the value is not
+    /// compact, in which case it is placed in the standard currency pattern.
+    fn compact_pattern(&self, selection: PatternSelection) -> Option<&'l PatternULE> {
+        let compact_decimal = self.value.get_compact_decimal();
+        if compact_decimal.exponent() == 0 {
+            return None;
+        }
+
+        let patterns = match selection {
+            PatternSelection::StandardAlphaNextToNumber
+                if !self.compact_data.alpha_next_to_number_patterns.is_empty() =>
+            {
+                &self.compact_data.alpha_next_to_number_patterns
+            }
+            _ => &self.compact_data.standard_patterns,
+        };
+
+        let significand = compact_decimal.significand();
+        let magnitude = significand.nonzero_magnitude_start() + compact_decimal.exponent();
+        let (plural_map, exponent) = plural_map_and_exponent_for_magnitude(patterns, magnitude);
+        // The currency patterns of a locale may abbreviate other powers of ten than its
+        // decimal patterns; the compact number then goes in the standard currency pattern.
+        if i16::from(exponent) != compact_decimal.exponent() {
+            return None;
+        }
+        select_compact_pattern(
+            plural_map.as_ref()?,
+            significand,
+            &self.value.formatter.plural_rules,
+        )
+    }
+}
+
+/// Writes the literal text of a compact currency pattern, replacing the currency sign `¤`
+/// by the text that stands for the currency.
+fn write_literal_text<W>(sink: &mut W, text: &str, placeholder: &str) -> core::fmt::Result
+where
+    W: core::fmt::Write + ?Sized,
+{
+    for (i, chunk) in text.split('¤').enumerate() {
+        if i != 0 {
+            sink.write_str(placeholder)?;
+        }
+        sink.write_str(chunk)?;
+    }
+    Ok(())
+}
+
+impl<'l> Writeable for FormattedCompactCurrency<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let (placeholder, selection) =
+            placeholder_and_selection(self.essential, &self.currency_code, self.options.width);
+
+        let affixes = match self.sign {
+            Sign::None => None,
+            Sign::Negative => Some(&self.decimal_symbols.minus_sign_affixes),
+            Sign::Positive => Some(&self.decimal_symbols.plus_sign_affixes),
+        };
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.prefix)?;
+        }
+
+        match self.compact_pattern(selection) {
+            Some(pattern) => match pattern.index {
+                u8::MAX => write_literal_text(sink, &pattern.literal_text, placeholder)?,
+                index => {
+                    let i = usize::from(index);
+                    write_literal_text(
+                        sink,
+                        pattern.literal_text.get(..i).ok_or(core::fmt::Error)?,
+                        placeholder,
+                    )?;
+                    self.value
+                        .formatter
+                        .fixed_decimal_format
+                        .format(self.value.get_compact_decimal().significand())
+                        .write_to(sink)?;
+                    write_literal_text(
+                        sink,
+                        pattern.literal_text.get(i..).ok_or(core::fmt::Error)?,
+                        placeholder,
+                    )?;
+                }
+            },
+            None => {
+                let pattern = select_pattern(
+                    selection,
+                    self.essential.standard_pattern.as_ref(),
+                    self.essential
+                        .standard_alpha_next_to_number_pattern
+                        .as_ref(),
+                );
+                match pattern {
+                    Some(pattern) => {
+                        for item in pattern.iter() {
+                            match item {
+                                PatternItem::Literal(s) => sink.write_str(s)?,
+                                PatternItem::Placeholder(DoublePlaceholderKey::Place0) => {
+                                    self.value.write_to(sink)?
+                                }
+                                PatternItem::Placeholder(DoublePlaceholderKey::Place1) => {
+                                    sink.write_str(placeholder)?
+                                }
+                            }
+                        }
+                    }
+                    // Without a pattern, there is nowhere to place the currency.
+                    None => self.value.write_to(sink)?,
+                }
+            }
+        }
+
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.suffix)?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCompactCurrency<'_>);
@@ -0,0 +1,303 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::provider::DecimalSymbolsV2Marker;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_provider::prelude::*;
+
+use crate::compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker;
+use crate::compactdecimal::CompactDecimalFormatter;
+use crate::dimension::currency::compact_format::FormattedCompactCurrency;
+use crate::dimension::currency::options::CompactCurrencyFormatterOptions;
+use crate::dimension::currency::CurrencyCode;
+use crate::dimension::provider::currency::{
+    CurrencyEssentialsV1Marker, ShortCurrencyCompactV1Marker,
+};
+use crate::dimension::DimensionError;
+
+/// A formatter for monetary values in short compact notation, such as "$1.2K".
+///
+/// [`CompactCurrencyFormatter`] supports:
+///   1. The compact rounding and the powers of ten of [`CompactDecimalFormatter`].
+///   2. The short compact currency patterns of the locale, which merge the currency symbol
+///      with the compact notation.
+///   3. The short, narrow and standard (ISO 4217 code) display of the currency.
+///
+/// Values that are not abbreviated are placed in the standard currency pattern of the locale.
+///
+/// Read more about the options in the [`options`](crate::dimension::currency::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::dimension::currency::options::Width;
+/// use icu_experimental::dimension::currency::{CompactCurrencyFormatter, CurrencyCode};
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let value = "1234567".parse().unwrap();
+///
+/// let fmt = CompactCurrencyFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "$1.2M"
+/// );
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "CHF"))),
+///     "CHF\u{a0}1.2M"
+/// );
+///
+/// let fmt = CompactCurrencyFormatter::try_new(&locale!("fr").into(), Width::Narrow.into())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "1,2\u{a0}M\u{a0}$"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct CompactCurrencyFormatter {
+    /// Options bag for the compact currency formatter to determine the behavior of the formatter.
+    options: CompactCurrencyFormatterOptions,
+
+    /// Essential data for the currency formatter, used for the currency symbols and
+    /// for the values that are not abbreviated.
+    essential: DataPayload<CurrencyEssentialsV1Marker>,
+
+    /// The short compact currency patterns.
+    compact_data: DataPayload<ShortCurrencyCompactV1Marker>,
+
+    /// The decimal symbols, used for the signs that are placed around the whole currency pattern.
+    decimal_symbols: DataPayload<DecimalSymbolsV2Marker>,
+
+    /// A short [`CompactDecimalFormatter`] to round the value and select its power of ten.
+    compact_decimal_formatter: CompactDecimalFormatter,
+}
+
+impl CompactCurrencyFormatter {
+    /// Creates a new [`CompactCurrencyFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: CompactCurrencyFormatterOptions,
+    ) -> Result<Self, DimensionError> {
+        let compact_decimal_formatter =
+            CompactDecimalFormatter::try_new_short(locale, Default::default())?;
+        let essential = DataProvider::<CurrencyEssentialsV1Marker>::load(
+            &crate::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let compact_data = DataProvider::<ShortCurrencyCompactV1Marker>::load(
+            &crate::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let decimal_symbols = DataProvider::<DecimalSymbolsV2Marker>::load(
+            &icu_decimal::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            options,
+            essential,
+            compact_data,
+            decimal_symbols,
+            compact_decimal_formatter,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: CompactCurrencyFormatterOptions,
+        error: DimensionError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: CompactCurrencyFormatterOptions,
+    ) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<CurrencyEssentialsV1Marker>
+            + DataProvider<ShortCurrencyCompactV1Marker>
+            + DataProvider<ShortCompactDecimalFormatDataV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let compact_decimal_formatter =
+            CompactDecimalFormatter::try_new_short_unstable(provider, locale, Default::default())?;
+        let essential = DataProvider::<CurrencyEssentialsV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let compact_data = DataProvider::<ShortCurrencyCompactV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let decimal_symbols = DataProvider::<DecimalSymbolsV2Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            options,
+            essential,
+            compact_data,
+            decimal_symbols,
+            compact_decimal_formatter,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] value for the given currency code in short compact notation.
+    ///
+    /// The value is rounded as by [`CompactDecimalFormatter::format_fixed_decimal()`], so
+    /// the number of fraction digits of the currency is not used.
+    pub fn format_fixed_decimal(
+        &self,
+        value: &FixedDecimal,
+        currency_code: CurrencyCode,
+    ) -> FormattedCompactCurrency<'_> {
+        // The sign is placed around the whole currency pattern, so only the
+        // absolute value is formatted by the `CompactDecimalFormatter`.
+        let sign = value.sign();
+        let mut value = value.clone();
+        value.set_sign(Sign::None);
+
+        FormattedCompactCurrency {
+            value: self.compact_decimal_formatter.format_fixed_decimal(value),
+            sign,
+            currency_code,
+            options: &self.options,
+            essential: self.essential.get(),
+            compact_data: self.compact_data.get(),
+            decimal_symbols: self.decimal_symbols.get(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::currency::options::Width;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_compact_currency() {
+        let cases = [
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "USD"),
+                "843",
+                "$843",
+            ),
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "USD"),
+                "1234",
+                "$1.2K",
+            ),
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "USD"),
+                "-15127",
+                "-$15K",
+            ),
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "USD"),
+                "999500",
+                "$1M",
+            ),
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "EGP"),
+                "1234",
+                "EGP\u{a0}1.2K",
+            ),
+            (
+                locale!("en"),
+                Width::Standard,
+                tinystr!(3, "USD"),
+                "3010349",
+                "USD\u{a0}3M",
+            ),
+            (
+                locale!("fr"),
+                Width::Short,
+                tinystr!(3, "EUR"),
+                "1234",
+                "1,2\u{a0}k\u{a0}€",
+            ),
+            (
+                locale!("ja"),
+                Width::Short,
+                tinystr!(3, "JPY"),
+                "1234",
+                "￥1234",
+            ),
+            (
+                locale!("ja"),
+                Width::Short,
+                tinystr!(3, "JPY"),
+                "12345",
+                "￥1.2万",
+            ),
+        ];
+        for (locale, width, code, input, expected) in cases {
+            let fmt = CompactCurrencyFormatter::try_new(&(&locale).into(), width.into()).unwrap();
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(
+                fmt.format_fixed_decimal(&value, CurrencyCode(code)),
+                expected,
+                "{locale} {width:?} {code} {input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::borrow::Cow;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::provider::DecimalSymbolsV2;
+use icu_decimal::FixedDecimalFormatter;
+use icu_pattern::{DoublePlaceholderKey, DoublePlaceholderPattern, PatternItem};
+use writeable::Writeable;
+
+use crate::dimension::currency::options::{CurrencyFormatterOptions, Width};
+use crate::dimension::currency::CurrencyCode;
+use crate::dimension::provider::currency::{
+    CurrencyAccountingPatternsV1, CurrencyEssentialsV1, PatternSelection, PlaceholderValue,
+};
+
+/// An intermediate structure returned by [`CurrencyFormatter`](crate::dimension::currency::CurrencyFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted currency to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedCurrency<'l> {
+    /// The absolute value of the amount.
+    pub(crate) value: FixedDecimal,
+    /// The sign of the amount, placed by the negative pattern of the locale if it has one,
+    /// or around the whole currency pattern otherwise.
+    pub(crate) sign: Sign,
+    pub(crate) currency_code: CurrencyCode,
+    pub(crate) options: &'l CurrencyFormatterOptions,
+    pub(crate) essential: &'l CurrencyEssentialsV1<'l>,
+    pub(crate) accounting: Option<&'l CurrencyAccountingPatternsV1<'l>>,
+    pub(crate) decimal_symbols: &'l DecimalSymbolsV2<'l>,
+    pub(crate) fixed_decimal_formatter: &'l FixedDecimalFormatter,
+}
+
+impl<'l> FormattedCurrency<'l> {
+    /// Returns the pattern to place the value in, and the sign that still needs to be
+    /// placed around the pattern.
+    fn pattern_and_sign(
+        &self,
+        selection: PatternSelection,
+    ) -> (Option<&DoublePlaceholderPattern<Cow<'l, str>>>, Sign) {
+        if self.sign == Sign::Negative {
+            let accounting_negative_pattern = self.accounting.and_then(|accounting| {
+                select_pattern(
+                    selection,
+                    accounting.negative_pattern.as_ref(),
+                    accounting.negative_alpha_next_to_number_pattern.as_ref(),
+                )
+            });
+            let negative_pattern = accounting_negative_pattern.or_else(|| {
+                select_pattern(
+                    selection,
+                    self.essential.negative_standard_pattern.as_ref(),
+                    self.essential
+                        .negative_standard_alpha_next_to_number_pattern
+                        .as_ref(),
+                )
+            });
+            // The negative patterns already mark the value as negative.
+            if let Some(negative_pattern) = negative_pattern {
+                return (Some(negative_pattern), Sign::None);
+            }
+        }
+
+        let standard_pattern = select_pattern(
+            selection,
+            self.essential.standard_pattern.as_ref(),
+            self.essential
+                .standard_alpha_next_to_number_pattern
+                .as_ref(),
+        );
+
+        // Locales without a negative pattern place the minus sign before the pattern.
+        let pattern = self.accounting.and_then(|accounting| {
+            select_pattern(
+                selection,
+                accounting.pattern.as_ref(),
+                accounting.alpha_next_to_number_pattern.as_ref(),
+            )
+        });
+        (pattern.or(standard_pattern), self.sign)
+    }
+}
+
+/// Returns the text that stands for the currency, and the pattern it should be placed in.
+pub(crate) fn placeholder_and_selection<'a>(
+    essential: &'a CurrencyEssentialsV1<'_>,
+    currency_code: &'a CurrencyCode,
+    width: Width,
+) -> (&'a str, PatternSelection) {
+    let config = essential
+        .pattern_config_map
+        .get_copied(&currency_code.0.to_unvalidated())
+        .unwrap_or(essential.default_pattern_config);
+
+    let (placeholder_value, selection) = match width {
+        // The ISO code is made of letters, so it always needs the alpha pattern.
+        Width::Standard => (None, PatternSelection::StandardAlphaNextToNumber),
+        Width::Narrow if config.narrow_placeholder_value.is_some() => (
+            config.narrow_placeholder_value,
+            config.narrow_pattern_selection,
+        ),
+        // Currencies without a narrow symbol use the short one.
+        Width::Short | Width::Narrow => (
+            config.short_placeholder_value,
+            config.short_pattern_selection,
+        ),
+    };
+
+    let placeholder = match placeholder_value {
+        Some(PlaceholderValue::Index(index)) => essential.placeholders.get(index as usize),
+        // Currencies without a symbol are displayed with their ISO code.
+        Some(PlaceholderValue::ISO) | None => None,
+    };
+
+    (placeholder.unwrap_or(currency_code.0.as_str()), selection)
+}
+
+/// Selects the alpha_next_to_number variant of a pattern if it is required and present.
+pub(crate) fn select_pattern<'a, T>(
+    selection: PatternSelection,
+    pattern: Option<&'a T>,
+    alpha_next_to_number_pattern: Option<&'a T>,
+) -> Option<&'a T> {
+    match selection {
+        PatternSelection::StandardAlphaNextToNumber => alpha_next_to_number_pattern.or(pattern),
+        PatternSelection::Standard => pattern,
+    }
+}
+
+impl<'l> Writeable for FormattedCurrency<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let (placeholder, selection) =
+            placeholder_and_selection(self.essential, &self.currency_code, self.options.width);
+
+        let (pattern, sign) = self.pattern_and_sign(selection);
+
+        let affixes = match sign {
+            Sign::None => None,
+            Sign::Negative => Some(&self.decimal_symbols.minus_sign_affixes),
+            Sign::Positive => Some(&self.decimal_symbols.plus_sign_affixes),
+        };
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.prefix)?;
+        }
+
+        let formatted_value = self.fixed_decimal_formatter.format(&self.value);
+        match pattern {
+            Some(pattern) => {
+                for item in pattern.iter() {
+                    match item {
+                        PatternItem::Literal(s) => sink.write_str(s)?,
+                        PatternItem::Placeholder(DoublePlaceholderKey::Place0) => {
+                            formatted_value.write_to(sink)?
+                        }
+                        PatternItem::Placeholder(DoublePlaceholderKey::Place1) => {
+                            sink.write_str(placeholder)?
+                        }
+                    }
+                }
+            }
+            // Without a pattern, there is nowhere to place the currency.
+            None => formatted_value.write_to(sink)?,
+        }
+
+        if let Some(affixes) = affixes {
+            sink.write_str(&affixes.suffix)?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCurrency<'_>);
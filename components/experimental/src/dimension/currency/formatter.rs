@@ -0,0 +1,456 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV2Marker, FixedDecimalFormatter,
+};
+use icu_provider::prelude::*;
+
+use crate::dimension::currency::format::FormattedCurrency;
+use crate::dimension::currency::options::{CurrencyFormatterOptions, CurrencySign};
+use crate::dimension::currency::{round_to_currency_digits, CurrencyCode};
+use crate::dimension::provider::currency::{
+    CurrencyAccountingPatternsV1Marker, CurrencyEssentialsV1Marker, CurrencyFractionsV1Marker,
+};
+use crate::dimension::DimensionError;
+
+/// A formatter for monetary values.
+///
+/// [`CurrencyFormatter`] supports:
+///   1. Rendering in the locale's currency system.
+///   2. Locale-sensitive grouping separator positions.
+///   3. The short, narrow and standard (ISO 4217 code) display of the currency.
+///   4. Rounding the value to the default number of fraction digits of the currency.
+///   5. Sign display options and the accounting patterns of the locale.
+///
+/// Read more about the options in the [`options`](crate::dimension::currency::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::dimension::currency::options::Width;
+/// use icu_experimental::dimension::currency::{CurrencyCode, CurrencyFormatter};
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let value = "12345.67".parse().unwrap();
+///
+/// let fmt = CurrencyFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "$12,345.67"
+/// );
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "JPY"))),
+///     "¥12,346"
+/// );
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "CHF"))),
+///     "CHF\u{a0}12,345.67"
+/// );
+///
+/// let fmt = CurrencyFormatter::try_new(&locale!("fr").into(), Width::Narrow.into())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "12\u{202f}345,67\u{a0}$"
+/// );
+/// ```
+///
+/// Negative values can be displayed with the accounting patterns of the locale:
+///
+/// ```
+/// use icu_experimental::dimension::currency::options::{
+///     CurrencyFormatterOptions, CurrencySign, SignDisplay,
+/// };
+/// use icu_experimental::dimension::currency::{CurrencyCode, CurrencyFormatter};
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = CurrencyFormatterOptions::default();
+/// options.currency_sign = CurrencySign::Accounting;
+/// let fmt = CurrencyFormatter::try_new(&locale!("en").into(), options)
+///     .expect("locale should be present");
+///
+/// let value = "-12345.67".parse().unwrap();
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "($12,345.67)"
+/// );
+///
+/// let mut options = CurrencyFormatterOptions::default();
+/// options.sign_display = Some(SignDisplay::Always);
+/// let fmt = CurrencyFormatter::try_new(&locale!("en").into(), options)
+///     .expect("locale should be present");
+///
+/// let value = "12345.67".parse().unwrap();
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "+$12,345.67"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct CurrencyFormatter {
+    /// Options bag for the currency formatter to determine the behavior of the formatter.
+    /// for example: currency width.
+    options: CurrencyFormatterOptions,
+
+    /// Essential data for the currency formatter.
+    essential: DataPayload<CurrencyEssentialsV1Marker>,
+
+    /// The number of fraction digits of each currency.
+    fractions: DataPayload<CurrencyFractionsV1Marker>,
+
+    /// The accounting patterns, only loaded for [`CurrencySign::Accounting`].
+    accounting: Option<DataPayload<CurrencyAccountingPatternsV1Marker>>,
+
+    /// The decimal symbols, used for the signs that are placed around the whole currency pattern.
+    decimal_symbols: DataPayload<DecimalSymbolsV2Marker>,
+
+    /// A [`FixedDecimalFormatter`] to format the currency value.
+    fixed_decimal_formatter: FixedDecimalFormatter,
+}
+
+impl CurrencyFormatter {
+    /// Creates a new [`CurrencyFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: CurrencyFormatterOptions,
+    ) -> Result<Self, DimensionError> {
+        let fixed_decimal_formatter =
+            FixedDecimalFormatter::try_new(locale, FixedDecimalFormatterOptions::default())?;
+        let essential = DataProvider::<CurrencyEssentialsV1Marker>::load(
+            &crate::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let fractions = DataProvider::<CurrencyFractionsV1Marker>::load(
+            &crate::provider::Baked,
+            Default::default(),
+        )?
+        .take_payload()?;
+        let accounting = match options.currency_sign {
+            CurrencySign::Standard => None,
+            CurrencySign::Accounting => Some(
+                DataProvider::<CurrencyAccountingPatternsV1Marker>::load(
+                    &crate::provider::Baked,
+                    DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    },
+                )?
+                .take_payload()?,
+            ),
+        };
+        let decimal_symbols = DataProvider::<DecimalSymbolsV2Marker>::load(
+            &icu_decimal::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            options,
+            essential,
+            fractions,
+            accounting,
+            decimal_symbols,
+            fixed_decimal_formatter,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: CurrencyFormatterOptions,
+        error: DimensionError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: CurrencyFormatterOptions,
+    ) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<CurrencyEssentialsV1Marker>
+            + DataProvider<CurrencyFractionsV1Marker>
+            + DataProvider<CurrencyAccountingPatternsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            locale,
+            FixedDecimalFormatterOptions::default(),
+        )?;
+        let essential = DataProvider::<CurrencyEssentialsV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let fractions =
+            DataProvider::<CurrencyFractionsV1Marker>::load(provider, Default::default())?
+                .take_payload()?;
+        let accounting = match options.currency_sign {
+            CurrencySign::Standard => None,
+            CurrencySign::Accounting => Some(
+                DataProvider::<CurrencyAccountingPatternsV1Marker>::load(
+                    provider,
+                    DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    },
+                )?
+                .take_payload()?,
+            ),
+        };
+        let decimal_symbols = DataProvider::<DecimalSymbolsV2Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            options,
+            essential,
+            fractions,
+            accounting,
+            decimal_symbols,
+            fixed_decimal_formatter,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] value for the given currency code.
+    ///
+    /// The value is rounded to the default number of fraction digits of the currency,
+    /// such as two for USD and zero for JPY. The sign of the value is displayed
+    /// according to the sign options after rounding.
+    pub fn format_fixed_decimal(
+        &self,
+        value: &FixedDecimal,
+        currency_code: CurrencyCode,
+    ) -> FormattedCurrency<'_> {
+        let mut value = round_to_currency_digits(self.fractions.get(), value, currency_code);
+        if let Some(sign_display) = self.options.sign_display {
+            value.apply_sign_display(sign_display);
+        }
+
+        // The sign is placed around the whole currency pattern, so only the
+        // absolute value is formatted by the `FixedDecimalFormatter`.
+        let sign = value.sign();
+        value.set_sign(Sign::None);
+
+        FormattedCurrency {
+            value,
+            sign,
+            currency_code,
+            options: &self.options,
+            essential: self.essential.get(),
+            accounting: self.accounting.as_ref().map(|accounting| accounting.get()),
+            decimal_symbols: self.decimal_symbols.get(),
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::currency::options::{SignDisplay, Width};
+    use icu_locid::locale;
+    use tinystr::tinystr;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_currency_widths() {
+        let cases = [
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "EGP"),
+                "12345.67",
+                "EGP\u{a0}12,345.67",
+            ),
+            (
+                locale!("en"),
+                Width::Narrow,
+                tinystr!(3, "EGP"),
+                "12345.67",
+                "E£12,345.67",
+            ),
+            (
+                locale!("en"),
+                Width::Standard,
+                tinystr!(3, "USD"),
+                "12345.67",
+                "USD\u{a0}12,345.67",
+            ),
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "USD"),
+                "5",
+                "$5.00",
+            ),
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "USD"),
+                "-5",
+                "-$5.00",
+            ),
+            (
+                locale!("en"),
+                Width::Short,
+                tinystr!(3, "BHD"),
+                "1.5",
+                "BHD\u{a0}1.500",
+            ),
+            (
+                locale!("fr"),
+                Width::Standard,
+                tinystr!(3, "EUR"),
+                "12345.67",
+                "12\u{202f}345,67\u{a0}EUR",
+            ),
+        ];
+        for (locale, width, code, input, expected) in cases {
+            let fmt = CurrencyFormatter::try_new(&locale.into(), width.into()).unwrap();
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(
+                fmt.format_fixed_decimal(&value, CurrencyCode(code)),
+                expected,
+                "{width:?} {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_currency_signs() {
+        let cases = [
+            (
+                locale!("en"),
+                CurrencySign::Standard,
+                None,
+                tinystr!(3, "USD"),
+                "-5",
+                "-$5.00",
+            ),
+            (
+                locale!("ar-u-nu-latn"),
+                CurrencySign::Standard,
+                None,
+                tinystr!(3, "USD"),
+                "-5",
+                "\u{200f}\u{200e}-5.00\u{a0}US$",
+            ),
+            (
+                locale!("en"),
+                CurrencySign::Accounting,
+                None,
+                tinystr!(3, "USD"),
+                "-5",
+                "($5.00)",
+            ),
+            (
+                locale!("en"),
+                CurrencySign::Accounting,
+                None,
+                tinystr!(3, "USD"),
+                "5",
+                "$5.00",
+            ),
+            (
+                locale!("en"),
+                CurrencySign::Accounting,
+                None,
+                tinystr!(3, "EGP"),
+                "-5",
+                "(EGP\u{a0}5.00)",
+            ),
+            (
+                locale!("fr"),
+                CurrencySign::Accounting,
+                None,
+                tinystr!(3, "EUR"),
+                "-5",
+                "(5,00\u{a0}€)",
+            ),
+            (
+                locale!("es"),
+                CurrencySign::Accounting,
+                None,
+                tinystr!(3, "EUR"),
+                "-5",
+                "-5,00\u{a0}€",
+            ),
+            (
+                locale!("en"),
+                CurrencySign::Standard,
+                Some(SignDisplay::Always),
+                tinystr!(3, "USD"),
+                "5",
+                "+$5.00",
+            ),
+            (
+                locale!("en"),
+                CurrencySign::Standard,
+                Some(SignDisplay::ExceptZero),
+                tinystr!(3, "USD"),
+                "-0.001",
+                "$0.00",
+            ),
+            (
+                locale!("en"),
+                CurrencySign::Accounting,
+                Some(SignDisplay::Never),
+                tinystr!(3, "USD"),
+                "-5",
+                "$5.00",
+            ),
+        ];
+        for (locale, currency_sign, sign_display, code, input, expected) in cases {
+            let options = CurrencyFormatterOptions {
+                currency_sign,
+                sign_display,
+                ..Default::default()
+            };
+            let fmt = CurrencyFormatter::try_new(&(&locale).into(), options).unwrap();
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(
+                fmt.format_fixed_decimal(&value, CurrencyCode(code)),
+                expected,
+                "{locale} {currency_sign:?} {sign_display:?} {input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_pattern::{DoublePlaceholderKey, PatternItem};
+use icu_plurals::PluralCategory;
+use writeable::Writeable;
+
+use crate::compactdecimal::FormattedCompactDecimal;
+use crate::dimension::currency::long_format::display_name;
+use crate::dimension::currency::CurrencyCode;
+use crate::dimension::provider::currency::CurrencyLongNamesV1;
+
+/// An intermediate structure returned by [`LongCompactCurrencyFormatter`](crate::dimension::currency::LongCompactCurrencyFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted currency to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedLongCompactCurrency<'l> {
+    /// The value of the amount, in long compact decimal notation.
+    pub(crate) value: FormattedCompactDecimal<'l>,
+    pub(crate) currency_code: CurrencyCode,
+    pub(crate) plural_category: PluralCategory,
+    pub(crate) long_names: &'l CurrencyLongNamesV1<'l>,
+}
+
+impl<'l> Writeable for FormattedLongCompactCurrency<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let pattern = self.long_names.unit_patterns.get(self.plural_category);
+
+        for item in pattern.iter() {
+            match item {
+                PatternItem::Literal(s) => sink.write_str(s)?,
+                PatternItem::Placeholder(DoublePlaceholderKey::Place0) => {
+                    self.value.write_to(sink)?
+                }
+                PatternItem::Placeholder(DoublePlaceholderKey::Place1) => sink.write_str(
+                    display_name(self.long_names, &self.currency_code, self.plural_category),
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedLongCompactCurrency<'_>);
@@ -0,0 +1,198 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_provider::prelude::*;
+
+use crate::compactdecimal::provider::LongCompactDecimalFormatDataV1Marker;
+use crate::compactdecimal::{compact_plural_category, CompactDecimalFormatter};
+use crate::dimension::currency::long_compact_format::FormattedLongCompactCurrency;
+use crate::dimension::currency::CurrencyCode;
+use crate::dimension::provider::currency::CurrencyLongNamesV1Marker;
+use crate::dimension::DimensionError;
+
+/// A formatter for monetary values in long compact notation that spells out the name
+/// of the currency, such as "1.2 thousand US dollars".
+///
+/// [`LongCompactCurrencyFormatter`] combines the long compact notation of
+/// [`CompactDecimalFormatter`] with the unit patterns and the display names of
+/// [`LongCurrencyFormatter`](crate::dimension::currency::LongCurrencyFormatter).
+/// The unit pattern and the display name are selected by the plural category of
+/// the rounded value.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::dimension::currency::{CurrencyCode, LongCompactCurrencyFormatter};
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let fmt = LongCompactCurrencyFormatter::try_new(&locale!("en").into())
+///     .expect("locale should be present");
+///
+/// let value = "1234567".parse().unwrap();
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "1.2 million US dollars"
+/// );
+///
+/// let value = "1".parse().unwrap();
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "1 US dollar"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct LongCompactCurrencyFormatter {
+    /// The display names of the currencies and the patterns to place them in.
+    long_names: DataPayload<CurrencyLongNamesV1Marker>,
+
+    /// A long [`CompactDecimalFormatter`] to round and format the value.
+    compact_decimal_formatter: CompactDecimalFormatter,
+}
+
+impl LongCompactCurrencyFormatter {
+    /// Creates a new [`LongCompactCurrencyFormatter`] from compiled locale data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(locale: &DataLocale) -> Result<Self, DimensionError> {
+        let compact_decimal_formatter =
+            CompactDecimalFormatter::try_new_long(locale, Default::default())?;
+        let long_names = DataProvider::<CurrencyLongNamesV1Marker>::load(
+            &crate::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            long_names,
+            compact_decimal_formatter,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: DimensionError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(provider: &D, locale: &DataLocale) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<CurrencyLongNamesV1Marker>
+            + DataProvider<LongCompactDecimalFormatDataV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let compact_decimal_formatter =
+            CompactDecimalFormatter::try_new_long_unstable(provider, locale, Default::default())?;
+        let long_names = DataProvider::<CurrencyLongNamesV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            long_names,
+            compact_decimal_formatter,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] value for the given currency code in long compact notation.
+    ///
+    /// The value is rounded as by [`CompactDecimalFormatter::format_fixed_decimal()`], so
+    /// the number of fraction digits of the currency is not used.
+    pub fn format_fixed_decimal(
+        &self,
+        value: &FixedDecimal,
+        currency_code: CurrencyCode,
+    ) -> FormattedLongCompactCurrency<'_> {
+        let value = self
+            .compact_decimal_formatter
+            .format_fixed_decimal(value.clone());
+        let plural_category = compact_plural_category(
+            value.get_compact_decimal(),
+            &self.compact_decimal_formatter.plural_rules,
+        );
+
+        FormattedLongCompactCurrency {
+            value,
+            currency_code,
+            plural_category,
+            long_names: self.long_names.get(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_long_compact_currency() {
+        let cases = [
+            (locale!("en"), tinystr!(3, "USD"), "1", "1 US dollar"),
+            (locale!("en"), tinystr!(3, "USD"), "843", "843 US dollars"),
+            (
+                locale!("en"),
+                tinystr!(3, "USD"),
+                "1000",
+                "1 thousand US dollars",
+            ),
+            (
+                locale!("en"),
+                tinystr!(3, "USD"),
+                "-2207",
+                "-2.2 thousand US dollars",
+            ),
+            (locale!("fr"), tinystr!(3, "EUR"), "1", "1 euro"),
+            (
+                locale!("fr"),
+                tinystr!(3, "EUR"),
+                "35357670",
+                "35 millions euros",
+            ),
+            (locale!("ja"), tinystr!(3, "JPY"), "35357670", "3536万円"),
+            (
+                locale!("en"),
+                tinystr!(3, "XYZ"),
+                "1234567",
+                "1.2 million XYZ",
+            ),
+        ];
+        for (locale, code, input, expected) in cases {
+            let fmt = LongCompactCurrencyFormatter::try_new(&(&locale).into()).unwrap();
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(
+                fmt.format_fixed_decimal(&value, CurrencyCode(code)),
+                expected,
+                "{locale} {code} {input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::FixedDecimalFormatter;
+use icu_pattern::{DoublePlaceholderKey, PatternItem};
+use icu_plurals::PluralCategory;
+use writeable::Writeable;
+
+use crate::dimension::currency::CurrencyCode;
+use crate::dimension::provider::currency::CurrencyLongNamesV1;
+
+/// An intermediate structure returned by [`LongCurrencyFormatter`](crate::dimension::currency::LongCurrencyFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted currency to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedLongCurrency<'l> {
+    pub(crate) value: FixedDecimal,
+    pub(crate) currency_code: CurrencyCode,
+    pub(crate) plural_category: PluralCategory,
+    pub(crate) long_names: &'l CurrencyLongNamesV1<'l>,
+    pub(crate) fixed_decimal_formatter: &'l FixedDecimalFormatter,
+}
+
+/// Returns the display name of the currency that agrees with the plural category.
+pub(crate) fn display_name<'a>(
+    long_names: &'a CurrencyLongNamesV1<'_>,
+    currency_code: &'a CurrencyCode,
+    plural_category: PluralCategory,
+) -> &'a str {
+    long_names
+        .display_names
+        .get0(&currency_code.0.to_unvalidated())
+        .and_then(|names| {
+            names
+                .get1(&plural_category)
+                .or_else(|| names.get1(&PluralCategory::Other))
+        })
+        // Currencies without a display name are displayed with their ISO code.
+        .unwrap_or(currency_code.0.as_str())
+}
+
+impl<'l> Writeable for FormattedLongCurrency<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let formatted_value = self.fixed_decimal_formatter.format(&self.value);
+        let pattern = self.long_names.unit_patterns.get(self.plural_category);
+
+        for item in pattern.iter() {
+            match item {
+                PatternItem::Literal(s) => sink.write_str(s)?,
+                PatternItem::Placeholder(DoublePlaceholderKey::Place0) => {
+                    formatted_value.write_to(sink)?
+                }
+                PatternItem::Placeholder(DoublePlaceholderKey::Place1) => sink.write_str(
+                    display_name(self.long_names, &self.currency_code, self.plural_category),
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedLongCurrency<'_>);
@@ -0,0 +1,201 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV2Marker, FixedDecimalFormatter,
+};
+use icu_plurals::{provider::CardinalV1Marker, PluralRules};
+use icu_provider::prelude::*;
+
+use crate::dimension::currency::long_format::FormattedLongCurrency;
+use crate::dimension::currency::{round_to_currency_digits, CurrencyCode};
+use crate::dimension::provider::currency::{CurrencyFractionsV1Marker, CurrencyLongNamesV1Marker};
+use crate::dimension::DimensionError;
+
+/// A formatter for monetary values that spells out the name of the currency,
+/// such as "12.00 euros".
+///
+/// [`LongCurrencyFormatter`] supports:
+///   1. Rendering in the locale's currency system.
+///   2. Locale-sensitive grouping separator positions.
+///   3. Selecting the display name of the currency that agrees with the plural category
+///      of the formatted number.
+///   4. Rounding the value to the default number of fraction digits of the currency.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::dimension::currency::{CurrencyCode, LongCurrencyFormatter};
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let fmt = LongCurrencyFormatter::try_new(&locale!("en").into())
+///     .expect("locale should be present");
+///
+/// let value = "12345.67".parse().unwrap();
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "USD"))),
+///     "12,345.67 US dollars"
+/// );
+///
+/// // The Chilean peso has no minor unit, so 1 selects the singular name.
+/// let value = "1".parse().unwrap();
+/// assert_writeable_eq!(
+///     fmt.format_fixed_decimal(&value, CurrencyCode(tinystr!(3, "CLP"))),
+///     "1 Chilean peso"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct LongCurrencyFormatter {
+    /// The display names of the currencies and the patterns to place them in.
+    long_names: DataPayload<CurrencyLongNamesV1Marker>,
+
+    /// The number of fraction digits of each currency.
+    fractions: DataPayload<CurrencyFractionsV1Marker>,
+
+    /// A [`FixedDecimalFormatter`] to format the currency value.
+    fixed_decimal_formatter: FixedDecimalFormatter,
+
+    /// A [`PluralRules`] to select the display name and pattern for the currency value.
+    plural_rules: PluralRules,
+}
+
+impl LongCurrencyFormatter {
+    /// Creates a new [`LongCurrencyFormatter`] from compiled locale data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(locale: &DataLocale) -> Result<Self, DimensionError> {
+        let fixed_decimal_formatter =
+            FixedDecimalFormatter::try_new(locale, FixedDecimalFormatterOptions::default())?;
+        let plural_rules = PluralRules::try_new_cardinal(locale)?;
+        let long_names = DataProvider::<CurrencyLongNamesV1Marker>::load(
+            &crate::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let fractions = DataProvider::<CurrencyFractionsV1Marker>::load(
+            &crate::provider::Baked,
+            Default::default(),
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            long_names,
+            fractions,
+            fixed_decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: DimensionError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(provider: &D, locale: &DataLocale) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<CurrencyLongNamesV1Marker>
+            + DataProvider<CurrencyFractionsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            locale,
+            FixedDecimalFormatterOptions::default(),
+        )?;
+        let plural_rules = PluralRules::try_new_cardinal_unstable(provider, locale)?;
+        let long_names = DataProvider::<CurrencyLongNamesV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let fractions =
+            DataProvider::<CurrencyFractionsV1Marker>::load(provider, Default::default())?
+                .take_payload()?;
+
+        Ok(Self {
+            long_names,
+            fractions,
+            fixed_decimal_formatter,
+            plural_rules,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] value for the given currency code.
+    ///
+    /// The value is rounded to the default number of fraction digits of the currency,
+    /// and the display name is selected by the plural category of the rounded value.
+    /// Currencies without a display name are displayed with their ISO 4217 code.
+    pub fn format_fixed_decimal(
+        &self,
+        value: &FixedDecimal,
+        currency_code: CurrencyCode,
+    ) -> FormattedLongCurrency<'_> {
+        let value = round_to_currency_digits(self.fractions.get(), value, currency_code);
+
+        let plural_category = self.plural_rules.category_for(&value);
+
+        FormattedLongCurrency {
+            value,
+            currency_code,
+            plural_category,
+            long_names: self.long_names.get(),
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_long_currency() {
+        let cases = [
+            (locale!("en"), tinystr!(3, "USD"), "1", "1.00 US dollars"),
+            (locale!("en"), tinystr!(3, "CLP"), "1", "1 Chilean peso"),
+            (locale!("en"), tinystr!(3, "CLP"), "1.4", "1 Chilean peso"),
+            (locale!("en"), tinystr!(3, "CLP"), "2", "2 Chilean pesos"),
+            (locale!("fr"), tinystr!(3, "EUR"), "1", "1,00 euro"),
+            (locale!("fr"), tinystr!(3, "EUR"), "12", "12,00 euros"),
+            (locale!("ja"), tinystr!(3, "JPY"), "12", "12円"),
+            (locale!("en"), tinystr!(3, "XYZ"), "12", "12.00 XYZ"),
+        ];
+        for (locale, code, input, expected) in cases {
+            let fmt = LongCurrencyFormatter::try_new(&(&locale).into()).unwrap();
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(
+                fmt.format_fixed_decimal(&value, CurrencyCode(code)),
+                expected,
+                "{locale} {code}"
+            );
+        }
+    }
+}
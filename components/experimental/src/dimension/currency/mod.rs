@@ -0,0 +1,50 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Currency formatting
+
+use crate::dimension::provider::currency::CurrencyFractionsV1;
+use fixed_decimal::FixedDecimal;
+use tinystr::TinyAsciiStr;
+
+mod compact_format;
+mod compact_formatter;
+mod format;
+mod formatter;
+mod long_compact_format;
+mod long_compact_formatter;
+mod long_format;
+mod long_formatter;
+pub mod options;
+
+pub use compact_format::FormattedCompactCurrency;
+pub use compact_formatter::CompactCurrencyFormatter;
+pub use format::FormattedCurrency;
+pub use formatter::CurrencyFormatter;
+pub use long_compact_format::FormattedLongCompactCurrency;
+pub use long_compact_formatter::LongCompactCurrencyFormatter;
+pub use long_format::FormattedLongCurrency;
+pub use long_formatter::LongCurrencyFormatter;
+pub use options::{CompactCurrencyFormatterOptions, CurrencyFormatterOptions};
+
+/// An ISO 4217 currency code, such as `USD` or `EUR`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CurrencyCode(pub TinyAsciiStr<3>);
+
+/// Returns the value rounded and padded to the default number of fraction digits of the
+/// currency, such as two for USD and zero for JPY.
+fn round_to_currency_digits(
+    fractions: &CurrencyFractionsV1,
+    value: &FixedDecimal,
+    currency_code: CurrencyCode,
+) -> FixedDecimal {
+    let digits = fractions
+        .digits_map
+        .get_copied(&currency_code.0.to_unvalidated())
+        .unwrap_or(fractions.default_digits) as i16;
+    let mut value = value.clone();
+    value.half_expand(-digits);
+    value.pad_end(-digits);
+    value
+}
@@ -0,0 +1,79 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for configuring [`CurrencyFormatter`](crate::dimension::currency::CurrencyFormatter).
+
+pub use fixed_decimal::SignDisplay;
+
+/// A bag of options for defining how to format currency using
+/// [`CurrencyFormatter`](crate::dimension::currency::CurrencyFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CurrencyFormatterOptions {
+    /// How to display the currency next to the number.
+    pub width: Width,
+
+    /// Whether to use the standard or the accounting patterns of the locale.
+    pub currency_sign: CurrencySign,
+
+    /// When to display the sign of the value. The sign is chosen after rounding
+    /// the value to the number of fraction digits of the currency.
+    ///
+    /// If unset, the sign of the value is displayed as it is.
+    pub sign_display: Option<SignDisplay>,
+}
+
+impl From<Width> for CurrencyFormatterOptions {
+    fn from(width: Width) -> Self {
+        Self {
+            width,
+            ..Default::default()
+        }
+    }
+}
+
+/// A bag of options for defining how to format currency using
+/// [`CompactCurrencyFormatter`](crate::dimension::currency::CompactCurrencyFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CompactCurrencyFormatterOptions {
+    /// How to display the currency next to the number.
+    pub width: Width,
+}
+
+impl From<Width> for CompactCurrencyFormatterOptions {
+    fn from(width: Width) -> Self {
+        Self { width }
+    }
+}
+
+/// Configures how the currency is displayed next to the number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Width {
+    /// Display the currency symbol, such as "US$" for USD in most English locales.
+    ///
+    /// Currencies without a symbol are displayed with their ISO 4217 code.
+    #[default]
+    Short,
+
+    /// Display the narrow currency symbol, such as "$" for USD.
+    ///
+    /// Currencies without a narrow symbol are displayed as with [`Width::Short`].
+    Narrow,
+
+    /// Display the ISO 4217 code of the currency, such as "USD".
+    Standard,
+}
+
+/// Configures how negative values are marked, following the `currencySign` option of ECMA-402.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum CurrencySign {
+    /// Mark negative values with the minus sign of the locale, such as "-$5.00" in English.
+    #[default]
+    Standard,
+
+    /// Use the accounting patterns of the locale, which mark negative values with
+    /// parentheses in many locales, such as "($5.00)" in English.
+    ///
+    /// Locales without a negative accounting pattern use the minus sign.
+    Accounting,
+}
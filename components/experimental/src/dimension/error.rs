@@ -0,0 +1,53 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::compactdecimal::CompactDecimalError;
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for the formatters in the [`dimension`](crate::dimension) module.
+///
+/// Re-exported as [`Error`](crate::dimension::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DimensionError {
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+    /// An error originating from [`CompactDecimalFormatter`](crate::compactdecimal::CompactDecimalFormatter).
+    #[displaydoc("Error loading CompactDecimalFormatter: {0}")]
+    CompactDecimal(CompactDecimalError),
+}
+
+impl From<PluralsError> for DimensionError {
+    fn from(e: PluralsError) -> Self {
+        DimensionError::PluralRules(e)
+    }
+}
+
+impl From<DataError> for DimensionError {
+    fn from(e: DataError) -> Self {
+        DimensionError::Data(e)
+    }
+}
+
+impl From<DecimalError> for DimensionError {
+    fn from(e: DecimalError) -> Self {
+        DimensionError::Decimal(e)
+    }
+}
+
+impl From<CompactDecimalError> for DimensionError {
+    fn from(e: CompactDecimalError) -> Self {
+        DimensionError::CompactDecimal(e)
+    }
+}
@@ -2,5 +2,13 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+pub mod currency;
+mod error;
+pub mod percent;
 pub mod provider;
 pub mod ule;
+pub mod units;
+
+pub use error::DimensionError;
+#[doc(no_inline)]
+pub use DimensionError as Error;
@@ -0,0 +1,106 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_decimal::FixedDecimalFormatter;
+use writeable::{Part, PartsWrite, Writeable};
+
+use crate::dimension::provider::percent::PercentEssentialsV1;
+
+/// The [`Part`]s used by [`FormattedPercent`], in addition to the [`parts`](icu_decimal::parts)
+/// of the number.
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the percent or permille sign, such as "%" in "50%".
+    ///
+    /// * `category`: `"percent"`
+    /// * `value`: `"percentSign"`
+    pub const PERCENT_SIGN: Part = Part {
+        category: "percent",
+        value: "percentSign",
+    };
+}
+
+/// An intermediate structure returned by [`PercentFormatter`](crate::dimension::percent::PercentFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted percentage to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedPercent<'l> {
+    /// The value, already multiplied by 100 or 1000.
+    pub(crate) value: FixedDecimal,
+    /// The percent or permille sign.
+    pub(crate) symbol: &'l str,
+    pub(crate) essential: &'l PercentEssentialsV1<'l>,
+    pub(crate) fixed_decimal_formatter: &'l FixedDecimalFormatter,
+}
+
+impl<'l> FormattedPercent<'l> {
+    /// Writes the sign together with the affixes that separate it from the number.
+    fn write_symbol<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let affixes = &self.essential.percent_sign_affixes;
+        sink.write_str(&affixes.prefix)?;
+        sink.with_part(parts::PERCENT_SIGN, |s| s.write_str(self.symbol))?;
+        sink.write_str(&affixes.suffix)
+    }
+}
+
+/// A [`PartsWrite`] that writes the percent sign before the integer digits of the number, so
+/// that a sign prefix, together with its bidi marks, goes before a percent sign prefix, as in
+/// "-%12" in Turkish.
+struct SymbolAfterSign<'a, 'l, W: ?Sized> {
+    sink: &'a mut W,
+    percent: &'a FormattedPercent<'l>,
+    symbol_written: bool,
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for SymbolAfterSign<'_, '_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.sink.write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.sink.write_char(c)
+    }
+}
+
+impl<W: PartsWrite + ?Sized> PartsWrite for SymbolAfterSign<'_, '_, W> {
+    type SubPartsWrite = W::SubPartsWrite;
+
+    fn with_part(
+        &mut self,
+        part: Part,
+        f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        if !self.symbol_written && part == icu_decimal::parts::INTEGER {
+            self.symbol_written = true;
+            self.percent.write_symbol(self.sink)?;
+        }
+        self.sink.with_part(part, f)
+    }
+}
+
+impl<'l> Writeable for FormattedPercent<'l> {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let formatted_value = self.fixed_decimal_formatter.format(&self.value);
+
+        if self.essential.percent_symbol_index < self.essential.number_index {
+            let mut sink = SymbolAfterSign {
+                sink,
+                percent: self,
+                symbol_written: false,
+            };
+            formatted_value.write_to_parts(&mut sink)?;
+            if !sink.symbol_written {
+                self.write_symbol(sink.sink)?;
+            }
+            Ok(())
+        } else {
+            formatted_value.write_to_parts(sink)?;
+            self.write_symbol(sink)
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedPercent<'_>);
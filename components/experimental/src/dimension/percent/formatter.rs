@@ -0,0 +1,280 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::{provider::DecimalSymbolsV2Marker, FixedDecimalFormatter};
+use icu_provider::prelude::*;
+
+use crate::dimension::percent::format::FormattedPercent;
+use crate::dimension::percent::options::{PercentFormatterOptions, PercentStyle};
+use crate::dimension::provider::percent::{PercentEssentialsV1Marker, PermilleSymbolV1Marker};
+use crate::dimension::DimensionError;
+
+/// A formatter for percentages and permilles.
+///
+/// [`PercentFormatter`] supports:
+///   1. Multiplying the value by 100 for percentages, or by 1000 for permilles.
+///   2. Placing the percent or permille sign according to the percent pattern of the locale,
+///      including the spacing between the sign and the number.
+///   3. The digit options of [`FixedDecimalFormatter`].
+///
+/// Read more about the options in the [`options`](crate::dimension::percent::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::dimension::percent::options::PercentStyle;
+/// use icu_experimental::dimension::percent::PercentFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let value = "0.125".parse().unwrap();
+///
+/// let fmt = PercentFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(fmt.format(&value), "12.5%");
+///
+/// let fmt = PercentFormatter::try_new(&locale!("fr").into(), Default::default())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(fmt.format(&value), "12,5\u{a0}%");
+///
+/// let fmt = PercentFormatter::try_new(&locale!("en").into(), PercentStyle::Permille.into())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(fmt.format(&value), "125‰");
+/// ```
+///
+/// The number of digits can be configured with the options of [`FixedDecimalFormatter`]:
+///
+/// ```
+/// use icu_decimal::options::FixedDecimalFormatterOptions;
+/// use icu_experimental::dimension::percent::PercentFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = FixedDecimalFormatterOptions::default();
+/// options.maximum_fraction_digits = Some(0);
+/// let fmt = PercentFormatter::try_new(&locale!("tr").into(), options.into())
+///     .expect("locale should be present");
+///
+/// assert_writeable_eq!(fmt.format(&"0.1234".parse().unwrap()), "%12");
+/// ```
+#[derive(Debug)]
+pub struct PercentFormatter {
+    /// The percent pattern and sign of the locale.
+    essential: DataPayload<PercentEssentialsV1Marker>,
+
+    /// The permille sign, only loaded for [`PercentStyle::Permille`].
+    permille: Option<DataPayload<PermilleSymbolV1Marker>>,
+
+    /// A [`FixedDecimalFormatter`] to format the multiplied value.
+    fixed_decimal_formatter: FixedDecimalFormatter,
+}
+
+impl PercentFormatter {
+    /// Creates a new [`PercentFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: PercentFormatterOptions,
+    ) -> Result<Self, DimensionError> {
+        let fixed_decimal_formatter =
+            FixedDecimalFormatter::try_new(locale, options.fixed_decimal_formatter_options)?;
+        let essential = DataProvider::<PercentEssentialsV1Marker>::load(
+            &crate::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let permille = match options.style {
+            PercentStyle::Percent => None,
+            PercentStyle::Permille => Some(
+                DataProvider::<PermilleSymbolV1Marker>::load(
+                    &crate::provider::Baked,
+                    DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    },
+                )?
+                .take_payload()?,
+            ),
+        };
+
+        Ok(Self {
+            essential,
+            permille,
+            fixed_decimal_formatter,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: PercentFormatterOptions,
+        error: DimensionError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: PercentFormatterOptions,
+    ) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<PercentEssentialsV1Marker>
+            + DataProvider<PermilleSymbolV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            locale,
+            options.fixed_decimal_formatter_options,
+        )?;
+        let essential = DataProvider::<PercentEssentialsV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let permille = match options.style {
+            PercentStyle::Percent => None,
+            PercentStyle::Permille => Some(
+                DataProvider::<PermilleSymbolV1Marker>::load(
+                    provider,
+                    DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    },
+                )?
+                .take_payload()?,
+            ),
+        };
+
+        Ok(Self {
+            essential,
+            permille,
+            fixed_decimal_formatter,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] value as a percentage or a permille.
+    ///
+    /// The value is a fraction of one, so 0.5 is formatted as "50%" or "500‰".
+    pub fn format(&self, value: &FixedDecimal) -> FormattedPercent<'_> {
+        let essential = self.essential.get();
+        let (symbol, delta) = match &self.permille {
+            Some(permille) => (&*permille.get().permille_sign_symbol, 3),
+            None => (&*essential.percent_sign_symbol, 2),
+        };
+        let mut value = value.clone();
+        value.multiply_pow10(delta);
+        // The integer zero of values such as 0.5 would otherwise become a leading zero.
+        value.trim_start();
+
+        FormattedPercent {
+            value,
+            symbol,
+            essential,
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_decimal::options::FixedDecimalFormatterOptions;
+    use icu_locid::locale;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_percent() {
+        let cases = [
+            (locale!("en"), PercentStyle::Percent, "0.5", "50%"),
+            (locale!("en"), PercentStyle::Percent, "-0.125", "-12.5%"),
+            (locale!("en"), PercentStyle::Percent, "12.5", "1,250%"),
+            (locale!("en"), PercentStyle::Permille, "0.5", "500‰"),
+            (locale!("fr"), PercentStyle::Percent, "0.5", "50\u{a0}%"),
+            (locale!("fr"), PercentStyle::Permille, "0.5", "500\u{a0}‰"),
+            (locale!("tr"), PercentStyle::Percent, "0.5", "%50"),
+            (locale!("tr"), PercentStyle::Percent, "-0.5", "-%50"),
+        ];
+        for (locale, style, input, expected) in cases {
+            let fmt = PercentFormatter::try_new(&(&locale).into(), style.into()).unwrap();
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&value), expected, "{locale} {style:?} {input}");
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        use crate::dimension::percent::parts::PERCENT_SIGN;
+        use icu_decimal::parts;
+        use writeable::assert_writeable_parts_eq;
+
+        let fmt = PercentFormatter::try_new(&locale!("tr").into(), Default::default()).unwrap();
+        assert_writeable_parts_eq!(
+            fmt.format(&"-0.5".parse().unwrap()),
+            "-%50",
+            [
+                (0, 1, parts::MINUS_SIGN),
+                (1, 2, PERCENT_SIGN),
+                (2, 4, parts::INTEGER)
+            ]
+        );
+
+        // The percent sign goes after the bidi mark of the minus sign, as in Arabic.
+        let fmt = PercentFormatter {
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new(
+                &locale!("ar").into(),
+                Default::default(),
+            )
+            .unwrap(),
+            ..fmt
+        };
+        assert_writeable_parts_eq!(
+            fmt.format(&"-0.5".parse().unwrap()),
+            "\u{61c}-%٥٠",
+            [
+                (0, 2, parts::LITERAL),
+                (2, 3, parts::MINUS_SIGN),
+                (3, 4, PERCENT_SIGN),
+                (4, 8, parts::INTEGER)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_precision() {
+        let mut options = FixedDecimalFormatterOptions::default();
+        options.minimum_fraction_digits = Some(1);
+        options.maximum_fraction_digits = Some(1);
+        let fmt = PercentFormatter::try_new(&locale!("en").into(), options.into()).unwrap();
+
+        let cases = [
+            ("0.5", "50.0%"),
+            ("0.12345", "12.3%"),
+            ("0.99999", "100.0%"),
+        ];
+        for (input, expected) in cases {
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&value), expected, "{input}");
+        }
+    }
+}
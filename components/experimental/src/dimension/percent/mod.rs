@@ -0,0 +1,13 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Percent and permille formatting
+
+mod format;
+mod formatter;
+pub mod options;
+
+pub use format::{parts, FormattedPercent};
+pub use formatter::PercentFormatter;
+pub use options::PercentFormatterOptions;
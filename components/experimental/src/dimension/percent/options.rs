@@ -0,0 +1,49 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for configuring [`PercentFormatter`](crate::dimension::percent::PercentFormatter).
+
+use icu_decimal::options::FixedDecimalFormatterOptions;
+
+/// A bag of options for defining how to format percentages using
+/// [`PercentFormatter`](crate::dimension::percent::PercentFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PercentFormatterOptions {
+    /// Whether the value is displayed per hundred or per thousand.
+    pub style: PercentStyle,
+
+    /// Options for rendering the number, such as the number of fraction or significant digits.
+    ///
+    /// The digit options apply to the number after it has been multiplied by 100 or 1000.
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+}
+
+impl From<PercentStyle> for PercentFormatterOptions {
+    fn from(style: PercentStyle) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<FixedDecimalFormatterOptions> for PercentFormatterOptions {
+    fn from(fixed_decimal_formatter_options: FixedDecimalFormatterOptions) -> Self {
+        Self {
+            fixed_decimal_formatter_options,
+            ..Default::default()
+        }
+    }
+}
+
+/// Configures the unit of the formatted value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum PercentStyle {
+    /// Multiply the value by 100 and display it with the percent sign, such as "12%".
+    #[default]
+    Percent,
+
+    /// Multiply the value by 1000 and display it with the permille sign, such as "125‰".
+    Permille,
+}
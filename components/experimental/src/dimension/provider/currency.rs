@@ -10,12 +10,15 @@
 //! Read more about data providers: [`icu_provider`]
 
 use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
 use icu_provider::prelude::*;
 use tinystr::UnvalidatedTinyAsciiStr;
-use zerovec::{VarZeroVec, ZeroMap};
+use zerovec::{VarZeroVec, ZeroMap, ZeroMap2d};
 
 use icu_pattern::DoublePlaceholderPattern;
 
+use crate::compactdecimal::provider::{Count, PatternULE};
+
 #[cfg(feature = "compiled_data")]
 /// Baked data
 ///
@@ -49,20 +52,34 @@ pub struct CurrencyEssentialsV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub pattern_config_map: ZeroMap<'data, UnvalidatedTinyAsciiStr<3>, CurrencyPatternConfig>,
 
-    // TODO(#4677): Implement the pattern to accept the signed negative and signed positive patterns.
+    // TODO(#4677): Implement the pattern to accept the signed positive patterns.
     /// Represents the standard pattern.
     /// NOTE: place holder 0 is the place of the currency value.
     ///       place holder 1 is the place of the currency sign `¤`.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub standard_pattern: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
 
-    // TODO(#4677): Implement the pattern to accept the signed negative and signed positive patterns.
+    // TODO(#4677): Implement the pattern to accept the signed positive patterns.
     /// Represents the standard alpha_next_to_number pattern.
     /// NOTE: place holder 0 is the place of the currency value.
     ///       place holder 1 is the place of the currency sign `¤`.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub standard_alpha_next_to_number_pattern: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
 
+    /// Represents the standard pattern for negative values, which already carries the
+    /// localized minus sign, such as "¤ -#,##0.00" in Dutch.
+    /// If the value is `None`, negative values are formatted with `standard_pattern`
+    /// and a minus sign before it.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative_standard_pattern: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+
+    /// Represents the standard alpha_next_to_number pattern for negative values.
+    /// If the value is `None`, negative values are formatted with
+    /// `standard_alpha_next_to_number_pattern` and a minus sign before it.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative_standard_alpha_next_to_number_pattern:
+        Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+
     /// Contains all the place holders.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub placeholders: VarZeroVec<'data, str>,
@@ -128,3 +145,183 @@ pub struct CurrencyPatternConfig {
     /// If the value is `None`, this means that the narrow pattern does not have a place holder.
     pub narrow_placeholder_value: Option<PlaceholderValue>,
 }
+
+/// This type contains the number of fraction digits to use when formatting each currency.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(CurrencyFractionsV1Marker, "currency/fractions@1", singleton))]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::dimension::provider::currency),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyFractionsV1<'data> {
+    /// A mapping from each currency's ISO code to its number of fraction digits.
+    /// Only the currencies that do not use `default_digits` are included.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub digits_map: ZeroMap<'data, UnvalidatedTinyAsciiStr<3>, u8>,
+
+    /// The number of fraction digits for the currencies that are not in `digits_map`.
+    pub default_digits: u8,
+}
+
+/// This type contains the accounting patterns for currency formatting, such as
+/// "¤#,##0.00;(¤#,##0.00)" in English, which wraps negative amounts in parentheses.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(CurrencyAccountingPatternsV1Marker = "currency/accounting@1")]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::dimension::provider::currency),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyAccountingPatternsV1<'data> {
+    /// Represents the accounting pattern for non-negative values.
+    /// NOTE: place holder 0 is the place of the currency value.
+    ///       place holder 1 is the place of the currency sign `¤`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pattern: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+
+    /// Represents the accounting alpha_next_to_number pattern for non-negative values.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub alpha_next_to_number_pattern: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+
+    /// Represents the accounting pattern for negative values, which already carries
+    /// the negative marking of the locale, such as the parentheses in "(¤#,##0.00)".
+    /// If the value is `None`, negative values are formatted with `pattern` and a minus sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative_pattern: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+
+    /// Represents the accounting alpha_next_to_number pattern for negative values.
+    /// If the value is `None`, negative values are formatted with `alpha_next_to_number_pattern`
+    /// and a minus sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative_alpha_next_to_number_pattern: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+}
+
+/// This type contains the short compact patterns for currency formatting, such as "¤0K"
+/// for thousands in English.
+///
+/// The patterns are stored in the format of
+/// [`CompactDecimalPatternDataV1`](crate::compactdecimal::provider::CompactDecimalPatternDataV1),
+/// except that the literal text keeps the currency sign `¤`, which is replaced by the currency
+/// symbol when formatting.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(ShortCurrencyCompactV1Marker = "currency/compact@1")]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::dimension::provider::currency),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct ShortCurrencyCompactV1<'data> {
+    /// The compact patterns for the standard currency pattern, keyed on log10 of the CLDR
+    /// `type` attribute and the CLDR `count` attribute.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub standard_patterns: ZeroMap2d<'data, i8, Count, PatternULE>,
+
+    /// The compact patterns for the standard alpha_next_to_number pattern.
+    /// If the map is empty, `standard_patterns` are used instead.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub alpha_next_to_number_patterns: ZeroMap2d<'data, i8, Count, PatternULE>,
+}
+
+/// This type contains the long display names of currencies, such as "US dollars",
+/// and the patterns used to place them next to the number.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(CurrencyLongNamesV1Marker = "currency/long_names@1")]
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::dimension::provider::currency),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyLongNamesV1<'data> {
+    /// The patterns used to place the display name next to the number,
+    /// selected by the plural category of the number.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unit_patterns: UnitPatterns<'data>,
+
+    /// A mapping from each currency's ISO code and a plural category to the display name
+    /// of the currency.
+    /// Only the names that differ from the one for [`PluralCategory::Other`] are included
+    /// for the other plural categories.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub display_names: ZeroMap2d<'data, UnvalidatedTinyAsciiStr<3>, PluralCategory, str>,
+}
+
+/// The `unitPattern`s of a locale, one for each plural category.
+///
+/// NOTE: place holder 0 is the place of the currency value.
+///       place holder 1 is the place of the currency display name.
+#[derive(Clone, PartialEq, Debug, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::dimension::provider::currency),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPatterns<'data> {
+    /// Pattern for PluralCategory::Zero or `None` if it is the same as `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+    /// Pattern for PluralCategory::One or `None` if it is the same as `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+    /// Pattern for PluralCategory::Two or `None` if it is the same as `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+    /// Pattern for PluralCategory::Few or `None` if it is the same as `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+    /// Pattern for PluralCategory::Many or `None` if it is the same as `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: Option<DoublePlaceholderPattern<Cow<'data, str>>>,
+    /// Pattern for PluralCategory::Other
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: DoublePlaceholderPattern<Cow<'data, str>>,
+}
+
+impl<'data> UnitPatterns<'data> {
+    /// Returns the pattern for the given plural category, falling back to `other`.
+    pub fn get(&self, category: PluralCategory) -> &DoublePlaceholderPattern<Cow<'data, str>> {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+}
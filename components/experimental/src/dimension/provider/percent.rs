@@ -63,3 +63,25 @@ pub struct PercentAffixesV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub suffix: Cow<'data, str>,
 }
+
+/// The permille sign of a locale, such as "‰". Values per thousand are placed
+/// in the percent pattern of the locale, with this sign in place of the percent sign.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(PermilleSymbolV1Marker = "percent/permille@1")]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::dimension::provider::percent),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PermilleSymbolV1<'data> {
+    /// The permille symbol.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub permille_sign_symbol: Cow<'data, str>,
+}
@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use writeable::Writeable;
+
+use crate::compactdecimal::FormattedCompactDecimal;
+
+/// An intermediate structure returned by [`CompactUnitFormatter`](crate::dimension::units::CompactUnitFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted unit to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedCompactUnit<'l> {
+    /// The value of the measure, in short compact decimal notation.
+    pub(crate) value: FormattedCompactDecimal<'l>,
+    /// The text before the value in the unit pattern.
+    pub(crate) prefix: &'l str,
+    /// The text after the value in the unit pattern, such as " km".
+    pub(crate) suffix: &'l str,
+}
+
+impl<'l> Writeable for FormattedCompactUnit<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        sink.write_str(self.prefix)?;
+        self.value.write_to(sink)?;
+        sink.write_str(self.suffix)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCompactUnit<'_>);
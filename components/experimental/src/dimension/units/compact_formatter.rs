@@ -0,0 +1,189 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
+use icu_pattern::SinglePlaceholderPattern;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+
+use crate::compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker;
+use crate::compactdecimal::{compact_plural_category, CompactDecimalFormatter};
+use crate::dimension::units::compact_format::FormattedCompactUnit;
+use crate::dimension::DimensionError;
+use crate::numberrange::pattern_affixes;
+
+/// A formatter for measures of a unit in short compact notation, such as "3.4M km".
+///
+/// [`CompactUnitFormatter`] combines the short compact notation of [`CompactDecimalFormatter`]
+/// with a unit pattern, such as "{0} km", which is selected by the plural category of the
+/// rounded value.
+///
+/// The display names of the units are not part of the data yet, so the unit patterns are
+/// given by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::dimension::units::CompactUnitFormatter;
+/// use icu_locid::locale;
+/// use icu_pattern::SinglePlaceholderPattern;
+/// use icu_plurals::PluralCategory;
+/// use writeable::assert_writeable_eq;
+///
+/// let fmt = CompactUnitFormatter::try_new(&locale!("en").into())
+///     .expect("locale should be present");
+///
+/// let km = SinglePlaceholderPattern::try_from_str("{0} km").unwrap();
+/// let value = "3412000".parse().unwrap();
+/// assert_writeable_eq!(fmt.format_fixed_decimal(&value, |_| &km), "3.4M km");
+///
+/// let mile = SinglePlaceholderPattern::try_from_str("{0} mile").unwrap();
+/// let miles = SinglePlaceholderPattern::try_from_str("{0} miles").unwrap();
+/// let unit = |category| match category {
+///     PluralCategory::One => &mile,
+///     _ => &miles,
+/// };
+/// assert_writeable_eq!(fmt.format_fixed_decimal(&"1".parse().unwrap(), unit), "1 mile");
+/// assert_writeable_eq!(fmt.format_fixed_decimal(&"1000".parse().unwrap(), unit), "1K miles");
+/// ```
+#[derive(Debug)]
+pub struct CompactUnitFormatter {
+    /// A short [`CompactDecimalFormatter`] to round and format the value.
+    compact_decimal_formatter: CompactDecimalFormatter,
+}
+
+impl CompactUnitFormatter {
+    /// Creates a new [`CompactUnitFormatter`] from compiled locale data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(locale: &DataLocale) -> Result<Self, DimensionError> {
+        Ok(Self {
+            compact_decimal_formatter: CompactDecimalFormatter::try_new_short(
+                locale,
+                Default::default(),
+            )?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: DimensionError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(provider: &D, locale: &DataLocale) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<ShortCompactDecimalFormatDataV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            compact_decimal_formatter: CompactDecimalFormatter::try_new_short_unstable(
+                provider,
+                locale,
+                Default::default(),
+            )?,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] value in short compact notation, in the unit pattern that
+    /// `pattern_for` returns for the plural category of the rounded value.
+    ///
+    /// The value is rounded as by [`CompactDecimalFormatter::format_fixed_decimal()`].
+    // TODO(#275): Load the unit patterns from the CLDR unit display names.
+    pub fn format_fixed_decimal<'l, 'p: 'l, S>(
+        &'l self,
+        value: &FixedDecimal,
+        pattern_for: impl FnOnce(PluralCategory) -> &'p SinglePlaceholderPattern<S>,
+    ) -> FormattedCompactUnit<'l>
+    where
+        S: AsRef<str> + 'p,
+    {
+        let value = self
+            .compact_decimal_formatter
+            .format_fixed_decimal(value.clone());
+        let plural_category = compact_plural_category(
+            value.get_compact_decimal(),
+            &self.compact_decimal_formatter.plural_rules,
+        );
+
+        let (prefix, suffix) = pattern_affixes(pattern_for(plural_category));
+
+        FormattedCompactUnit {
+            value,
+            prefix,
+            suffix,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_compact_unit() {
+        let cases = [
+            (locale!("en"), "843", "843 km"),
+            (locale!("en"), "3412000", "3.4M km"),
+            (locale!("en"), "-15127", "-15K km"),
+            (locale!("en"), "999500", "1M km"),
+            (locale!("fr"), "1234", "1,2\u{a0}k km"),
+            (locale!("ja"), "12345", "1.2万 km"),
+        ];
+        let km = SinglePlaceholderPattern::try_from_str("{0} km").unwrap();
+        for (locale, input, expected) in cases {
+            let fmt = CompactUnitFormatter::try_new(&(&locale).into()).unwrap();
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(
+                fmt.format_fixed_decimal(&value, |_| &km),
+                expected,
+                "{locale} {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_plural_pattern() {
+        let fmt = CompactUnitFormatter::try_new(&locale!("fr").into()).unwrap();
+        let kilometer = SinglePlaceholderPattern::try_from_str("{0} kilomètre").unwrap();
+        let kilometers = SinglePlaceholderPattern::try_from_str("{0} kilomètres").unwrap();
+        let of_kilometers = SinglePlaceholderPattern::try_from_str("{0} de kilomètres").unwrap();
+        let unit = |category| match category {
+            PluralCategory::One => &kilometer,
+            PluralCategory::Many => &of_kilometers,
+            _ => &kilometers,
+        };
+
+        let cases = [
+            ("1", "1 kilomètre"),
+            ("1.5", "1,5 kilomètre"),
+            ("2", "2 kilomètres"),
+            ("1200", "1,2\u{a0}k kilomètres"),
+            // The compact exponent 6 of 1.2 million makes it `many` in French.
+            ("1200000", "1,2\u{a0}M de kilomètres"),
+        ];
+        for (input, expected) in cases {
+            let value = input.parse().unwrap();
+            assert_writeable_eq!(fmt.format_fixed_decimal(&value, unit), expected, "{input}");
+        }
+    }
+}
@@ -0,0 +1,11 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Measurement unit formatting
+
+mod compact_format;
+mod compact_formatter;
+
+pub use compact_format::FormattedCompactUnit;
+pub use compact_formatter::CompactUnitFormatter;
@@ -17,7 +17,9 @@ extern crate alloc;
 pub mod compactdecimal;
 pub mod dimension;
 pub mod displaynames;
+pub mod numberrange;
 pub mod personnames;
+pub mod rbnf;
 pub mod relativetime;
 pub mod transliterate;
 pub mod unicodeset_parse;
@@ -41,11 +43,22 @@ pub mod provider {
         icu_experimental_data::make_provider!(Baked);
         icu_experimental_data::impl_compactdecimal_long_v1!(Baked);
         icu_experimental_data::impl_compactdecimal_short_v1!(Baked);
+        icu_experimental_data::impl_currency_accounting_v1!(Baked);
+        icu_experimental_data::impl_currency_compact_v1!(Baked);
+        icu_experimental_data::impl_currency_essentials_v1!(Baked);
+        icu_experimental_data::impl_currency_fractions_v1!(Baked);
+        icu_experimental_data::impl_currency_long_names_v1!(Baked);
         icu_experimental_data::impl_displaynames_languages_v1!(Baked);
         icu_experimental_data::impl_displaynames_locales_v1!(Baked);
         icu_experimental_data::impl_displaynames_regions_v1!(Baked);
         icu_experimental_data::impl_displaynames_scripts_v1!(Baked);
         icu_experimental_data::impl_displaynames_variants_v1!(Baked);
+        icu_experimental_data::impl_numberrange_patterns_v1!(Baked);
+        icu_experimental_data::impl_percent_essentials_v1!(Baked);
+        icu_experimental_data::impl_percent_permille_v1!(Baked);
+        icu_experimental_data::impl_rbnf_numbering_v1!(Baked);
+        icu_experimental_data::impl_rbnf_ordinal_v1!(Baked);
+        icu_experimental_data::impl_rbnf_spellout_v1!(Baked);
         icu_experimental_data::impl_relativetime_long_day_v1!(Baked);
         icu_experimental_data::impl_relativetime_long_hour_v1!(Baked);
         icu_experimental_data::impl_relativetime_long_minute_v1!(Baked);
@@ -83,13 +96,22 @@ pub mod provider {
         super::compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker::KEY,
         super::compactdecimal::provider::LongCompactDecimalFormatDataV1Marker::KEY,
         super::compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker::KEY,
+        super::dimension::provider::currency::CurrencyAccountingPatternsV1Marker::KEY,
         super::dimension::provider::currency::CurrencyEssentialsV1Marker::KEY,
+        super::dimension::provider::currency::CurrencyFractionsV1Marker::KEY,
+        super::dimension::provider::currency::CurrencyLongNamesV1Marker::KEY,
+        super::dimension::provider::currency::ShortCurrencyCompactV1Marker::KEY,
         super::dimension::provider::percent::PercentEssentialsV1Marker::KEY,
+        super::dimension::provider::percent::PermilleSymbolV1Marker::KEY,
         super::displaynames::provider::LanguageDisplayNamesV1Marker::KEY,
         super::displaynames::provider::LocaleDisplayNamesV1Marker::KEY,
         super::displaynames::provider::RegionDisplayNamesV1Marker::KEY,
         super::displaynames::provider::ScriptDisplayNamesV1Marker::KEY,
         super::displaynames::provider::VariantDisplayNamesV1Marker::KEY,
+        super::numberrange::provider::NumberRangePatternsV1Marker::KEY,
+        super::rbnf::provider::NumberingSystemRulesV1Marker::KEY,
+        super::rbnf::provider::OrdinalRulesV1Marker::KEY,
+        super::rbnf::provider::SpelloutRulesV1Marker::KEY,
         super::relativetime::provider::LongDayRelativeTimeFormatDataV1Marker::KEY,
         super::relativetime::provider::LongHourRelativeTimeFormatDataV1Marker::KEY,
         super::relativetime::provider::LongMinuteRelativeTimeFormatDataV1Marker::KEY,
@@ -0,0 +1,43 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::numberrange::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum NumberRangeError {
+    /// An error originating from [`PluralRulesWithRanges`](icu_plurals::PluralRulesWithRanges).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+}
+
+impl From<PluralsError> for NumberRangeError {
+    fn from(e: PluralsError) -> Self {
+        NumberRangeError::PluralRules(e)
+    }
+}
+
+impl From<DataError> for NumberRangeError {
+    fn from(e: DataError) -> Self {
+        NumberRangeError::Data(e)
+    }
+}
+
+impl From<DecimalError> for NumberRangeError {
+    fn from(e: DecimalError) -> Self {
+        NumberRangeError::Decimal(e)
+    }
+}
@@ -0,0 +1,199 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt::{self, Write};
+use icu_decimal::parts;
+use writeable::{Part, PartsWrite, Writeable};
+
+use crate::numberrange::options::{
+    NumberRangeFormatterOptions, RangeCollapse, RangeIdentityFallback,
+};
+use crate::numberrange::provider::NumberRangePatternsV1;
+
+/// The kind of output produced by [`FormattedNumberRange`].
+#[derive(Debug)]
+pub(crate) enum RangeKind<'l, T> {
+    /// A range between two values, with the affixes of the pattern they are displayed in,
+    /// such as " kg" in "{0} kg".
+    ///
+    /// `same_value` is true if both values are the same before formatting.
+    Range {
+        start: T,
+        end: T,
+        same_value: bool,
+        prefix: &'l str,
+        suffix: &'l str,
+    },
+    /// An approximate value, such as "~5".
+    Approximately(T),
+    /// A value or more, such as "5+".
+    AtLeast(T),
+    /// A value or less, such as "≤5".
+    AtMost(T),
+}
+
+/// An intermediate structure returned by [`NumberRangeFormatter`](crate::numberrange::NumberRangeFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted range to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedNumberRange<'l, T> {
+    pub(crate) kind: RangeKind<'l, T>,
+    pub(crate) options: &'l NumberRangeFormatterOptions,
+    pub(crate) patterns: &'l NumberRangePatternsV1<'l>,
+}
+
+/// A value displayed with a prefix and a suffix.
+struct Affixed<'a, T> {
+    prefix: &'a str,
+    value: &'a T,
+    suffix: &'a str,
+}
+
+impl<'a, T: Writeable> Writeable for Affixed<'a, T> {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_str(self.prefix)?;
+        self.value.write_to_parts(sink)?;
+        sink.write_str(self.suffix)
+    }
+}
+
+/// A sink that only records whether a value is written with a sign.
+#[derive(Default)]
+struct SignRecorder {
+    has_sign: bool,
+}
+
+impl Write for SignRecorder {
+    fn write_str(&mut self, _: &str) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl PartsWrite for SignRecorder {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        self.has_sign |= part == parts::MINUS_SIGN || part == parts::PLUS_SIGN;
+        f(self)
+    }
+}
+
+fn has_sign(value: &impl Writeable) -> bool {
+    let mut recorder = SignRecorder::default();
+    // The recorder never fails.
+    let _ = value.write_to_parts(&mut recorder);
+    recorder.has_sign
+}
+
+impl<'l, T: Writeable> FormattedNumberRange<'l, T> {
+    /// Returns whether an affix of the pattern can be displayed only once for both ends.
+    ///
+    /// Like ICU, only affixes of more than one character are collapsed, so that "$3 – $5"
+    /// keeps both currency symbols.
+    fn is_collapsible(&self, affix: &str) -> bool {
+        self.options.collapse == RangeCollapse::Auto && affix.chars().nth(1).is_some()
+    }
+
+    fn write_range<W>(
+        &self,
+        start: &T,
+        end: &T,
+        prefix: &str,
+        suffix: &str,
+        sink: &mut W,
+    ) -> fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        // A collapsed prefix is only displayed before the start, and a collapsed suffix only
+        // after the end.
+        let collapse_prefix = self.is_collapsible(prefix);
+        let collapse_suffix = self.is_collapsible(suffix);
+        let start = Affixed {
+            prefix,
+            value: start,
+            suffix: if collapse_suffix { "" } else { suffix },
+        };
+        let end = Affixed {
+            prefix: if collapse_prefix { "" } else { prefix },
+            value: end,
+            suffix,
+        };
+
+        // Like ICU, the range separator is spaced if a sign or an affix is repeated, so that
+        // "-5 – -3" is not displayed as "-5–-3".
+        let needs_spacing = (!prefix.is_empty() && !collapse_prefix)
+            || (!suffix.is_empty() && !collapse_suffix)
+            || has_sign(start.value)
+            || has_sign(end.value);
+        let pattern = if needs_spacing {
+            &self.patterns.range_with_spacing
+        } else {
+            &self.patterns.range
+        };
+        pattern.interpolate((start, end)).write_to(sink)
+    }
+}
+
+impl<'l, T: Writeable> Writeable for FormattedNumberRange<'l, T> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let (start, end, same_value, prefix, suffix) = match &self.kind {
+            RangeKind::Range {
+                start,
+                end,
+                same_value,
+                prefix,
+                suffix,
+            } => (start, end, *same_value, *prefix, *suffix),
+            RangeKind::Approximately(value) => {
+                return self
+                    .patterns
+                    .approximately
+                    .interpolate((value,))
+                    .write_to(sink)
+            }
+            RangeKind::AtLeast(value) => {
+                return self.patterns.at_least.interpolate((value,)).write_to(sink)
+            }
+            RangeKind::AtMost(value) => {
+                return self.patterns.at_most.interpolate((value,)).write_to(sink)
+            }
+        };
+
+        if start.write_to_string() != end.write_to_string() {
+            return self.write_range(start, end, prefix, suffix, sink);
+        }
+
+        let single = Affixed {
+            prefix,
+            value: start,
+            suffix,
+        };
+        match self.options.identity_fallback {
+            RangeIdentityFallback::SingleValue => single.write_to(sink),
+            RangeIdentityFallback::ApproximatelyOrSingleValue if same_value => {
+                single.write_to(sink)
+            }
+            RangeIdentityFallback::ApproximatelyOrSingleValue
+            | RangeIdentityFallback::Approximately => self
+                .patterns
+                .approximately
+                .interpolate((single,))
+                .write_to(sink),
+            RangeIdentityFallback::Range => self.write_range(start, end, prefix, suffix, sink),
+        }
+    }
+}
+
+impl<'l, T: Writeable> core::fmt::Display for FormattedNumberRange<'l, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_to(f)
+    }
+}
@@ -0,0 +1,21 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Number range and approximate number formatting, such as "3–5" and "~5".
+
+#![warn(missing_docs)]
+
+mod error;
+mod format;
+mod numberrange;
+pub mod options;
+pub mod provider;
+
+pub use error::NumberRangeError;
+pub use format::FormattedNumberRange;
+pub(crate) use numberrange::pattern_affixes;
+pub use numberrange::NumberRangeFormatter;
+pub use options::NumberRangeFormatterOptions;
+#[doc(no_inline)]
+pub use NumberRangeError as Error;
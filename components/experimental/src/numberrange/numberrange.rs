@@ -0,0 +1,518 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::{provider::DecimalSymbolsV2Marker, FixedDecimalFormatter, FormattedFixedDecimal};
+use icu_pattern::{PatternItem, SinglePlaceholderPattern};
+use icu_plurals::provider::{CardinalV1Marker, PluralRangesV1Marker};
+use icu_plurals::{PluralCategory, PluralRules, PluralRulesWithRanges};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+use crate::numberrange::format::{FormattedNumberRange, RangeKind};
+use crate::numberrange::options::NumberRangeFormatterOptions;
+use crate::numberrange::provider::NumberRangePatternsV1Marker;
+use crate::numberrange::NumberRangeError;
+
+/// A formatter for ranges of numbers, such as "3–5", and approximate numbers, such as "~5".
+///
+/// [`NumberRangeFormatter`] supports:
+///   1. Placing two numbers in the range pattern of the locale.
+///   2. Placing a range in a unit pattern selected by the plural category of the range, and
+///      displaying the affixes of the pattern only once, such as "3–5 kg".
+///   3. Falling back to a single or approximate value when both ends look the same.
+///   4. The approximately, "at least" and "at most" patterns of the locale.
+///
+/// Read more about the options in the [`options`](crate::numberrange::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::numberrange::NumberRangeFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// let three = "3".parse().unwrap();
+/// let five = "5".parse().unwrap();
+/// assert_writeable_eq!(fmt.format(&three, &five), "3–5");
+/// assert_writeable_eq!(fmt.format_approximately(&five), "~5");
+/// assert_writeable_eq!(fmt.format_at_least(&five), "5+");
+/// assert_writeable_eq!(fmt.format_at_most(&five), "≤5");
+/// ```
+///
+/// A range can be displayed in a unit pattern, which is selected by the plural category of the
+/// range:
+///
+/// ```
+/// use icu_experimental::numberrange::NumberRangeFormatter;
+/// use icu_locid::locale;
+/// use icu_pattern::SinglePlaceholderPattern;
+/// use icu_plurals::PluralCategory;
+/// use writeable::assert_writeable_eq;
+///
+/// let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// let day = SinglePlaceholderPattern::try_from_str("{0} day").unwrap();
+/// let days = SinglePlaceholderPattern::try_from_str("{0} days").unwrap();
+/// let unit = |category| match category {
+///     PluralCategory::One => &day,
+///     _ => &days,
+/// };
+///
+/// let one = "1".parse().unwrap();
+/// let two = "2".parse().unwrap();
+/// assert_writeable_eq!(fmt.format_with_pattern(&one, &two, unit), "1–2 days");
+/// assert_writeable_eq!(fmt.format_with_pattern(&one, &one, unit), "1 day");
+/// ```
+///
+/// Any [`Writeable`], such as an amount of a currency, can be displayed as an approximate
+/// value:
+///
+/// ```
+/// use icu_experimental::numberrange::NumberRangeFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// assert_writeable_eq!(fmt.approximately("$10"), "~$10");
+/// ```
+#[derive(Debug)]
+pub struct NumberRangeFormatter {
+    /// The range and approximately patterns of the locale.
+    patterns: DataPayload<NumberRangePatternsV1Marker>,
+
+    /// A [`FixedDecimalFormatter`] to format both ends of the range.
+    fixed_decimal_formatter: FixedDecimalFormatter,
+
+    /// The cardinal plural rules and plural ranges of the locale.
+    plural_rules: PluralRulesWithRanges<PluralRules>,
+
+    options: NumberRangeFormatterOptions,
+}
+
+impl NumberRangeFormatter {
+    /// Creates a new [`NumberRangeFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: NumberRangeFormatterOptions,
+    ) -> Result<Self, NumberRangeError> {
+        let fixed_decimal_formatter =
+            FixedDecimalFormatter::try_new(locale, options.fixed_decimal_formatter_options)?;
+        let plural_rules = PluralRulesWithRanges::try_new_cardinal(locale)?;
+        let patterns = DataProvider::<NumberRangePatternsV1Marker>::load(
+            &crate::provider::Baked,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            patterns,
+            fixed_decimal_formatter,
+            plural_rules,
+            options,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: NumberRangeFormatterOptions,
+        error: NumberRangeError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: NumberRangeFormatterOptions,
+    ) -> Result<Self, NumberRangeError>
+    where
+        D: DataProvider<NumberRangePatternsV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<PluralRangesV1Marker>
+            + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            provider,
+            locale,
+            options.fixed_decimal_formatter_options,
+        )?;
+        let plural_rules = PluralRulesWithRanges::try_new_cardinal_unstable(provider, locale)?;
+        let patterns = DataProvider::<NumberRangePatternsV1Marker>::load(
+            provider,
+            DataRequest {
+                locale,
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+
+        Ok(Self {
+            patterns,
+            fixed_decimal_formatter,
+            plural_rules,
+            options,
+        })
+    }
+
+    /// Formats the range between two [`FixedDecimal`] values.
+    ///
+    /// If both values look the same once formatted, the
+    /// [`RangeIdentityFallback`](crate::numberrange::options::RangeIdentityFallback) option
+    /// decides what is displayed instead of the range.
+    pub fn format<'l>(
+        &'l self,
+        start: &'l FixedDecimal,
+        end: &'l FixedDecimal,
+    ) -> FormattedNumberRange<'l, FormattedFixedDecimal<'l>> {
+        self.formatted(Self::range_kind(
+            self.fixed_decimal_formatter.format(start),
+            self.fixed_decimal_formatter.format(end),
+            start,
+            end,
+            "",
+            "",
+        ))
+    }
+
+    /// Formats the range between two [`FixedDecimal`] values in a pattern with a unit, such
+    /// as "{0} kg".
+    ///
+    /// The pattern is selected by the plural category of the range, as computed by
+    /// [`Self::category_for_range`] for the values with the digit options applied. Affixes of
+    /// more than one character, such as " kg", are only displayed once, unless the
+    /// [`RangeCollapse`](crate::numberrange::options::RangeCollapse) option says otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_experimental::numberrange::NumberRangeFormatter;
+    /// use icu_locid::locale;
+    /// use icu_pattern::SinglePlaceholderPattern;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), Default::default())
+    ///     .expect("locale should be present");
+    ///
+    /// let kg = SinglePlaceholderPattern::try_from_str("{0} kg").unwrap();
+    /// let dollars = SinglePlaceholderPattern::try_from_str("${0}").unwrap();
+    ///
+    /// let three = "3".parse().unwrap();
+    /// let five = "5".parse().unwrap();
+    /// assert_writeable_eq!(fmt.format_with_pattern(&three, &five, |_| &kg), "3–5 kg");
+    /// assert_writeable_eq!(fmt.format_with_pattern(&three, &five, |_| &dollars), "$3 – $5");
+    /// ```
+    pub fn format_with_pattern<'l, 'p: 'l, S>(
+        &'l self,
+        start: &'l FixedDecimal,
+        end: &'l FixedDecimal,
+        pattern_for: impl FnOnce(PluralCategory) -> &'p SinglePlaceholderPattern<S>,
+    ) -> FormattedNumberRange<'l, FormattedFixedDecimal<'l>>
+    where
+        S: AsRef<str> + 'p,
+    {
+        let formatted_start = self.fixed_decimal_formatter.format(start);
+        let formatted_end = self.fixed_decimal_formatter.format(end);
+        let category = self
+            .plural_rules
+            .category_for_range(formatted_start.value(), formatted_end.value());
+
+        let (prefix, suffix) = pattern_affixes(pattern_for(category));
+
+        self.formatted(Self::range_kind(
+            formatted_start,
+            formatted_end,
+            start,
+            end,
+            prefix,
+            suffix,
+        ))
+    }
+
+    /// Formats an approximate [`FixedDecimal`] value, such as "~5".
+    pub fn format_approximately<'l>(
+        &'l self,
+        value: &'l FixedDecimal,
+    ) -> FormattedNumberRange<'l, FormattedFixedDecimal<'l>> {
+        self.approximately(self.fixed_decimal_formatter.format(value))
+    }
+
+    /// Formats a [`FixedDecimal`] value or more, such as "5+".
+    pub fn format_at_least<'l>(
+        &'l self,
+        value: &'l FixedDecimal,
+    ) -> FormattedNumberRange<'l, FormattedFixedDecimal<'l>> {
+        self.at_least(self.fixed_decimal_formatter.format(value))
+    }
+
+    /// Formats a [`FixedDecimal`] value or less, such as "≤5".
+    pub fn format_at_most<'l>(
+        &'l self,
+        value: &'l FixedDecimal,
+    ) -> FormattedNumberRange<'l, FormattedFixedDecimal<'l>> {
+        self.at_most(self.fixed_decimal_formatter.format(value))
+    }
+
+    /// Formats an approximate value that is already formatted, such as "~$10".
+    pub fn approximately<T: Writeable>(&self, value: T) -> FormattedNumberRange<'_, T> {
+        self.formatted(RangeKind::Approximately(value))
+    }
+
+    /// Formats a value that is already formatted as a lower bound, such as "$10+".
+    pub fn at_least<T: Writeable>(&self, value: T) -> FormattedNumberRange<'_, T> {
+        self.formatted(RangeKind::AtLeast(value))
+    }
+
+    /// Formats a value that is already formatted as an upper bound, such as "≤$10".
+    pub fn at_most<T: Writeable>(&self, value: T) -> FormattedNumberRange<'_, T> {
+        self.formatted(RangeKind::AtMost(value))
+    }
+
+    /// Returns the plural category of the range between two values, such as
+    /// [`PluralCategory::Other`] for "1–2 days" in English.
+    ///
+    /// This is the category used by [`Self::format_with_pattern`] to select the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_experimental::numberrange::NumberRangeFormatter;
+    /// use icu_locid::locale;
+    /// use icu_plurals::PluralCategory;
+    ///
+    /// let fmt = NumberRangeFormatter::try_new(&locale!("ru").into(), Default::default())
+    ///     .expect("locale should be present");
+    ///
+    /// let start = "0".parse().unwrap();
+    /// let end = "2".parse().unwrap();
+    /// assert_eq!(fmt.category_for_range(&start, &end), PluralCategory::Few);
+    /// ```
+    pub fn category_for_range(&self, start: &FixedDecimal, end: &FixedDecimal) -> PluralCategory {
+        self.plural_rules.category_for_range(start, end)
+    }
+
+    fn range_kind<'l>(
+        formatted_start: FormattedFixedDecimal<'l>,
+        formatted_end: FormattedFixedDecimal<'l>,
+        start: &FixedDecimal,
+        end: &FixedDecimal,
+        prefix: &'l str,
+        suffix: &'l str,
+    ) -> RangeKind<'l, FormattedFixedDecimal<'l>> {
+        let same_value = start.clone().trimmed_start().trimmed_end()
+            == end.clone().trimmed_start().trimmed_end();
+        RangeKind::Range {
+            start: formatted_start,
+            end: formatted_end,
+            same_value,
+            prefix,
+            suffix,
+        }
+    }
+
+    fn formatted<'l, T>(&'l self, kind: RangeKind<'l, T>) -> FormattedNumberRange<'l, T> {
+        FormattedNumberRange {
+            kind,
+            options: &self.options,
+            patterns: self.patterns.get(),
+        }
+    }
+}
+
+/// Returns the literal text before and after the placeholder of a pattern, such as
+/// ("", " kg") for "{0} kg".
+pub(crate) fn pattern_affixes<S: AsRef<str>>(
+    pattern: &SinglePlaceholderPattern<S>,
+) -> (&str, &str) {
+    let mut prefix = "";
+    let mut suffix = "";
+    let mut after_placeholder = false;
+    for item in pattern.iter() {
+        match item {
+            PatternItem::Placeholder(_) => after_placeholder = true,
+            PatternItem::Literal(s) if after_placeholder => suffix = s,
+            PatternItem::Literal(s) => prefix = s,
+        }
+    }
+    (prefix, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numberrange::options::{RangeCollapse, RangeIdentityFallback};
+    use icu_locid::locale;
+    use icu_pattern::SinglePlaceholderPattern;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_range() {
+        let cases = [
+            (locale!("en"), "3", "5", "3–5"),
+            (locale!("en"), "-5", "-3", "-5 – -3"),
+            (locale!("en"), "1000", "2500", "1,000–2,500"),
+            (locale!("es"), "3", "5", "3-5"),
+            (locale!("fr"), "1.5", "2.5", "1,5–2,5"),
+            (locale!("ja"), "3", "5", "3～5"),
+        ];
+        for (locale, start, end, expected) in cases {
+            let fmt = NumberRangeFormatter::try_new(&(&locale).into(), Default::default()).unwrap();
+            let start = start.parse().unwrap();
+            let end = end.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&start, &end), expected, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_approximately() {
+        let cases = [
+            (locale!("en"), "~5", "5+", "≤5"),
+            (locale!("fr"), "≈5", "≥5", "≤5"),
+            (locale!("es"), "~5", "Más de 5", "≤5"),
+            (locale!("ja"), "約 5", "5 以上", "5 以下"),
+        ];
+        let value = FixedDecimal::from(5);
+        for (locale, approximately, at_least, at_most) in cases {
+            let fmt = NumberRangeFormatter::try_new(&locale.into(), Default::default()).unwrap();
+            assert_writeable_eq!(fmt.format_approximately(&value), approximately);
+            assert_writeable_eq!(fmt.format_at_least(&value), at_least);
+            assert_writeable_eq!(fmt.format_at_most(&value), at_most);
+        }
+    }
+
+    #[test]
+    fn test_collapse() {
+        let cases = [
+            ("{0} kg", "3", "5", "3–5 kg"),
+            ("US${0}", "3", "5", "US$3–5"),
+            ("${0}", "3", "5", "$3 – $5"),
+            ("{0} kg", "-3", "-2", "-3 – -2 kg"),
+            ("{0} kg", "-3", "2", "-3 – 2 kg"),
+            // Digits and non-ASCII numerals in affixes are left alone.
+            ("{0} m2", "3", "5", "3–5 m2"),
+            ("{0}", "3", "5", "3–5"),
+        ];
+        let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), Default::default()).unwrap();
+        for (pattern, start, end, expected) in cases {
+            let pattern = SinglePlaceholderPattern::try_from_str(pattern).unwrap();
+            let start = start.parse().unwrap();
+            let end = end.parse().unwrap();
+            assert_writeable_eq!(
+                fmt.format_with_pattern(&start, &end, |_| &pattern),
+                expected
+            );
+        }
+
+        let kg = SinglePlaceholderPattern::try_from_str("{0} kg").unwrap();
+        let three = "3".parse().unwrap();
+        let five = "5".parse().unwrap();
+
+        let fmt =
+            NumberRangeFormatter::try_new(&locale!("ar-u-nu-arab").into(), Default::default())
+                .unwrap();
+        assert_writeable_eq!(fmt.format_with_pattern(&three, &five, |_| &kg), "٣–٥ kg");
+
+        let options = NumberRangeFormatterOptions {
+            collapse: RangeCollapse::Never,
+            ..Default::default()
+        };
+        let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), options).unwrap();
+        assert_writeable_eq!(
+            fmt.format_with_pattern(&three, &five, |_| &kg),
+            "3 kg – 5 kg"
+        );
+    }
+
+    #[test]
+    fn test_plural_pattern() {
+        let day = SinglePlaceholderPattern::try_from_str("{0} day").unwrap();
+        let days = SinglePlaceholderPattern::try_from_str("{0} days").unwrap();
+        let unit = |category| match category {
+            PluralCategory::One => &day,
+            _ => &days,
+        };
+
+        let mut options = NumberRangeFormatterOptions::default();
+        options
+            .fixed_decimal_formatter_options
+            .maximum_fraction_digits = Some(0);
+        let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), options).unwrap();
+        let cases = [
+            ("1", "2", "1–2 days"),
+            ("0", "1", "0–1 days"),
+            ("1", "1", "1 day"),
+            // The category is selected for the rounded values.
+            ("0.6", "1.4", "~1 day"),
+        ];
+        for (start, end, expected) in cases {
+            let start = start.parse().unwrap();
+            let end = end.parse().unwrap();
+            assert_writeable_eq!(fmt.format_with_pattern(&start, &end, unit), expected);
+        }
+    }
+
+    #[test]
+    fn test_identity_fallback() {
+        let five = FixedDecimal::from(5);
+        let almost_five: FixedDecimal = "4.9".parse().unwrap();
+        let cases = [
+            (RangeIdentityFallback::SingleValue, "5", "5"),
+            (RangeIdentityFallback::ApproximatelyOrSingleValue, "5", "~5"),
+            (RangeIdentityFallback::Approximately, "~5", "~5"),
+            (RangeIdentityFallback::Range, "5–5", "5–5"),
+        ];
+        for (identity_fallback, same, rounded) in cases {
+            let mut options = NumberRangeFormatterOptions {
+                identity_fallback,
+                ..Default::default()
+            };
+            options
+                .fixed_decimal_formatter_options
+                .maximum_fraction_digits = Some(0);
+            let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), options).unwrap();
+            assert_writeable_eq!(fmt.format(&five, &five), same, "{identity_fallback:?}");
+            assert_writeable_eq!(
+                fmt.format(&almost_five, &five),
+                rounded,
+                "{identity_fallback:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_category_for_range() {
+        let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), Default::default()).unwrap();
+        let cases = [
+            ("1", "2", PluralCategory::Other),
+            ("0", "1", PluralCategory::Other),
+        ];
+        for (start, end, expected) in cases {
+            let start = start.parse().unwrap();
+            let end = end.parse().unwrap();
+            assert_eq!(fmt.category_for_range(&start, &end), expected);
+        }
+    }
+}
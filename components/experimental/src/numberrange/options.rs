@@ -0,0 +1,117 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`NumberRangeFormatter`](crate::numberrange::NumberRangeFormatter).
+
+use icu_decimal::options::FixedDecimalFormatterOptions;
+
+/// A bag of options for defining how to format ranges of numbers using
+/// [`NumberRangeFormatter`](crate::numberrange::NumberRangeFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NumberRangeFormatterOptions {
+    /// Options for rendering the numbers, such as the number of fraction or significant digits.
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+
+    /// Whether the affixes of the unit pattern are only displayed once.
+    pub collapse: RangeCollapse,
+
+    /// What to display when both ends of the range are the same.
+    pub identity_fallback: RangeIdentityFallback,
+}
+
+impl From<FixedDecimalFormatterOptions> for NumberRangeFormatterOptions {
+    fn from(fixed_decimal_formatter_options: FixedDecimalFormatterOptions) -> Self {
+        Self {
+            fixed_decimal_formatter_options,
+            ..Default::default()
+        }
+    }
+}
+
+/// Configures whether the affixes of the unit pattern of a range are displayed only once.
+///
+/// The unit pattern is given to
+/// [`NumberRangeFormatter::format_with_pattern`](crate::numberrange::NumberRangeFormatter::format_with_pattern).
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::numberrange::options::RangeCollapse;
+/// use icu_experimental::numberrange::{
+///     NumberRangeFormatter, NumberRangeFormatterOptions,
+/// };
+/// use icu_locid::locale;
+/// use icu_pattern::SinglePlaceholderPattern;
+/// use writeable::assert_writeable_eq;
+///
+/// let kg = SinglePlaceholderPattern::try_from_str("{0} kg").unwrap();
+/// let three = "3".parse().unwrap();
+/// let five = "5".parse().unwrap();
+///
+/// let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+/// assert_writeable_eq!(fmt.format_with_pattern(&three, &five, |_| &kg), "3–5 kg");
+///
+/// let mut options = NumberRangeFormatterOptions::default();
+/// options.collapse = RangeCollapse::Never;
+/// let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), options)
+///     .expect("locale should be present");
+/// assert_writeable_eq!(
+///     fmt.format_with_pattern(&three, &five, |_| &kg),
+///     "3 kg – 5 kg"
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RangeCollapse {
+    /// Display an affix of the unit pattern only once, such as "3–5 kg".
+    ///
+    /// Affixes of a single character, such as "$", are never collapsed.
+    #[default]
+    Auto,
+
+    /// Display the affixes on both ends, such as "3 kg – 5 kg".
+    Never,
+}
+
+/// Configures what to display when both ends of a range are the same.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::numberrange::options::RangeIdentityFallback;
+/// use icu_experimental::numberrange::{
+///     NumberRangeFormatter, NumberRangeFormatterOptions,
+/// };
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = NumberRangeFormatterOptions::default();
+/// options.fixed_decimal_formatter_options.maximum_fraction_digits = Some(0);
+/// let fmt = NumberRangeFormatter::try_new(&locale!("en").into(), options)
+///     .expect("locale should be present");
+///
+/// let five = "5".parse().unwrap();
+/// let almost_five = "4.9".parse().unwrap();
+/// assert_writeable_eq!(fmt.format(&five, &five), "5");
+/// assert_writeable_eq!(fmt.format(&almost_five, &five), "~5");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RangeIdentityFallback {
+    /// Display a single value, such as "5".
+    SingleValue,
+
+    /// Display a single value if both ends are the same number, or an approximate value,
+    /// such as "~5", if they are only the same after rounding.
+    #[default]
+    ApproximatelyOrSingleValue,
+
+    /// Display an approximate value, such as "~5".
+    Approximately,
+
+    /// Display the range, such as "5–5".
+    Range,
+}
@@ -0,0 +1,65 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable.
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_pattern::{DoublePlaceholderPattern, SinglePlaceholderPattern};
+use icu_provider::prelude::*;
+
+#[cfg(feature = "compiled_data")]
+/// Baked data
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. In particular, the `DataProvider` implementations are only
+/// guaranteed to match with this version's `*_unstable` providers. Use with caution.
+/// </div>
+pub use crate::provider::Baked;
+
+/// The patterns for ranges and approximate numbers of a locale, from the CLDR `miscPatterns`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(NumberRangePatternsV1Marker = "numberrange/patterns@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::numberrange::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct NumberRangePatternsV1<'data> {
+    /// The pattern for a range of numbers, such as "{0}–{1}".
+    ///
+    /// Placeholder 0 is the start of the range, and placeholder 1 is the end of the range.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub range: DoublePlaceholderPattern<Cow<'data, str>>,
+
+    /// The pattern for a range of numbers that keep a sign or an affix next to the range
+    /// separator, such as "{0} – {1}" for "-5 – -3" or "$3 – $5".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub range_with_spacing: DoublePlaceholderPattern<Cow<'data, str>>,
+
+    /// The pattern for an approximate number, such as "~{0}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub approximately: SinglePlaceholderPattern<Cow<'data, str>>,
+
+    /// The pattern for a number or more, such as "{0}+".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub at_least: SinglePlaceholderPattern<Cow<'data, str>>,
+
+    /// The pattern for a number or less, such as "≤{0}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub at_most: SinglePlaceholderPattern<Cow<'data, str>>,
+}
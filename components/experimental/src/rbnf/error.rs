@@ -0,0 +1,48 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::rbnf::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RbnfError {
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider), or from
+    /// rules that could not be parsed.
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// The locale has no rule set for the requested
+    /// [`RuleSetKind`](crate::rbnf::options::RuleSetKind).
+    #[displaydoc("The locale has no rule set for the requested kind")]
+    MissingRuleSet,
+}
+
+impl From<DataError> for RbnfError {
+    fn from(e: DataError) -> Self {
+        RbnfError::Data(e)
+    }
+}
+
+impl From<DecimalError> for RbnfError {
+    fn from(e: DecimalError) -> Self {
+        RbnfError::Decimal(e)
+    }
+}
+
+impl From<PluralsError> for RbnfError {
+    fn from(e: PluralsError) -> Self {
+        RbnfError::PluralRules(e)
+    }
+}
@@ -0,0 +1,257 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{FixedDecimal, Sign};
+use writeable::Writeable;
+
+use crate::rbnf::rbnf::RuleBasedNumberFormatter;
+use crate::rbnf::rules::{Part, Rule, Substitution, SubstitutionKind, Target};
+
+/// The maximum number of nested rules, after which the number is written with digits.
+///
+/// Rules that reference each other forever are rejected when the formatter is created,
+/// so this only guards the stack against very deep data.
+const MAX_NESTING: u8 = u8::MAX;
+
+/// An intermediate structure returned by [`RuleBasedNumberFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted number to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedRuleBasedNumber<'l> {
+    pub(crate) formatter: &'l RuleBasedNumberFormatter,
+    pub(crate) value: &'l FixedDecimal,
+}
+
+/// The number a rule is applied to.
+#[derive(Debug, Clone, Copy)]
+enum Value<'a> {
+    /// A non-negative integer, formatted by a rule with a base value.
+    Integer(u128),
+    /// The absolute value of a negative number, formatted by the `-x` rule.
+    Negative(&'a FixedDecimal),
+    /// A positive number with a fraction, formatted by the `x.x` rule.
+    Fraction(&'a FixedDecimal),
+}
+
+/// Returns the integer part of a non-negative number, if it fits in a `u128`.
+fn integer_part(value: &FixedDecimal) -> Option<u128> {
+    (0..=value.nonzero_magnitude_start().max(0))
+        .rev()
+        .try_fold(0u128, |n, magnitude| {
+            n.checked_mul(10)?
+                .checked_add(value.digit_at(magnitude).into())
+        })
+}
+
+impl<'l> FormattedRuleBasedNumber<'l> {
+    fn write_decimal<W>(&self, value: &FixedDecimal, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        self.formatter
+            .fixed_decimal_formatter
+            .format(value)
+            .write_to(sink)
+    }
+
+    /// Formats any number with a rule set.
+    fn write_number<W>(
+        &self,
+        set: usize,
+        value: &FixedDecimal,
+        depth: u8,
+        sink: &mut W,
+    ) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let rule_set = &self.formatter.rule_sets[set];
+        if value.sign() == Sign::Negative {
+            return match &rule_set.negative {
+                Some(rule) => {
+                    let value = value.clone().with_sign(Sign::None);
+                    self.write_rule(set, rule, None, Value::Negative(&value), depth, sink)
+                }
+                None => self.write_decimal(value, sink),
+            };
+        }
+        if value.nonzero_magnitude_end() < 0 {
+            return match &rule_set.fraction {
+                Some(rule) => self.write_rule(set, rule, None, Value::Fraction(value), depth, sink),
+                None => self.write_decimal(value, sink),
+            };
+        }
+        match integer_part(value) {
+            Some(n) => self.write_integer(set, n, depth, sink),
+            // Like in ICU, numbers too large for the rules are formatted with digits.
+            None => self.write_decimal(value, sink),
+        }
+    }
+
+    /// Formats a non-negative integer with the rule of a rule set for its value.
+    fn write_integer<W>(&self, set: usize, n: u128, depth: u8, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let rules = &self.formatter.rule_sets[set].rules;
+        let Some(mut index) = rules.partition_point(|rule| rule.base <= n).checked_sub(1) else {
+            return self.write_decimal(&n.into(), sink);
+        };
+        if index > 0 && rules[index].should_roll_back(n) {
+            index -= 1;
+        }
+        let previous = index.checked_sub(1).map(|i| &rules[i]);
+        self.write_rule(set, &rules[index], previous, Value::Integer(n), depth, sink)
+    }
+
+    fn write_rule<W>(
+        &self,
+        set: usize,
+        rule: &Rule,
+        previous: Option<&Rule>,
+        value: Value,
+        depth: u8,
+        sink: &mut W,
+    ) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        if depth >= MAX_NESTING {
+            return match value {
+                Value::Integer(n) => self.write_decimal(&n.into(), sink),
+                Value::Negative(value) => {
+                    self.write_decimal(&value.clone().with_sign(Sign::Negative), sink)
+                }
+                Value::Fraction(value) => self.write_decimal(value, sink),
+            };
+        }
+        self.write_parts(set, rule, previous, &rule.parts, value, depth + 1, sink)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_parts<W>(
+        &self,
+        set: usize,
+        rule: &Rule,
+        previous: Option<&Rule>,
+        parts: &[Part],
+        value: Value,
+        depth: u8,
+        sink: &mut W,
+    ) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        for part in parts {
+            match part {
+                Part::Literal(text) => sink.write_str(text)?,
+                Part::Optional(parts) => {
+                    // The text between brackets is omitted if the remainder is zero.
+                    let omit = matches!(value, Value::Integer(n) if n % rule.divisor == 0);
+                    if !omit {
+                        self.write_parts(set, rule, previous, parts, value, depth, sink)?;
+                    }
+                }
+                Part::Plural(plural) => {
+                    let plural_rules = if plural.ordinal {
+                        &self.formatter.ordinal_rules
+                    } else {
+                        &self.formatter.cardinal_rules
+                    };
+                    let category = match value {
+                        Value::Integer(n) => plural_rules.category_for(n / rule.divisor),
+                        Value::Negative(value) | Value::Fraction(value) => {
+                            plural_rules.category_for(value)
+                        }
+                    };
+                    sink.write_str(plural.get(category))?;
+                }
+                Part::Substitution(substitution) => {
+                    self.write_substitution(set, rule, previous, substitution, value, depth, sink)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_substitution<W>(
+        &self,
+        set: usize,
+        rule: &Rule,
+        previous: Option<&Rule>,
+        substitution: &Substitution,
+        value: Value,
+        depth: u8,
+        sink: &mut W,
+    ) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let target_set = match substitution.target {
+            Target::Inherit => Some(set),
+            Target::RuleSet(index) => Some(index),
+            Target::Decimal => None,
+        };
+        let write_integer = |n: u128, sink: &mut W| match target_set {
+            Some(set) => self.write_integer(set, n, depth, sink),
+            None => self.write_decimal(&n.into(), sink),
+        };
+        let write_number = |value: &FixedDecimal, sink: &mut W| match target_set {
+            Some(set) => self.write_number(set, value, depth, sink),
+            None => self.write_decimal(value, sink),
+        };
+
+        match (value, substitution.kind) {
+            (Value::Integer(n), SubstitutionKind::Quotient) => {
+                write_integer(n / rule.divisor, sink)
+            }
+            (Value::Integer(n), SubstitutionKind::Remainder { triple: true }) => match previous {
+                Some(previous) => self.write_rule(
+                    set,
+                    previous,
+                    None,
+                    Value::Integer(n % rule.divisor),
+                    depth,
+                    sink,
+                ),
+                None => write_integer(n % rule.divisor, sink),
+            },
+            (Value::Integer(n), SubstitutionKind::Remainder { triple: false }) => {
+                write_integer(n % rule.divisor, sink)
+            }
+            (Value::Integer(n), SubstitutionKind::Same) => write_integer(n, sink),
+            // All substitutions of the `-x` rule format the absolute value.
+            (Value::Negative(value), _) => write_number(value, sink),
+            (Value::Fraction(value), SubstitutionKind::Quotient) => match integer_part(value) {
+                Some(n) => write_integer(n, sink),
+                None => self.write_decimal(&value.clone().trunced(0), sink),
+            },
+            // The digits of the fraction are formatted one by one.
+            (Value::Fraction(value), SubstitutionKind::Remainder { triple }) => {
+                for magnitude in (value.nonzero_magnitude_end()..0).rev() {
+                    if !triple && magnitude != -1 {
+                        sink.write_char(' ')?;
+                    }
+                    write_integer(value.digit_at(magnitude).into(), sink)?;
+                }
+                Ok(())
+            }
+            (Value::Fraction(value), SubstitutionKind::Same) => write_number(value, sink),
+        }
+    }
+}
+
+impl<'l> Writeable for FormattedRuleBasedNumber<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        match self.formatter.rule_set {
+            Some(set) => self.write_number(set, self.value, 0, sink),
+            None => self.write_decimal(self.value, sink),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedRuleBasedNumber<'_>);
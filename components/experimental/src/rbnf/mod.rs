@@ -0,0 +1,24 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Rule-based number formatting, such as "one hundred twenty-three" and "123rd".
+//!
+//! The rules come from the CLDR `rbnf` data, and are evaluated over
+//! [`FixedDecimal`](fixed_decimal::FixedDecimal).
+
+#![warn(missing_docs)]
+
+mod error;
+mod format;
+pub mod options;
+pub mod provider;
+mod rbnf;
+mod rules;
+
+pub use error::RbnfError;
+pub use format::FormattedRuleBasedNumber;
+pub use options::RuleBasedNumberFormatterOptions;
+pub use rbnf::RuleBasedNumberFormatter;
+#[doc(no_inline)]
+pub use RbnfError as Error;
@@ -0,0 +1,136 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`RuleBasedNumberFormatter`](crate::rbnf::RuleBasedNumberFormatter).
+
+/// A bag of options for defining how to format numbers using
+/// [`RuleBasedNumberFormatter`](crate::rbnf::RuleBasedNumberFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RuleBasedNumberFormatterOptions {
+    /// The rule set used to format numbers.
+    pub rule_set: RuleSetKind,
+
+    /// The grammatical gender of the noun the number refers to, for languages where
+    /// numbers agree with it, such as "una" rather than "uno" in Spanish.
+    ///
+    /// If the locale has no rule set for the gender, the rule set without gender is used,
+    /// then the masculine rule set.
+    pub gender: Option<RuleSetGender>,
+}
+
+impl From<RuleSetKind> for RuleBasedNumberFormatterOptions {
+    fn from(rule_set: RuleSetKind) -> Self {
+        Self {
+            rule_set,
+            ..Default::default()
+        }
+    }
+}
+
+/// The rule set used to format numbers.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::rbnf::options::RuleSetKind;
+/// use icu_experimental::rbnf::RuleBasedNumberFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let value = 123.into();
+/// let cases = [
+///     (RuleSetKind::SpelloutCardinal, "one hundred twenty-three"),
+///     (RuleSetKind::SpelloutOrdinal, "one hundred twenty-third"),
+///     (RuleSetKind::DigitsOrdinal, "123rd"),
+/// ];
+/// for (rule_set, expected) in cases {
+///     let fmt = RuleBasedNumberFormatter::try_new(&locale!("en").into(), rule_set.into())
+///         .expect("locale should be present");
+///     assert_writeable_eq!(fmt.format(&value), expected);
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RuleSetKind {
+    /// Numbers in words, such as "one hundred twenty-three".
+    #[default]
+    SpelloutCardinal,
+
+    /// Ordinal numbers in words, such as "one hundred twenty-third".
+    SpelloutOrdinal,
+
+    /// Ordinal numbers in digits, such as "123rd".
+    DigitsOrdinal,
+
+    /// Numbers in the algorithmic numbering system selected with the `-u-nu` keyword of the
+    /// locale, such as "MMXXIV" for `-u-nu-roman` or "ה׳תשפ״ד" for `-u-nu-hebr`.
+    ///
+    /// Numbers in numeric numbering systems, such as `latn` or `arab`, or in locales without a
+    /// `-u-nu` keyword are formatted with digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_experimental::rbnf::options::RuleSetKind;
+    /// use icu_experimental::rbnf::RuleBasedNumberFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let value = 2024.into();
+    /// let cases = [
+    ///     (locale!("en-u-nu-roman"), "MMXXIV"),
+    ///     (locale!("en-u-nu-romanlow"), "mmxxiv"),
+    ///     (locale!("ja-u-nu-jpan"), "二千二十四"),
+    ///     (locale!("en"), "2,024"),
+    /// ];
+    /// for (locale, expected) in cases {
+    ///     let fmt =
+    ///         RuleBasedNumberFormatter::try_new(&locale.into(), RuleSetKind::NumberingSystem.into())
+    ///             .expect("locale should be present");
+    ///     assert_writeable_eq!(fmt.format(&value), expected);
+    /// }
+    /// ```
+    NumberingSystem,
+}
+
+/// The grammatical gender of the noun a number refers to.
+///
+/// # Examples
+///
+/// ```
+/// use icu_experimental::rbnf::options::{RuleSetGender, RuleSetKind};
+/// use icu_experimental::rbnf::{
+///     RuleBasedNumberFormatter, RuleBasedNumberFormatterOptions,
+/// };
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = RuleBasedNumberFormatterOptions::from(RuleSetKind::SpelloutOrdinal);
+/// options.gender = Some(RuleSetGender::Feminine);
+/// let fmt = RuleBasedNumberFormatter::try_new(&locale!("fr").into(), options)
+///     .expect("locale should be present");
+/// let value = 1.into();
+/// assert_writeable_eq!(fmt.format(&value), "première");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RuleSetGender {
+    /// The masculine gender.
+    Masculine,
+    /// The feminine gender.
+    Feminine,
+    /// The neuter gender.
+    Neuter,
+}
+
+impl RuleSetGender {
+    pub(crate) fn suffix(self) -> &'static str {
+        match self {
+            Self::Masculine => "-masculine",
+            Self::Feminine => "-feminine",
+            Self::Neuter => "-neuter",
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable.
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::prelude::*;
+use tinystr::UnvalidatedTinyAsciiStr;
+use zerovec::ule::UnvalidatedStr;
+use zerovec::ZeroMap;
+
+#[cfg(feature = "compiled_data")]
+/// Baked data
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. In particular, the `DataProvider` implementations are only
+/// guaranteed to match with this version's `*_unstable` providers. Use with caution.
+/// </div>
+pub use crate::provider::Baked;
+
+/// A group of rule sets from the CLDR `rbnf` data, such as the `SpelloutRules` of a locale.
+///
+/// The rules are stored in the syntax of the ICU rule-based number format, with one rule per
+/// `descriptor: text;` entry, such as `"0: zero;1: one;20: twenty[->>];"`. They are parsed when
+/// constructing a [`RuleBasedNumberFormatter`](crate::rbnf::RuleBasedNumberFormatter).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(
+    marker(SpelloutRulesV1Marker, "rbnf/spellout@1"),
+    marker(OrdinalRulesV1Marker, "rbnf/ordinal@1")
+)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::rbnf::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RuleBasedNumberFormatV1<'data> {
+    /// A map from rule set names, such as `"%spellout-cardinal"`, to their rules.
+    ///
+    /// Private rule sets, which can only be referenced by other rule sets of the group, start
+    /// with `"%%"`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rule_sets: ZeroMap<'data, UnvalidatedStr, str>,
+}
+
+/// The rules of the algorithmic numbering systems, such as `roman` or `hebr`, which are
+/// selected with the `-u-nu` keyword of a locale.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(NumberingSystemRulesV1Marker, "rbnf/numbering@1", singleton))]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_experimental::rbnf::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct NumberingSystemRulesV1<'data> {
+    /// A map from numbering systems, such as `"roman"`, to the rule set that formats numbers
+    /// in them.
+    ///
+    /// Rule sets of [`rules`](Self::rules) are referenced by their name, such as
+    /// `"%roman-upper"`, and spellout rule sets of a locale by the locale and the name, such as
+    /// `"ja/%spellout-cardinal"`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub systems: ZeroMap<'data, UnvalidatedTinyAsciiStr<8>, str>,
+
+    /// The rule sets of the numbering systems that are not spelled out in a locale.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: RuleBasedNumberFormatV1<'data>,
+}
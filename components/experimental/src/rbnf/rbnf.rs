@@ -0,0 +1,492 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::format;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV2Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_locid::extensions::unicode::key;
+use icu_locid::LanguageIdentifier;
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+
+use crate::rbnf::format::FormattedRuleBasedNumber;
+use crate::rbnf::options::{RuleBasedNumberFormatterOptions, RuleSetKind};
+use crate::rbnf::provider::{
+    NumberingSystemRulesV1Marker, OrdinalRulesV1Marker, RuleBasedNumberFormatV1,
+    SpelloutRulesV1Marker,
+};
+use crate::rbnf::rules::{index_of, parse_rule_sets, RuleSet};
+use crate::rbnf::RbnfError;
+
+/// A formatter for numbers in words, such as "one hundred twenty-three", or ordinal numbers,
+/// such as "123rd", using the rule-based number format rules of a locale.
+///
+/// [`RuleBasedNumberFormatter`] supports:
+///   1. The spellout and ordinal rule sets of CLDR, selected with [`RuleSetKind`].
+///   2. Rule sets for a grammatical gender, selected with
+///      [`RuleSetGender`](crate::rbnf::options::RuleSetGender).
+///   3. Algorithmic numbering systems, such as Roman or Hebrew numerals, selected with the
+///      `-u-nu` keyword of the locale and [`RuleSetKind::NumberingSystem`].
+///   4. Negative numbers and numbers with a fraction, if the rule set has rules for them.
+///
+/// Numbers that the rules do not cover, such as very large numbers, are formatted with digits.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_experimental::rbnf::RuleBasedNumberFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let fmt = RuleBasedNumberFormatter::try_new(&locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+///
+/// let value = FixedDecimal::from(123);
+/// assert_writeable_eq!(fmt.format(&value), "one hundred twenty-three");
+///
+/// let value = FixedDecimal::from(-7);
+/// assert_writeable_eq!(fmt.format(&value), "minus seven");
+///
+/// let value = "1.25".parse().unwrap();
+/// assert_writeable_eq!(fmt.format(&value), "one point two five");
+/// ```
+#[derive(Debug)]
+pub struct RuleBasedNumberFormatter {
+    /// All rule sets of the group, which can reference each other by index.
+    pub(crate) rule_sets: Vec<RuleSet>,
+
+    /// The index of the rule set used to format numbers, or `None` to format them with digits.
+    pub(crate) rule_set: Option<usize>,
+
+    /// A [`FixedDecimalFormatter`] for rules that format numbers with digits.
+    pub(crate) fixed_decimal_formatter: FixedDecimalFormatter,
+
+    /// The plural rules used by `$(cardinal,…)$` rule texts.
+    pub(crate) cardinal_rules: PluralRules,
+
+    /// The plural rules used by `$(ordinal,…)$` rule texts.
+    pub(crate) ordinal_rules: PluralRules,
+}
+
+impl RuleBasedNumberFormatter {
+    /// Creates a new [`RuleBasedNumberFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: RuleBasedNumberFormatterOptions,
+    ) -> Result<Self, RbnfError> {
+        let (rule_sets, rule_set) = load_rule_sets(&crate::provider::Baked, locale, options)?;
+        Ok(Self {
+            rule_sets,
+            rule_set,
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new(locale, Default::default())?,
+            cardinal_rules: PluralRules::try_new_cardinal(locale)?,
+            ordinal_rules: PluralRules::try_new_ordinal(locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: RuleBasedNumberFormatterOptions,
+        error: RbnfError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: RuleBasedNumberFormatterOptions,
+    ) -> Result<Self, RbnfError>
+    where
+        D: DataProvider<SpelloutRulesV1Marker>
+            + DataProvider<OrdinalRulesV1Marker>
+            + DataProvider<NumberingSystemRulesV1Marker>
+            + DataProvider<DecimalSymbolsV2Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + ?Sized,
+    {
+        let (rule_sets, rule_set) = load_rule_sets(provider, locale, options)?;
+        Ok(Self {
+            rule_sets,
+            rule_set,
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                provider,
+                locale,
+                Default::default(),
+            )?,
+            cardinal_rules: PluralRules::try_new_cardinal_unstable(provider, locale)?,
+            ordinal_rules: PluralRules::try_new_ordinal_unstable(provider, locale)?,
+        })
+    }
+
+    /// Formats a [`FixedDecimal`] with the rule set of this formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_experimental::rbnf::options::RuleSetKind;
+    /// use icu_experimental::rbnf::RuleBasedNumberFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let fmt = RuleBasedNumberFormatter::try_new(
+    ///     &locale!("ja").into(),
+    ///     RuleSetKind::SpelloutCardinal.into(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// let value = FixedDecimal::from(12345);
+    /// assert_writeable_eq!(fmt.format(&value), "一万二千三百四十五");
+    /// ```
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedRuleBasedNumber<'l> {
+        FormattedRuleBasedNumber {
+            formatter: self,
+            value,
+        }
+    }
+}
+
+/// Loads the rule sets of the group containing the requested rule set, and returns them with
+/// the index of the requested rule set.
+fn load_rule_sets<P>(
+    provider: &P,
+    locale: &DataLocale,
+    options: RuleBasedNumberFormatterOptions,
+) -> Result<(Vec<RuleSet>, Option<usize>), RbnfError>
+where
+    P: DataProvider<SpelloutRulesV1Marker>
+        + DataProvider<OrdinalRulesV1Marker>
+        + DataProvider<NumberingSystemRulesV1Marker>
+        + ?Sized,
+{
+    let req = DataRequest {
+        locale,
+        metadata: Default::default(),
+    };
+    let name = match options.rule_set {
+        RuleSetKind::SpelloutCardinal => "%spellout-cardinal",
+        RuleSetKind::SpelloutOrdinal => "%spellout-ordinal",
+        RuleSetKind::DigitsOrdinal => {
+            let payload =
+                DataProvider::<OrdinalRulesV1Marker>::load(provider, req)?.take_payload()?;
+            return select_rule_set(payload.get(), "%digits-ordinal", options);
+        }
+        RuleSetKind::NumberingSystem => return load_numbering_system(provider, locale),
+    };
+    let payload = DataProvider::<SpelloutRulesV1Marker>::load(provider, req)?.take_payload()?;
+    select_rule_set(payload.get(), name, options)
+}
+
+/// Loads the rule sets of the numbering system selected with the `-u-nu` keyword of a locale.
+fn load_numbering_system<P>(
+    provider: &P,
+    locale: &DataLocale,
+) -> Result<(Vec<RuleSet>, Option<usize>), RbnfError>
+where
+    P: DataProvider<SpelloutRulesV1Marker> + DataProvider<NumberingSystemRulesV1Marker> + ?Sized,
+{
+    let payload = DataProvider::<NumberingSystemRulesV1Marker>::load(provider, Default::default())?
+        .take_payload()?;
+    let data = payload.get();
+    // Numeric numbering systems have no rules, and are formatted with digits.
+    let Some(target) = locale
+        .get_unicode_ext(&key!("nu"))
+        .as_ref()
+        .and_then(|nu| nu.as_single_subtag())
+        .and_then(|nu| data.systems.get(&nu.to_unvalidated()))
+    else {
+        return Ok((Vec::new(), None));
+    };
+    let Some((langid, name)) = target.split_once('/') else {
+        return select_named_rule_set(&data.rules, target);
+    };
+    // Some numbering systems, such as `jpan`, use the spellout rules of a locale.
+    let langid = langid.parse::<LanguageIdentifier>().map_err(|_| {
+        DataError::custom("Invalid numbering system locale").with_display_context(target)
+    })?;
+    let payload = DataProvider::<SpelloutRulesV1Marker>::load(
+        provider,
+        DataRequest {
+            locale: &langid.into(),
+            metadata: Default::default(),
+        },
+    )?
+    .take_payload()?;
+    select_named_rule_set(payload.get(), name)
+}
+
+fn select_rule_set(
+    data: &RuleBasedNumberFormatV1,
+    name: &str,
+    options: RuleBasedNumberFormatterOptions,
+) -> Result<(Vec<RuleSet>, Option<usize>), RbnfError> {
+    // Rule sets for a gender fall back to the rule set without gender, and languages that
+    // always have a gender use the masculine rule set by default.
+    let index = options
+        .gender
+        .and_then(|gender| index_of(data, &format!("{name}{}", gender.suffix())))
+        .or_else(|| index_of(data, name))
+        .or_else(|| index_of(data, &format!("{name}-masculine")))
+        .ok_or(RbnfError::MissingRuleSet)?;
+    Ok((parse_rule_sets(data)?, Some(index)))
+}
+
+fn select_named_rule_set(
+    data: &RuleBasedNumberFormatV1,
+    name: &str,
+) -> Result<(Vec<RuleSet>, Option<usize>), RbnfError> {
+    let index = index_of(data, name).ok_or(RbnfError::MissingRuleSet)?;
+    Ok((parse_rule_sets(data)?, Some(index)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rbnf::options::RuleSetGender;
+    use icu_locid::locale;
+    use writeable::assert_writeable_eq;
+    use zerovec::ule::UnvalidatedStr;
+
+    fn formatter(
+        locale: &DataLocale,
+        rule_set: RuleSetKind,
+        gender: Option<RuleSetGender>,
+    ) -> RuleBasedNumberFormatter {
+        let mut options = RuleBasedNumberFormatterOptions::from(rule_set);
+        options.gender = gender;
+        RuleBasedNumberFormatter::try_new(locale, options).unwrap()
+    }
+
+    #[test]
+    fn test_spellout_cardinal() {
+        let cases = [
+            (locale!("en"), "0", "zero"),
+            (locale!("en"), "15", "fifteen"),
+            (locale!("en"), "40", "forty"),
+            (locale!("en"), "123", "one hundred twenty-three"),
+            (locale!("en"), "1000", "one thousand"),
+            (locale!("en"), "2024", "two thousand twenty-four"),
+            (
+                locale!("en"),
+                "1234567",
+                "one million two hundred thirty-four thousand five hundred sixty-seven",
+            ),
+            (locale!("en"), "-42", "minus forty-two"),
+            (locale!("en"), "3.14", "three point one four"),
+            (
+                locale!("en"),
+                "1000000000000000000",
+                "1,000,000,000,000,000,000",
+            ),
+            (locale!("es"), "21", "veintiuno"),
+            (locale!("es"), "100", "cien"),
+            (locale!("es"), "101", "ciento uno"),
+            (locale!("es"), "1999", "mil novecientos noventa y nueve"),
+            (locale!("es"), "2000000", "dos millones"),
+            (locale!("fr"), "21", "vingt-et-un"),
+            (locale!("fr"), "71", "soixante-et-onze"),
+            (locale!("fr"), "80", "quatre-vingts"),
+            (locale!("fr"), "99", "quatre-vingt-dix-neuf"),
+            (locale!("fr"), "200", "deux cents"),
+            (locale!("fr"), "201", "deux cent un"),
+            (locale!("fr"), "1.5", "un virgule cinq"),
+            (locale!("ja"), "0", "〇"),
+            (locale!("ja"), "12345", "一万二千三百四十五"),
+            (locale!("ja"), "100000000", "一億"),
+            (locale!("ja"), "1.25", "一点二五"),
+        ];
+        for (locale, value, expected) in cases {
+            let fmt = formatter(&(&locale).into(), RuleSetKind::SpelloutCardinal, None);
+            let value = value.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&value), expected, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_spellout_ordinal() {
+        let cases = [
+            (locale!("en"), "1", "first"),
+            (locale!("en"), "12", "twelfth"),
+            (locale!("en"), "13", "thirteenth"),
+            (locale!("en"), "20", "twentieth"),
+            (locale!("en"), "21", "twenty-first"),
+            (locale!("en"), "100", "one hundredth"),
+            (locale!("en"), "123", "one hundred twenty-third"),
+            (locale!("es"), "3", "tercero"),
+            (locale!("es"), "13", "decimotercero"),
+            (locale!("es"), "25", "vigésimo quinto"),
+            (locale!("fr"), "1", "premier"),
+            (locale!("fr"), "21", "vingt-et-unième"),
+            (locale!("fr"), "30", "trentième"),
+            (locale!("fr"), "71", "soixante-et-onzième"),
+            (locale!("fr"), "100", "centième"),
+            (locale!("fr"), "1001", "mille unième"),
+            (locale!("ja"), "3", "第三"),
+        ];
+        for (locale, value, expected) in cases {
+            let fmt = formatter(&(&locale).into(), RuleSetKind::SpelloutOrdinal, None);
+            let value = value.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&value), expected, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_digits_ordinal() {
+        let cases = [
+            (locale!("en"), "1", "1st"),
+            (locale!("en"), "2", "2nd"),
+            (locale!("en"), "3", "3rd"),
+            (locale!("en"), "11", "11th"),
+            (locale!("en"), "22", "22nd"),
+            (locale!("en"), "1001", "1,001st"),
+            (locale!("en"), "-1", "−1st"),
+            (locale!("es"), "1", "1º"),
+            (locale!("fr"), "1", "1er"),
+            (locale!("fr"), "2", "2e"),
+            (locale!("ja"), "3", "第3"),
+        ];
+        for (locale, value, expected) in cases {
+            let fmt = formatter(&(&locale).into(), RuleSetKind::DigitsOrdinal, None);
+            let value = value.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&value), expected, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_numbering_system() {
+        let cases = [
+            (locale!("en-u-nu-roman"), "0", "N"),
+            (locale!("en-u-nu-roman"), "4", "IV"),
+            (locale!("en-u-nu-roman"), "1999", "MCMXCIX"),
+            (locale!("en-u-nu-roman"), "3888", "MMMDCCCLXXXVIII"),
+            (locale!("en-u-nu-roman"), "5000", "5,000"),
+            (locale!("en-u-nu-roman"), "-5", "−V"),
+            (locale!("fr-u-nu-romanlow"), "2024", "mmxxiv"),
+            (locale!("en-u-nu-hebr"), "5", "ה׳"),
+            (locale!("en-u-nu-hebr"), "15", "ט״ו"),
+            (locale!("en-u-nu-hebr"), "18", "י״ח"),
+            (locale!("en-u-nu-hebr"), "300", "ש׳"),
+            (locale!("en-u-nu-hebr"), "500", "ת״ק"),
+            (locale!("en-u-nu-hebr"), "115", "קט״ו"),
+            (locale!("en-u-nu-hebr"), "5784", "ה׳תשפ״ד"),
+            (locale!("en-u-nu-armn"), "2024", "ՍԻԴ"),
+            (locale!("en-u-nu-armnlow"), "2024", "սիդ"),
+            (locale!("en-u-nu-geor"), "2024", "ცკდ"),
+            (locale!("en-u-nu-ethi"), "100", "፻"),
+            (locale!("en-u-nu-ethi"), "2024", "፳፻፳፬"),
+            (locale!("en-u-nu-ethi"), "12345", "፼፳፫፻፵፭"),
+            (locale!("ja-u-nu-jpan"), "12345", "一万二千三百四十五"),
+            (locale!("en-u-nu-hans"), "12", "十二"),
+            (locale!("zh-u-nu-hans"), "2024", "二千零二十四"),
+            (locale!("zh-u-nu-hans"), "100010", "十万零一十"),
+            // Numeric numbering systems are formatted with digits.
+            (locale!("en"), "2024", "2,024"),
+            (locale!("en-u-nu-latn"), "2024", "2,024"),
+        ];
+        for (locale, value, expected) in cases {
+            let fmt = formatter(&(&locale).into(), RuleSetKind::NumberingSystem, None);
+            let value = value.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&value), expected, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_gender() {
+        let cases = [
+            (
+                locale!("es"),
+                RuleSetKind::SpelloutCardinal,
+                "21",
+                "veintiuna",
+            ),
+            (
+                locale!("es"),
+                RuleSetKind::SpelloutCardinal,
+                "200",
+                "doscientas",
+            ),
+            (locale!("es"), RuleSetKind::SpelloutOrdinal, "2", "segunda"),
+            (locale!("es"), RuleSetKind::DigitsOrdinal, "2", "2ª"),
+            (locale!("fr"), RuleSetKind::SpelloutCardinal, "1", "une"),
+            (
+                locale!("fr"),
+                RuleSetKind::SpelloutCardinal,
+                "41",
+                "quarante-et-une",
+            ),
+            (locale!("fr"), RuleSetKind::SpelloutOrdinal, "1", "première"),
+            (locale!("fr"), RuleSetKind::DigitsOrdinal, "1", "1re"),
+            // Rule sets without gender are used for languages without them.
+            (locale!("en"), RuleSetKind::SpelloutCardinal, "1", "one"),
+        ];
+        for (locale, rule_set, value, expected) in cases {
+            let fmt = formatter(&(&locale).into(), rule_set, Some(RuleSetGender::Feminine));
+            let value = value.parse().unwrap();
+            assert_writeable_eq!(fmt.format(&value), expected, "{locale}");
+        }
+
+        // The masculine rule set is the default for languages with genders.
+        let one = FixedDecimal::from(1);
+        let fmt = formatter(&locale!("es").into(), RuleSetKind::SpelloutCardinal, None);
+        assert_writeable_eq!(fmt.format(&one), "uno");
+        let fmt = formatter(
+            &locale!("fr").into(),
+            RuleSetKind::SpelloutCardinal,
+            Some(RuleSetGender::Neuter),
+        );
+        assert_writeable_eq!(fmt.format(&one), "un");
+    }
+
+    #[test]
+    fn test_recursive_rules() {
+        let parse = |rule_sets: Vec<(String, String)>| {
+            let data = RuleBasedNumberFormatV1 {
+                rule_sets: rule_sets
+                    .iter()
+                    .map(|(name, rules)| (UnvalidatedStr::from_str(name), rules.as_str()))
+                    .collect(),
+            };
+            parse_rule_sets(&data).map(|_| ())
+        };
+        let chain = |length: usize| {
+            (0..length)
+                .map(|i| (format!("%s{i:03}"), format!("0: =%s{:03}=;", i + 1)))
+                .chain([(format!("%s{length:03}"), "0: =#,##0=;".into())])
+                .collect::<Vec<_>>()
+        };
+
+        assert!(parse(vec![("%loop".into(), "0: =%loop=;".into())]).is_err());
+        assert!(parse(vec![
+            ("%a".into(), "0: x; 1: <%b<;".into()),
+            ("%b".into(), "0: =%a=;".into()),
+        ])
+        .is_err());
+        // Substitutions that make the number smaller can reference each other.
+        assert!(parse(vec![
+            ("%a".into(), "0: x; 10: <%b<;".into()),
+            ("%b".into(), "0: =%a=;".into()),
+        ])
+        .is_ok());
+        assert!(parse(chain(63)).is_ok());
+        assert!(parse(chain(64)).is_err());
+    }
+}
@@ -0,0 +1,390 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for the rules of the ICU rule-based number format.
+//!
+//! See <https://unicode-org.github.io/icu/userguide/format_parse/numbers/rbnf.html> for the syntax.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_plurals::PluralCategory;
+use icu_provider::DataError;
+use zerovec::ule::UnvalidatedStr;
+
+use crate::rbnf::provider::RuleBasedNumberFormatV1;
+
+/// A parsed rule set.
+#[derive(Debug)]
+pub(crate) struct RuleSet {
+    /// The rules for non-negative integers, sorted by base value.
+    pub(crate) rules: Vec<Rule>,
+    /// The `-x` rule, for negative numbers.
+    pub(crate) negative: Option<Rule>,
+    /// The `x.x` rule, for numbers with a fraction.
+    pub(crate) fraction: Option<Rule>,
+}
+
+/// A parsed rule.
+#[derive(Debug)]
+pub(crate) struct Rule {
+    /// The smallest value this rule applies to.
+    pub(crate) base: u128,
+    /// The value by which `<<` and `>>` substitutions divide the number.
+    pub(crate) divisor: u128,
+    pub(crate) parts: Vec<Part>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Part {
+    Literal(String),
+    Substitution(Substitution),
+    /// Text between brackets, which is omitted when the number is a multiple of the divisor.
+    Optional(Vec<Part>),
+    /// A `$(cardinal,one{…}other{…})$` or `$(ordinal,…)$` text, selected by plural category.
+    Plural(Plural),
+}
+
+#[derive(Debug)]
+pub(crate) struct Substitution {
+    pub(crate) kind: SubstitutionKind,
+    pub(crate) target: Target,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SubstitutionKind {
+    /// `<<`: the number divided by the divisor, or the integer part of a fraction.
+    Quotient,
+    /// `>>`: the remainder of the division, or the digits of a fraction.
+    ///
+    /// `>>>` is `triple`: the remainder is formatted with the preceding rule instead of
+    /// searching the rule set, and the digits of a fraction are not separated by spaces.
+    Remainder { triple: bool },
+    /// `==`: the number itself.
+    Same,
+}
+
+/// What a substitution is formatted with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Target {
+    /// The rule set containing the rule.
+    Inherit,
+    /// Another rule set of the group, by index.
+    RuleSet(usize),
+    /// A decimal format pattern, such as `#,##0`.
+    Decimal,
+}
+
+#[derive(Debug)]
+pub(crate) struct Plural {
+    pub(crate) ordinal: bool,
+    pub(crate) variants: Vec<(PluralCategory, String)>,
+}
+
+impl Plural {
+    /// Returns the text for a category, falling back to `other`.
+    pub(crate) fn get(&self, category: PluralCategory) -> &str {
+        self.variants
+            .iter()
+            .find(|(c, _)| *c == category)
+            .or_else(|| {
+                self.variants
+                    .iter()
+                    .find(|(c, _)| *c == PluralCategory::Other)
+            })
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_default()
+    }
+}
+
+impl Rule {
+    /// Returns whether the preceding rule should be used instead of this one.
+    ///
+    /// Like ICU, this is the case when the rule has a remainder substitution, the number is a
+    /// multiple of the divisor, and the base value of the rule is not. For example, with
+    /// `"1010/100: << >>;"`, 1100 is not formatted with this rule.
+    pub(crate) fn should_roll_back(&self, n: u128) -> bool {
+        fn has_remainder(parts: &[Part]) -> bool {
+            parts.iter().any(|part| match part {
+                Part::Substitution(s) => matches!(s.kind, SubstitutionKind::Remainder { .. }),
+                Part::Optional(parts) => has_remainder(parts),
+                _ => false,
+            })
+        }
+        has_remainder(&self.parts) && n % self.divisor == 0 && self.base % self.divisor != 0
+    }
+}
+
+/// Returns the index of a rule set in the data.
+pub(crate) fn index_of(data: &RuleBasedNumberFormatV1, name: &str) -> Option<usize> {
+    data.rule_sets
+        .iter_keys()
+        .position(|key| key == UnvalidatedStr::from_str(name))
+}
+
+/// The maximum number of rule sets that pass the same value on to each other.
+pub(crate) const MAX_DEPTH: u8 = 64;
+
+/// Parses all rule sets of a group, so that they can reference each other by index.
+///
+/// Fails if rule sets pass a value on to each other in a cycle, or through more than
+/// [`MAX_DEPTH`] rule sets.
+pub(crate) fn parse_rule_sets(data: &RuleBasedNumberFormatV1) -> Result<Vec<RuleSet>, DataError> {
+    let rule_sets = data
+        .rule_sets
+        .iter_values()
+        .map(|rules| parse_rule_set(data, rules))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut depths = alloc::vec![Depth::Unknown; 2 * rule_sets.len()];
+    for node in 0..depths.len() {
+        if let Err(set) = check_depth(&rule_sets, node, &mut depths) {
+            let name = data.rule_sets.iter_keys().nth(set).map(|name| &**name);
+            return Err(DataError::custom("Recursive RBNF rules")
+                .with_display_context(&String::from_utf8_lossy(name.unwrap_or_default())));
+        }
+    }
+    Ok(rule_sets)
+}
+
+/// The state of a rule set during the search of [`check_depth`].
+#[derive(Debug, Clone, Copy)]
+enum Depth {
+    Unknown,
+    /// The rule set is on the path being searched.
+    Visiting,
+    /// The number of nested rule sets that the rule set passes the same value on to.
+    Known(u8),
+}
+
+/// Computes the depth of a node, which is the index of a rule set times two, plus one for
+/// the fraction rule of the rule set.
+///
+/// Only the substitutions that do not make the number smaller are followed, which are `==`
+/// substitutions and the substitutions of rules with a divisor of 1. The others end because
+/// the number gets smaller, or because the integer part of a fraction does not have a fraction.
+///
+/// Returns the index of the rule set where a cycle or too deep nesting was found.
+fn check_depth(rule_sets: &[RuleSet], node: usize, depths: &mut [Depth]) -> Result<u8, usize> {
+    /// Collects the targets of the substitutions in `parts`, or only of the `==` ones.
+    fn targets(set: usize, parts: &[Part], all: bool, out: &mut Vec<usize>) {
+        for part in parts {
+            match part {
+                Part::Substitution(s) if all || s.kind == SubstitutionKind::Same => {
+                    match s.target {
+                        Target::Inherit => out.push(set),
+                        Target::RuleSet(index) => out.push(index),
+                        Target::Decimal => (),
+                    }
+                }
+                Part::Optional(parts) => targets(set, parts, all, out),
+                _ => (),
+            }
+        }
+    }
+
+    let set = node / 2;
+    match depths[node] {
+        Depth::Known(depth) => return Ok(depth),
+        Depth::Visiting => return Err(set),
+        Depth::Unknown => depths[node] = Depth::Visiting,
+    }
+
+    let rule_set = &rule_sets[set];
+    let mut next = Vec::new();
+    if node % 2 == 0 {
+        for rule in &rule_set.rules {
+            targets(set, &rule.parts, rule.divisor == 1, &mut next);
+        }
+    } else if let Some(rule) = &rule_set.fraction {
+        targets(set, &rule.parts, false, &mut next);
+    }
+
+    let mut depth = 0;
+    for target in next {
+        let target = 2 * target + node % 2;
+        depth = depth.max(check_depth(rule_sets, target, depths)?);
+    }
+    let depth = depth + 1;
+    if depth > MAX_DEPTH {
+        return Err(set);
+    }
+    depths[node] = Depth::Known(depth);
+    Ok(depth)
+}
+
+fn invalid(rule: &str) -> DataError {
+    DataError::custom("Invalid RBNF rule").with_display_context(rule)
+}
+
+fn parse_rule_set(data: &RuleBasedNumberFormatV1, rules: &str) -> Result<RuleSet, DataError> {
+    let mut rule_set = RuleSet {
+        rules: Vec::new(),
+        negative: None,
+        fraction: None,
+    };
+    for rule in rules.split(';') {
+        let rule = rule.trim_start();
+        if rule.is_empty() {
+            continue;
+        }
+        let (descriptor, text) = rule.split_once(':').ok_or_else(|| invalid(rule))?;
+        // A leading apostrophe keeps the whitespace that follows it.
+        let text = text.trim_start();
+        let text = text.strip_prefix('\'').unwrap_or(text);
+        let parts = parse_parts(data, text).ok_or_else(|| invalid(rule))?;
+        match descriptor.trim() {
+            "-x" => {
+                rule_set.negative = Some(Rule {
+                    base: 0,
+                    divisor: 1,
+                    parts,
+                })
+            }
+            "x.x" => {
+                rule_set.fraction = Some(Rule {
+                    base: 0,
+                    divisor: 1,
+                    parts,
+                })
+            }
+            // `FixedDecimal` has no infinity or NaN, and fractions are formatted with `x.x`.
+            "Inf" | "NaN" | "0.x" | "x.0" => {}
+            descriptor => {
+                let (base, divisor) = parse_base(descriptor).ok_or_else(|| invalid(rule))?;
+                rule_set.rules.push(Rule {
+                    base,
+                    divisor,
+                    parts,
+                });
+            }
+        }
+    }
+    Ok(rule_set)
+}
+
+/// Parses a descriptor such as `100`, `60/20` or `100>`, and returns the base value and divisor.
+fn parse_base(descriptor: &str) -> Option<(u128, u128)> {
+    let reduce = descriptor.len() - descriptor.trim_end_matches('>').len();
+    let descriptor = descriptor.trim_end_matches('>');
+    let (base, radix) = match descriptor.split_once('/') {
+        Some((base, radix)) => (base.parse().ok()?, radix.parse().ok()?),
+        None => (descriptor.parse().ok()?, 10),
+    };
+    if radix < 2 {
+        return None;
+    }
+    // The divisor is the highest power of the radix that is not greater than the base value.
+    let mut divisor: u128 = 1;
+    while let Some(next) = divisor.checked_mul(radix).filter(|next| *next <= base) {
+        divisor = next;
+    }
+    for _ in 0..reduce {
+        divisor = (divisor / radix).max(1);
+    }
+    Some((base, divisor))
+}
+
+fn parse_parts(data: &RuleBasedNumberFormatV1, text: &str) -> Option<Vec<Part>> {
+    let mut parts = Vec::new();
+    let mut optional: Option<Vec<Part>> = None;
+    let mut literal = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        let part = match c {
+            '[' => {
+                if optional.is_some() {
+                    return None;
+                }
+                flush(&mut literal, &mut parts);
+                optional = Some(Vec::new());
+                continue;
+            }
+            ']' => {
+                let mut optional_parts = optional.take()?;
+                flush(&mut literal, &mut optional_parts);
+                parts.push(Part::Optional(optional_parts));
+                continue;
+            }
+            '$' if rest.starts_with('(') => {
+                let (plural, after) = rest[1..].split_once(")$")?;
+                rest = after;
+                Part::Plural(parse_plural(plural)?)
+            }
+            '<' | '←' | '>' | '→' | '=' => {
+                let is_token = |d: char| match c {
+                    '<' | '←' => d == '<' || d == '←',
+                    '>' | '→' => d == '>' || d == '→',
+                    _ => d == '=',
+                };
+                let end = rest.find(is_token)?;
+                let description = &rest[..end];
+                rest = &rest[end..];
+                rest = &rest[rest.chars().next()?.len_utf8()..];
+                let kind = match c {
+                    '<' | '←' => SubstitutionKind::Quotient,
+                    '>' | '→' => {
+                        let triple = description.is_empty() && rest.starts_with(is_token);
+                        if triple {
+                            rest = &rest[rest.chars().next()?.len_utf8()..];
+                        }
+                        SubstitutionKind::Remainder { triple }
+                    }
+                    _ => SubstitutionKind::Same,
+                };
+                let target = if description.is_empty() {
+                    Target::Inherit
+                } else if description.starts_with('%') {
+                    Target::RuleSet(index_of(data, description)?)
+                } else if description.contains(['#', '0']) {
+                    Target::Decimal
+                } else {
+                    return None;
+                };
+                Part::Substitution(Substitution { kind, target })
+            }
+            _ => {
+                literal.push(c);
+                continue;
+            }
+        };
+        let parts = optional.as_mut().unwrap_or(&mut parts);
+        flush(&mut literal, parts);
+        parts.push(part);
+    }
+
+    if optional.is_some() {
+        return None;
+    }
+    flush(&mut literal, &mut parts);
+    Some(parts)
+}
+
+fn flush(literal: &mut String, parts: &mut Vec<Part>) {
+    if !literal.is_empty() {
+        parts.push(Part::Literal(core::mem::take(literal)));
+    }
+}
+
+/// Parses the inside of `$(ordinal,one{st}two{nd}few{rd}other{th})$`.
+fn parse_plural(text: &str) -> Option<Plural> {
+    let (kind, mut rest) = text.split_once(',')?;
+    let ordinal = match kind {
+        "cardinal" => false,
+        "ordinal" => true,
+        _ => return None,
+    };
+    let mut variants = Vec::new();
+    while !rest.is_empty() {
+        let (category, after) = rest.split_once('{')?;
+        let (variant, after) = after.split_once('}')?;
+        variants.push((
+            PluralCategory::get_for_cldr_string(category.trim())?,
+            variant.into(),
+        ));
+        rest = after;
+    }
+    Some(Plural { ordinal, variants })
+}
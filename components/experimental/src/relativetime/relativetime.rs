@@ -4,7 +4,7 @@
 
 use fixed_decimal::{FixedDecimal, Sign};
 use icu_decimal::{
-    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV2Marker, FixedDecimalFormatter,
 };
 use icu_plurals::{provider::CardinalV1Marker, PluralRules};
 use icu_provider::prelude::*;
@@ -167,7 +167,7 @@ macro_rules! constructor {
         where
             D: DataProvider<CardinalV1Marker>
                 + DataProvider<$marker>
-                + DataProvider<DecimalSymbolsV1Marker>
+                + DataProvider<DecimalSymbolsV2Marker>
                 + ?Sized,
         {
             let plural_rules = PluralRules::try_new_cardinal_unstable(provider, locale)?;
@@ -149,7 +149,11 @@ pub enum PluralRuleType {
 /// assert_eq!(pr.category_for(5_usize), PluralCategory::Other);
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
-#[cfg_attr(feature = "datagen", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals),
+)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 #[repr(u8)]
 #[zerovec::make_ule(PluralCategoryULE)]
@@ -0,0 +1,511 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing and serialization of zoned date times in the Internet Extended Date/Time Format
+//! (IXDTF), as specified in [RFC 9557](https://www.rfc-editor.org/rfc/rfc9557.html).
+//!
+//! [`IxdtfZonedDateTime`] writes a [`DateTime`] together with a [`CustomTimeZone`].
+//!
+//! With the `ixdtf` Cargo feature, the time zone of an IXDTF string can be parsed into a
+//! [`CustomTimeZone`], see [`CustomTimeZone::try_from_str()`].
+
+use crate::{CustomTimeZone, GmtOffset, IanaBcp47RoundTripMapperBorrowed};
+#[cfg(feature = "ixdtf")]
+use crate::{IanaToBcp47MapperBorrowed, TimeZoneError};
+use core::fmt::{self, Write};
+#[cfg(feature = "ixdtf")]
+use displaydoc::Display;
+use icu_calendar::ixdtf::IxdtfOptions;
+use icu_calendar::{AsCalendar, DateTime};
+use writeable::Writeable;
+
+/// How to write the UTC offset in [`IxdtfZonedDateTime`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum IxdtfOffsetDisplay {
+    /// Write a numeric offset, e.g. `+05:30` or `+00:00`.
+    #[default]
+    Numeric,
+    /// Write `Z` for an offset of zero, and a numeric offset otherwise.
+    ///
+    /// `Z` means that the local time is unknown, so parsers ignore it in favor of a time zone
+    /// annotation. If a time zone annotation is written, the offset is always numeric.
+    Z,
+    /// Do not write the offset.
+    Never,
+}
+
+/// How to write the time zone annotation in [`IxdtfZonedDateTime`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum IxdtfTimeZoneDisplay {
+    /// Write the annotation, e.g. `[Asia/Kolkata]`.
+    #[default]
+    Auto,
+    /// Write the annotation with the critical flag, e.g. `[!Asia/Kolkata]`.
+    Critical,
+    /// Do not write the annotation.
+    Never,
+}
+
+/// Options for [`IxdtfZonedDateTime`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct ZonedIxdtfOptions {
+    /// Options for the date, time and calendar annotation.
+    pub datetime: IxdtfOptions,
+    /// How to write the UTC offset.
+    pub offset: IxdtfOffsetDisplay,
+    /// How to write the time zone annotation.
+    pub time_zone: IxdtfTimeZoneDisplay,
+}
+
+/// A [`DateTime`] with a [`CustomTimeZone`] that can be written as an IXDTF string, as
+/// specified in [RFC 9557](https://www.rfc-editor.org/rfc/rfc9557.html).
+///
+/// The string consists of the local date and time, the GMT offset, and the time zone
+/// annotation, followed by the calendar annotation. The time zone annotation is the IANA
+/// name of the time zone ID if it is known, otherwise the GMT offset if it has no seconds.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::{Date, DateTime};
+/// use icu::timezone::ixdtf::{
+///     IxdtfOffsetDisplay, IxdtfTimeZoneDisplay, IxdtfZonedDateTime,
+///     ZonedIxdtfOptions,
+/// };
+/// use icu::timezone::{CustomTimeZone, IanaBcp47RoundTripMapper};
+/// use writeable::assert_writeable_eq;
+///
+/// let mapper = IanaBcp47RoundTripMapper::new();
+/// let datetime = DateTime::try_new_gregorian_datetime(2024, 3, 11, 10, 0, 0).unwrap();
+///
+/// let mut time_zone = CustomTimeZone::new_empty();
+/// time_zone.gmt_offset = "+05:30".parse().ok();
+/// time_zone.time_zone_id = mapper.as_borrowed().iana_to_bcp47("Asia/Kolkata");
+///
+/// assert_writeable_eq!(
+///     IxdtfZonedDateTime::new(
+///         &datetime,
+///         &time_zone,
+///         mapper.as_borrowed(),
+///         Default::default()
+///     ),
+///     "2024-03-11T10:00:00+05:30[Asia/Kolkata][u-ca=gregory]"
+/// );
+///
+/// let utc = CustomTimeZone::utc();
+/// let mut options = ZonedIxdtfOptions::default();
+/// options.offset = IxdtfOffsetDisplay::Z;
+/// options.time_zone = IxdtfTimeZoneDisplay::Never;
+/// assert_writeable_eq!(
+///     IxdtfZonedDateTime::new(&datetime, &utc, mapper.as_borrowed(), options),
+///     "2024-03-11T10:00:00Z[u-ca=gregory]"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct IxdtfZonedDateTime<'a, A: AsCalendar> {
+    datetime: &'a DateTime<A>,
+    time_zone: &'a CustomTimeZone,
+    mapper: IanaBcp47RoundTripMapperBorrowed<'a>,
+    options: ZonedIxdtfOptions,
+}
+
+impl<'a, A: AsCalendar> IxdtfZonedDateTime<'a, A> {
+    /// Creates a new [`IxdtfZonedDateTime`].
+    ///
+    /// The `mapper` is used to look up the IANA name of the time zone.
+    pub fn new(
+        datetime: &'a DateTime<A>,
+        time_zone: &'a CustomTimeZone,
+        mapper: IanaBcp47RoundTripMapperBorrowed<'a>,
+        options: ZonedIxdtfOptions,
+    ) -> Self {
+        Self {
+            datetime,
+            time_zone,
+            mapper,
+            options,
+        }
+    }
+}
+
+fn write_offset<W: Write + ?Sized>(offset: GmtOffset, sink: &mut W) -> fmt::Result {
+    let seconds = offset.offset_seconds();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.unsigned_abs();
+    write!(sink, "{sign}{:02}:{:02}", seconds / 3600, seconds / 60 % 60)?;
+    if seconds % 60 != 0 {
+        write!(sink, ":{:02}", seconds % 60)?;
+    }
+    Ok(())
+}
+
+impl<'a, A: AsCalendar> Writeable for IxdtfZonedDateTime<'a, A> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let datetime = self.datetime.to_ixdtf(self.options.datetime);
+        datetime.write_date_time_to(sink)?;
+
+        let critical = match self.options.time_zone {
+            IxdtfTimeZoneDisplay::Auto => Some(""),
+            IxdtfTimeZoneDisplay::Critical => Some("!"),
+            IxdtfTimeZoneDisplay::Never => None,
+        };
+        let iana = critical
+            .and(self.time_zone.time_zone_id)
+            .and_then(|id| self.mapper.bcp47_to_iana(id));
+        // Offset time zone annotations cannot have seconds
+        let offset_annotation = self.time_zone.gmt_offset.filter(|offset| {
+            critical.is_some() && iana.is_none() && offset.offset_seconds() % 60 == 0
+        });
+
+        if let Some(offset) = self.time_zone.gmt_offset {
+            match self.options.offset {
+                // Parsers ignore `Z` in favor of a time zone annotation, so it is only
+                // written without one.
+                IxdtfOffsetDisplay::Z
+                    if offset.is_zero() && iana.is_none() && offset_annotation.is_none() =>
+                {
+                    sink.write_char('Z')?
+                }
+                IxdtfOffsetDisplay::Numeric | IxdtfOffsetDisplay::Z => write_offset(offset, sink)?,
+                IxdtfOffsetDisplay::Never => {}
+            }
+        }
+
+        if let Some(critical) = critical {
+            if let Some(iana) = iana {
+                write!(sink, "[{critical}{iana}]")?;
+            } else if let Some(offset) = offset_annotation {
+                write!(sink, "[{critical}")?;
+                write_offset(offset, sink)?;
+                sink.write_char(']')?;
+            }
+        }
+
+        datetime.calendar_annotation().write_to(sink)
+    }
+}
+
+/// This trait is implemented for compatibility with [`fmt!`](alloc::fmt).
+/// To create a string, [`Writeable::write_to_string`] is usually more efficient.
+impl<'a, A: AsCalendar> fmt::Display for IxdtfZonedDateTime<'a, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+/// An error returned when parsing a [`CustomTimeZone`] from an IXDTF string.
+///
+/// ✨ *Enabled with the `ixdtf` Cargo feature.*
+#[cfg(feature = "ixdtf")]
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The string is not valid IXDTF syntax.
+    #[displaydoc("Syntax error in the IXDTF string: {0:?}")]
+    Syntax(ixdtf::parser::ParseError),
+    /// The string contains neither a UTC offset nor a time zone annotation.
+    #[displaydoc("The IXDTF string does not contain a time zone")]
+    MissingFields,
+    /// The time zone annotation is not a known IANA time zone.
+    #[displaydoc("Unknown IANA time zone in the IXDTF string")]
+    UnknownTimeZone,
+    /// The UTC offset is out of range or more precise than seconds.
+    #[displaydoc("{0}")]
+    Offset(TimeZoneError),
+    /// The UTC offset differs from the offset time zone annotation, as in
+    /// `2024-03-11T10:00:00+05:30[+01:00]`.
+    #[displaydoc("The UTC offset contradicts the time zone annotation")]
+    InconsistentOffset,
+}
+
+#[cfg(all(feature = "ixdtf", feature = "std"))]
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "ixdtf")]
+impl From<ixdtf::parser::ParseError> for ParseError {
+    fn from(e: ixdtf::parser::ParseError) -> Self {
+        ParseError::Syntax(e)
+    }
+}
+
+#[cfg(feature = "ixdtf")]
+impl From<TimeZoneError> for ParseError {
+    fn from(e: TimeZoneError) -> Self {
+        ParseError::Offset(e)
+    }
+}
+
+#[cfg(feature = "ixdtf")]
+fn gmt_offset_for_ixdtf(offset: &ixdtf::parser::UtcOffset) -> Result<GmtOffset, ParseError> {
+    if offset.nano_second != 0 {
+        return Err(TimeZoneError::InvalidOffset.into());
+    }
+    let seconds = offset.hour as i32 * 3600 + offset.minute as i32 * 60 + offset.second as i32;
+    Ok(GmtOffset::try_from_offset_seconds(
+        offset.sign as i32 * seconds,
+    )?)
+}
+
+#[cfg(feature = "ixdtf")]
+impl CustomTimeZone {
+    /// Parses a [`CustomTimeZone`] from the time zone part of an IXDTF string, such as
+    /// `2024-03-11T10:00:00+05:30[Asia/Kolkata]`.
+    ///
+    /// The GMT offset is taken from the UTC offset, or from an offset time zone annotation
+    /// such as `[+05:30]`. An IANA time zone annotation is mapped to a BCP-47 time zone ID.
+    /// `Z` only sets the GMT offset if there is no time zone annotation, because it means
+    /// that the local time is unknown rather than that the offset is zero.
+    ///
+    /// A UTC offset that differs from an offset time zone annotation is an error. A UTC
+    /// offset is not checked against an IANA time zone annotation, since that requires the
+    /// offset rules of the time zone: `2024-03-11T10:00:00+01:00[Asia/Kolkata]` is accepted
+    /// with both the offset and the time zone ID set.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `ixdtf` Cargo features.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::timezone::ixdtf::ParseError;
+    /// use icu::timezone::{CustomTimeZone, GmtOffset};
+    /// use tinystr::tinystr;
+    ///
+    /// let time_zone =
+    ///     CustomTimeZone::try_from_str("2024-03-11T10:00:00+05:30[Asia/Kolkata]")
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     time_zone.gmt_offset.map(GmtOffset::offset_seconds),
+    ///     Some(19800)
+    /// );
+    /// assert_eq!(time_zone.time_zone_id, Some(tinystr!(8, "inccu").into()));
+    ///
+    /// assert!(matches!(
+    ///     CustomTimeZone::try_from_str("2024-03-11T10:00:00[Mars/Olympus_Mons]"),
+    ///     Err(ParseError::UnknownTimeZone)
+    /// ));
+    ///
+    /// assert!(matches!(
+    ///     CustomTimeZone::try_from_str("2024-03-11T10:00:00+05:30[+01:00]"),
+    ///     Err(ParseError::InconsistentOffset)
+    /// ));
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_from_str(ixdtf: &str) -> Result<Self, ParseError> {
+        let ixdtf = ixdtf::parser::DateTimeParser::new(ixdtf.as_bytes()).parse()?;
+        Self::try_from_ixdtf(&ixdtf, crate::IanaToBcp47Mapper::new().as_borrowed())
+    }
+
+    /// Creates a [`CustomTimeZone`] from the result of the `ixdtf` parser, using `mapper`
+    /// to look up IANA time zone annotations.
+    ///
+    /// See [`Self::try_from_str()`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    pub fn try_from_ixdtf(
+        ixdtf: &ixdtf::parser::ParsedDateTime,
+        mapper: IanaToBcp47MapperBorrowed,
+    ) -> Result<Self, ParseError> {
+        use ixdtf::parser::{Offset, TimeZoneRecord};
+
+        let mut time_zone = CustomTimeZone::new_empty();
+        match ixdtf.offset {
+            Some(Offset::Numeric(ref offset)) => {
+                time_zone.gmt_offset = Some(gmt_offset_for_ixdtf(offset)?)
+            }
+            Some(Offset::Z) if ixdtf.time_zone.is_none() => {
+                time_zone.gmt_offset = Some(GmtOffset::utc())
+            }
+            _ => {}
+        }
+        match ixdtf.time_zone.map(|annotation| annotation.time_zone) {
+            Some(TimeZoneRecord::Name(name)) => {
+                time_zone.time_zone_id = Some(
+                    core::str::from_utf8(name)
+                        .ok()
+                        .and_then(|name| mapper.get(name))
+                        .ok_or(ParseError::UnknownTimeZone)?,
+                );
+            }
+            Some(TimeZoneRecord::Offset(ref offset)) => {
+                let offset = gmt_offset_for_ixdtf(offset)?;
+                let gmt_offset = time_zone.gmt_offset.get_or_insert(offset);
+                if gmt_offset.offset_seconds() != offset.offset_seconds() {
+                    return Err(ParseError::InconsistentOffset);
+                }
+            }
+            _ => {}
+        }
+        if time_zone.gmt_offset.is_none() && time_zone.time_zone_id.is_none() {
+            return Err(ParseError::MissingFields);
+        }
+        Ok(time_zone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IanaBcp47RoundTripMapper;
+    use icu_calendar::ixdtf::{IxdtfCalendarDisplay, IxdtfPrecision};
+    use ixdtf::parser::{DateTimeParser, Offset, TimeZoneRecord};
+
+    #[test]
+    fn test_options() {
+        let mapper = IanaBcp47RoundTripMapper::new();
+        let datetime = DateTime::try_new_iso_datetime(2024, 3, 11, 10, 0, 0).unwrap();
+        let mut time_zone = CustomTimeZone::new_with_offset("-03:30".parse().unwrap());
+        let zoned = |time_zone: &CustomTimeZone, options: ZonedIxdtfOptions| {
+            IxdtfZonedDateTime::new(&datetime, time_zone, mapper.as_borrowed(), options)
+                .write_to_string()
+                .into_owned()
+        };
+
+        let mut options = ZonedIxdtfOptions::default();
+        assert_eq!(
+            zoned(&time_zone, options),
+            "2024-03-11T10:00:00-03:30[-03:30]"
+        );
+
+        time_zone.time_zone_id = mapper.as_borrowed().iana_to_bcp47("America/St_Johns");
+        options.datetime.precision = IxdtfPrecision::Minute;
+        options.datetime.calendar = IxdtfCalendarDisplay::Critical;
+        options.time_zone = IxdtfTimeZoneDisplay::Critical;
+        assert_eq!(
+            zoned(&time_zone, options),
+            "2024-03-11T10:00-03:30[!America/St_Johns][!u-ca=iso8601]"
+        );
+
+        options.offset = IxdtfOffsetDisplay::Never;
+        options.time_zone = IxdtfTimeZoneDisplay::Never;
+        options.datetime.calendar = IxdtfCalendarDisplay::Auto;
+        assert_eq!(zoned(&time_zone, options), "2024-03-11T10:00");
+
+        // Offsets with seconds are not valid time zone annotations
+        let time_zone = CustomTimeZone::new_with_offset(
+            GmtOffset::try_from_offset_seconds(-(3600 + 60 + 1)).unwrap(),
+        );
+        assert_eq!(
+            zoned(&time_zone, Default::default()),
+            "2024-03-11T10:00:00-01:01:01"
+        );
+        assert_eq!(
+            zoned(&CustomTimeZone::new_empty(), options),
+            "2024-03-11T10:00"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mapper = IanaBcp47RoundTripMapper::new();
+        let datetime = DateTime::try_new_iso_datetime(2024, 3, 11, 10, 0, 0).unwrap();
+        let mut time_zone = CustomTimeZone::utc();
+        time_zone.time_zone_id = mapper.as_borrowed().iana_to_bcp47("Europe/London");
+        let options = ZonedIxdtfOptions {
+            offset: IxdtfOffsetDisplay::Z,
+            ..Default::default()
+        };
+
+        // `Z` would be ignored in favor of the annotation
+        let string = IxdtfZonedDateTime::new(&datetime, &time_zone, mapper.as_borrowed(), options)
+            .write_to_string()
+            .into_owned();
+        assert_eq!(string, "2024-03-11T10:00:00+00:00[Europe/London]");
+        let parsed = DateTimeParser::new(string.as_bytes()).parse().unwrap();
+        assert!(matches!(parsed.offset, Some(Offset::Numeric(_))));
+        match parsed.time_zone.map(|annotation| annotation.time_zone) {
+            Some(TimeZoneRecord::Name(name)) => assert_eq!(name, b"Europe/London"),
+            other => panic!("unexpected time zone annotation: {other:?}"),
+        }
+        #[cfg(feature = "ixdtf")]
+        {
+            let parsed = CustomTimeZone::try_from_str(&string).unwrap();
+            assert_eq!(parsed.gmt_offset.map(GmtOffset::offset_seconds), Some(0));
+            assert_eq!(parsed.time_zone_id, time_zone.time_zone_id);
+        }
+
+        let options = ZonedIxdtfOptions {
+            offset: IxdtfOffsetDisplay::Z,
+            time_zone: IxdtfTimeZoneDisplay::Never,
+            ..Default::default()
+        };
+        let string = IxdtfZonedDateTime::new(&datetime, &time_zone, mapper.as_borrowed(), options)
+            .write_to_string()
+            .into_owned();
+        assert_eq!(string, "2024-03-11T10:00:00Z");
+        #[cfg(feature = "ixdtf")]
+        assert_eq!(
+            CustomTimeZone::try_from_str(&string)
+                .unwrap()
+                .gmt_offset
+                .map(GmtOffset::offset_seconds),
+            Some(0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ixdtf")]
+    fn test_parse() {
+        let parse = |string: &str| {
+            CustomTimeZone::try_from_str(string).map(|time_zone| {
+                (
+                    time_zone.gmt_offset.map(GmtOffset::offset_seconds),
+                    time_zone.time_zone_id,
+                )
+            })
+        };
+        let offset = Some;
+        let mapper = IanaBcp47RoundTripMapper::new();
+        let london = mapper.as_borrowed().iana_to_bcp47("Europe/London");
+        assert!(london.is_some());
+
+        assert_eq!(parse("2024-03-11T10:00-01:30"), Ok((offset(-5400), None)));
+        assert_eq!(parse("2024-03-11T10:00Z"), Ok((offset(0), None)));
+        assert_eq!(parse("2024-03-11T10:00[Europe/London]"), Ok((None, london)));
+        assert_eq!(
+            parse("2024-03-11T10:00Z[Europe/London]"),
+            Ok((None, london))
+        );
+        assert_eq!(
+            parse("2024-03-11T10:00+00:00[europe/london]"),
+            Ok((offset(0), london))
+        );
+        assert_eq!(parse("2024-03-11T10:00[+05:30]"), Ok((offset(19800), None)));
+        assert_eq!(
+            parse("2024-03-11T10:00Z[-05:30]"),
+            Ok((offset(-19800), None))
+        );
+        assert_eq!(parse("2024-03-11T10:00+01:00:01"), Ok((offset(3601), None)));
+        assert_eq!(
+            parse("2024-03-11T10:00+05:30[+05:30]"),
+            Ok((offset(19800), None))
+        );
+        // The offset is not checked against the IANA time zone
+        assert_eq!(
+            parse("2024-03-11T10:00+05:30[Europe/London]"),
+            Ok((offset(19800), london))
+        );
+
+        assert_eq!(parse("2024-03-11T10:00"), Err(ParseError::MissingFields));
+        assert_eq!(
+            parse("2024-03-11T10:00+01:00:01.5"),
+            Err(ParseError::Offset(TimeZoneError::InvalidOffset))
+        );
+        assert_eq!(
+            parse("2024-03-11T10:00[Mars/Olympus_Mons]"),
+            Err(ParseError::UnknownTimeZone)
+        );
+        assert_eq!(
+            parse("2024-03-11T10:00+05:30[+01:00]"),
+            Err(ParseError::InconsistentOffset)
+        );
+        assert_eq!(
+            parse("2024-03-11T10:00+25:00"),
+            Err(ParseError::Syntax(ixdtf::parser::ParseError::UtcOffsetHour))
+        );
+    }
+}
@@ -33,6 +33,10 @@
 //! ICU4X uses BCP-47 time zone IDs for all of its APIs. To get a BCP-47 time zone from an
 //! IANA time zone, use [`IanaToBcp47Mapper`].
 //!
+//! Windows uses its own time zone IDs, like `"Central Standard Time"`, which map to different
+//! BCP-47 time zones depending on the territory. To convert between Windows and BCP-47 time
+//! zones, use [`WindowsToBcp47Mapper`] or [`WindowsBcp47RoundTripMapper`].
+//!
 //! ## Metazone
 //!
 //! A metazone is a collection of multiple time zones that share the same localized formatting
@@ -51,6 +55,9 @@
 //!
 //! ICU4X uses the short form.
 //!
+//! To find the time zones used in a region, or the primary time zone of a metazone in a
+//! region, use [`RegionTimeZoneMapper`].
+//!
 //! Note: in ICU4X, "metazone" is one word and "time zone" is two words, except for this crate
 //! and module name, where "timezone" is used with no separators. See
 //! <https://github.com/unicode-org/icu4x/issues/2507>.
@@ -72,10 +79,10 @@
 //! In date/time processing, normally only a subset of information is available, and the other
 //! fields must be computed from it.
 //!
-//! The following calculations are currently supported or will be supported:
+//! The following calculations are currently supported:
 //!
 //! 1. Time Zone + Local DateTime → Meta Zone ([`MetazoneCalculator`])
-//! 2. Time Zone + Absolute Time → Offset + Zone Variant (not yet supported)
+//! 2. Time Zone + Absolute Time → Offset + Zone Variant ([`ZoneOffsetCalculator`])
 //!
 //! # Examples
 //!
@@ -127,17 +134,30 @@ extern crate alloc;
 
 mod error;
 mod iana_ids;
+pub mod ixdtf;
 mod metazone;
 pub mod provider;
+mod region_zones;
 mod time_zone;
 mod types;
+mod windows_ids;
+mod zone_offset;
 
 pub use error::TimeZoneError;
-pub use iana_ids::{IanaBcp47RoundTripMapper, IanaToBcp47Mapper};
+pub use iana_ids::{
+    IanaBcp47RoundTripMapper, IanaBcp47RoundTripMapperBorrowed, IanaToBcp47Mapper,
+    IanaToBcp47MapperBorrowed,
+};
 pub use metazone::MetazoneCalculator;
 pub use provider::{MetazoneId, TimeZoneBcp47Id};
+pub use region_zones::RegionTimeZoneMapper;
 pub use time_zone::CustomTimeZone;
 pub use types::{GmtOffset, ZoneVariant};
+pub use windows_ids::{
+    WindowsBcp47RoundTripMapper, WindowsBcp47RoundTripMapperBorrowed, WindowsToBcp47Mapper,
+    WindowsToBcp47MapperBorrowed,
+};
+pub use zone_offset::ZoneOffsetCalculator;
 
 #[doc(no_inline)]
 pub use TimeZoneError as Error;
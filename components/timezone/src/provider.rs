@@ -17,9 +17,9 @@
 
 use core::str::FromStr;
 use icu_provider::prelude::*;
-use tinystr::TinyAsciiStr;
+use tinystr::{TinyAsciiStr, UnvalidatedTinyAsciiStr};
 use zerovec::ule::{AsULE, ULE};
-use zerovec::{ZeroMap2d, ZeroSlice, ZeroVec};
+use zerovec::{ZeroMap, ZeroMap2d, ZeroSlice, ZeroVec};
 
 pub mod names;
 
@@ -41,16 +41,26 @@ const _: () = {
     }
     icu_timezone_data::make_provider!(Baked);
     icu_timezone_data::impl_time_zone_bcp47_to_iana_v1!(Baked);
+    icu_timezone_data::impl_time_zone_bcp47_to_windows_v1!(Baked);
     icu_timezone_data::impl_time_zone_iana_to_bcp47_v1!(Baked);
     icu_timezone_data::impl_time_zone_metazone_period_v1!(Baked);
+    icu_timezone_data::impl_time_zone_metazone_primary_zones_v1!(Baked);
+    icu_timezone_data::impl_time_zone_region_zones_v1!(Baked);
+    icu_timezone_data::impl_time_zone_windows_to_bcp47_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
 /// The latest minimum set of keys required by this component.
 pub const KEYS: &[DataKey] = &[
     MetazonePeriodV1Marker::KEY,
+    MetazonePrimaryZonesV1Marker::KEY,
+    RegionTimeZonesV1Marker::KEY,
     names::Bcp47ToIanaMapV1Marker::KEY,
+    names::Bcp47ToWindowsMapV1Marker::KEY,
     names::IanaToBcp47MapV1Marker::KEY,
+    names::WindowsToBcp47MapV1Marker::KEY,
+    // `ZoneOffsetPeriodV1Marker` is not included: it is generated from the IANA time zone
+    // database, which is not a default datagen source, and has no compiled data.
 ];
 
 /// TimeZone ID in BCP47 format
@@ -185,3 +195,184 @@ pub struct MetazonePeriodV1<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub ZeroMap2d<'data, TimeZoneBcp47Id, i32, Option<MetazoneId>>,
 );
+
+/// A UTC offset and whether it is daylight saving time.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneOffsetULE)]
+#[derive(
+    Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug, yoke::Yokeable, zerofrom::ZeroFrom,
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneOffset {
+    /// The offset from UTC in seconds, positive east of Greenwich.
+    pub offset_seconds: i32,
+    /// Whether the offset is daylight saving time.
+    pub is_dst: bool,
+}
+
+/// The date and local time of a yearly daylight saving time transition, as in a POSIX TZ string.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneTransitionRuleULE)]
+#[derive(
+    Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug, yoke::Yokeable, zerofrom::ZeroFrom,
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneTransitionRule {
+    /// The month (1-12) of an `Mm.w.d` rule, or 0 for a day-of-year rule.
+    pub month: u8,
+    /// The week of the month (1-5, where 5 is the last week) of an `Mm.w.d` rule.
+    ///
+    /// For day-of-year rules, this is 1 if `day` counts February 29th (`n`), and 0 if it
+    /// does not (`Jn`).
+    pub week: u8,
+    /// The day of the week (0-6, starting on Sunday) of an `Mm.w.d` rule, or the day of the year
+    /// of a day-of-year rule.
+    pub day: u16,
+    /// The local time of the transition in seconds after midnight, which may be negative or
+    /// more than a day.
+    pub time_seconds: i32,
+}
+
+/// The yearly daylight saving time rule of a time zone.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneRuleULE)]
+#[derive(
+    Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug, yoke::Yokeable, zerofrom::ZeroFrom,
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneRule {
+    /// The standard offset from UTC in seconds, positive east of Greenwich.
+    pub std_offset_seconds: i32,
+    /// The daylight saving offset from UTC in seconds, positive east of Greenwich.
+    pub dst_offset_seconds: i32,
+    /// The start of daylight saving time, in local standard time.
+    pub start: ZoneTransitionRule,
+    /// The end of daylight saving time, in local daylight saving time.
+    pub end: ZoneTransitionRule,
+}
+
+/// An ICU4X mapping to the UTC offsets of time zones over time.
+/// See the TZif files of the IANA time zone database for more context.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    ZoneOffsetPeriodV1Marker,
+    "time_zone/offset_period@1",
+    singleton
+))]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct ZoneOffsetPeriodV1<'data> {
+    /// The offsets of each time zone. The second level key is the UTC time in seconds since the
+    /// unix epoch from which the offset is used. The first offset of each time zone starts at
+    /// [`i64::MIN`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transitions: ZeroMap2d<'data, TimeZoneBcp47Id, i64, ZoneOffset>,
+    /// The daylight saving time rules that apply after the last transition, for time zones that
+    /// still observe daylight saving time.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: ZeroMap<'data, TimeZoneBcp47Id, ZoneRule>,
+}
+
+// We use raw TinyAsciiStrs for map keys, as we then don't have to
+// validate them as subtags on deserialization.
+type UnvalidatedRegion = UnvalidatedTinyAsciiStr<3>;
+
+/// An ICU4X mapping from regions to the time zones used in them.
+///
+/// The regions of a time zone are the territories it is listed under in CLDR-JSON
+/// windowsZones.json, together with the territories for which it is a metazone's
+/// primary zone in metaZones.json. Time zones that appear in neither have the region of their
+/// BCP-47 ID if it is a UN/LOCODE, and are not included otherwise.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    RegionTimeZonesV1Marker,
+    "time_zone/region_zones@1",
+    singleton
+))]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RegionTimeZonesV1<'data>(
+    /// A sorted list of canonical time zones for each region.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ZeroMap<'data, UnvalidatedRegion, ZeroSlice<TimeZoneBcp47Id>>,
+);
+
+/// An ICU4X mapping from metazones to their primary time zone in a region.
+/// See CLDR-JSON metaZones.json for more context.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    MetazonePrimaryZonesV1Marker,
+    "time_zone/metazone_primary_zones@1",
+    singleton
+))]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct MetazonePrimaryZonesV1<'data>(
+    /// The primary time zone of a metazone in a region. The region `001` contains the
+    /// "golden zone", which is the primary time zone for regions without their own entry.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ZeroMap2d<'data, MetazoneId, UnvalidatedRegion, TimeZoneBcp47Id>,
+);
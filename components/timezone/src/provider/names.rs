@@ -15,7 +15,7 @@
 use crate::TimeZoneBcp47Id;
 use core::str;
 use icu_provider::prelude::*;
-use zerotrie::{ZeroAsciiIgnoreCaseTrie, ZeroTrie};
+use zerotrie::{ZeroAsciiIgnoreCaseTrie, ZeroTrie, ZeroTrieSimpleAscii};
 use zerovec::{VarZeroVec, ZeroVec};
 
 /// A mapping from lowercase IANA time zone identifiers to BCP-47 time zone identifiers.
@@ -134,3 +134,74 @@ pub struct Bcp47ToIanaMapV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub canonical_iana_ids: VarZeroVec<'data, str>,
 }
+
+/// A mapping from Windows time zone identifiers, qualified by territory, to BCP-47 time zone
+/// identifiers.
+///
+/// This data is derived from the CLDR `windowsZones.json` supplemental file.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq)]
+#[icu_provider::data_struct(marker(
+    WindowsToBcp47MapV1Marker,
+    "time_zone/windows_to_bcp47@1",
+    singleton
+))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider::names),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct WindowsToBcp47MapV1<'data> {
+    /// A map from Windows time zone identifiers and territories to indexes of BCP-47 time zone
+    /// identifiers.
+    ///
+    /// The keys have the form `"{windows_id}/{territory}"`, for example
+    /// `"Pacific Standard Time/001"` or `"Pacific Standard Time/CA"`. The territory `"001"`
+    /// identifies the default ("golden") zone for the Windows time zone.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub map: ZeroTrieSimpleAscii<ZeroVec<'data, u8>>,
+    /// A sorted list of BCP-47 time zone identifiers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bcp47_ids: ZeroVec<'data, TimeZoneBcp47Id>,
+}
+
+/// A mapping from BCP-47 time zone identifiers to Windows time zone identifiers.
+///
+/// Every BCP-47 time zone identifier occurs in at most one Windows time zone.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq)]
+#[icu_provider::data_struct(marker(
+    Bcp47ToWindowsMapV1Marker,
+    "time_zone/bcp47_to_windows@1",
+    singleton
+))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider::names),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct Bcp47ToWindowsMapV1<'data> {
+    /// A sorted list of BCP-47 time zone identifiers that have a Windows time zone.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bcp47_ids: ZeroVec<'data, TimeZoneBcp47Id>,
+    /// For each entry in [`Self::bcp47_ids`], the index of its identifier in
+    /// [`Self::windows_ids`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub windows_indices: ZeroVec<'data, u16>,
+    /// A sorted list of the Windows time zone identifiers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub windows_ids: VarZeroVec<'data, str>,
+}
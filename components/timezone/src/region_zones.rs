@@ -0,0 +1,177 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::provider::{
+    MetazoneId, MetazonePrimaryZonesV1Marker, RegionTimeZonesV1Marker, TimeZoneBcp47Id,
+};
+use icu_locid::subtags::{region, Region};
+use icu_provider::prelude::*;
+
+/// [`RegionTimeZoneMapper`] uses data from the [data provider] to look up the time zones
+/// used in a region, as well as the primary time zone of a metazone in a region.
+///
+/// This is useful for building time zone pickers.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::subtags::region;
+/// use icu::timezone::RegionTimeZoneMapper;
+///
+/// let mapper = RegionTimeZoneMapper::new();
+///
+/// // The time zones used in Japan
+/// assert_eq!(
+///     mapper.time_zones_for_region(region!("JP")).collect::<Vec<_>>(),
+///     vec!["jptyo".parse().unwrap()]
+/// );
+///
+/// // The primary time zone of US Pacific Time in Canada
+/// assert_eq!(
+///     mapper.primary_zone_for_metazone(
+///         "ampa".parse().unwrap(),
+///         Some(region!("CA"))
+///     ),
+///     Some("cavan".parse().unwrap())
+/// );
+/// ```
+///
+/// [data provider]: icu_provider
+#[derive(Debug)]
+pub struct RegionTimeZoneMapper {
+    region_zones: DataPayload<RegionTimeZonesV1Marker>,
+    primary_zones: DataPayload<MetazonePrimaryZonesV1Marker>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for RegionTimeZoneMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegionTimeZoneMapper {
+    /// Constructs a `RegionTimeZoneMapper` using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        RegionTimeZoneMapper {
+            region_zones: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_REGION_ZONES_V1,
+            ),
+            primary_zones: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_METAZONE_PRIMARY_ZONES_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: TimeZoneError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<RegionTimeZonesV1Marker>
+            + DataProvider<MetazonePrimaryZonesV1Marker>
+            + ?Sized,
+    {
+        let region_zones = provider.load(Default::default())?.take_payload()?;
+        let primary_zones = provider.load(Default::default())?.take_payload()?;
+        Ok(Self {
+            region_zones,
+            primary_zones,
+        })
+    }
+
+    /// Returns the canonical time zones used in the given region, sorted by BCP-47 ID.
+    ///
+    /// Returns an empty iterator if the region is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::subtags::region;
+    /// use icu::timezone::RegionTimeZoneMapper;
+    ///
+    /// let mapper = RegionTimeZoneMapper::new();
+    ///
+    /// let zones = mapper
+    ///     .time_zones_for_region(region!("PT"))
+    ///     .map(|id| id.0)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(zones, ["ptfnc", "ptlis", "ptpdl"]);
+    ///
+    /// assert_eq!(mapper.time_zones_for_region(region!("AQ")).count(), 10);
+    /// assert_eq!(mapper.time_zones_for_region(region!("ZZ")).count(), 0);
+    /// ```
+    pub fn time_zones_for_region(
+        &self,
+        region: Region,
+    ) -> impl Iterator<Item = TimeZoneBcp47Id> + '_ {
+        self.region_zones
+            .get()
+            .0
+            .get(&region.into_tinystr().to_unvalidated())
+            .into_iter()
+            .flat_map(|zones| zones.iter())
+    }
+
+    /// Returns the primary time zone of a metazone in the given region.
+    ///
+    /// If no region is given, or if the metazone has no primary time zone specific to the
+    /// region, the "golden zone" of the metazone (its primary time zone in the region `001`)
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::subtags::region;
+    /// use icu::timezone::RegionTimeZoneMapper;
+    ///
+    /// let mapper = RegionTimeZoneMapper::new();
+    /// let america_central = "amce".parse().unwrap();
+    ///
+    /// // The golden zone of US Central Time is "uschi" (America/Chicago)
+    /// assert_eq!(
+    ///     mapper.primary_zone_for_metazone(america_central, None),
+    ///     Some("uschi".parse().unwrap())
+    /// );
+    ///
+    /// // In Mexico, it is "mxmex" (America/Mexico_City)
+    /// assert_eq!(
+    ///     mapper.primary_zone_for_metazone(america_central, Some(region!("MX"))),
+    ///     Some("mxmex".parse().unwrap())
+    /// );
+    ///
+    /// // Regions without a specific primary zone use the golden zone
+    /// assert_eq!(
+    ///     mapper.primary_zone_for_metazone(america_central, Some(region!("NI"))),
+    ///     Some("uschi".parse().unwrap())
+    /// );
+    /// ```
+    pub fn primary_zone_for_metazone(
+        &self,
+        metazone_id: MetazoneId,
+        region: Option<Region>,
+    ) -> Option<TimeZoneBcp47Id> {
+        let data = &self.primary_zones.get().0;
+        let cursor = data.get0(&metazone_id)?;
+        region
+            .and_then(|region| cursor.get1_copied(&region.into_tinystr().to_unvalidated()))
+            .or_else(|| cursor.get1_copied(&region!("001").into_tinystr().to_unvalidated()))
+    }
+}
@@ -0,0 +1,253 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt::Write;
+
+use crate::error::TimeZoneError;
+use crate::provider::names::*;
+use crate::TimeZoneBcp47Id;
+use icu_locid::subtags::{region, Region};
+use icu_provider::prelude::*;
+
+/// A mapper from Windows time zone identifiers to BCP-47 time zone identifiers.
+///
+/// Windows time zones are mapped per territory, following the CLDR `windowsZones.json`
+/// data. If no territory is given, or if there is no mapping specific to the territory,
+/// the default zone for the territory `001` ("World") is returned.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::subtags::region;
+/// use icu::timezone::WindowsToBcp47Mapper;
+///
+/// let mapper = WindowsToBcp47Mapper::new();
+/// let mapper_borrowed = mapper.as_borrowed();
+///
+/// // The default zone for "Pacific Standard Time" is "uslax" (America/Los_Angeles)
+/// assert_eq!(
+///     mapper_borrowed.get("Pacific Standard Time", None),
+///     Some("uslax".parse().unwrap())
+/// );
+///
+/// // In Canada, "Pacific Standard Time" is "cavan" (America/Vancouver)
+/// assert_eq!(
+///     mapper_borrowed.get("Pacific Standard Time", Some(region!("CA"))),
+///     Some("cavan".parse().unwrap())
+/// );
+///
+/// // Territories without a specific mapping use the default zone
+/// assert_eq!(
+///     mapper_borrowed.get("Pacific Standard Time", Some(region!("FR"))),
+///     Some("uslax".parse().unwrap())
+/// );
+/// ```
+#[derive(Debug)]
+pub struct WindowsToBcp47Mapper {
+    data: DataPayload<WindowsToBcp47MapV1Marker>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for WindowsToBcp47Mapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowsToBcp47Mapper {
+    /// Creates a new [`WindowsToBcp47Mapper`] using compiled data.
+    ///
+    /// See [`WindowsToBcp47Mapper`] for an example.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        WindowsToBcp47Mapper {
+            data: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_WINDOWS_TO_BCP47_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: TimeZoneError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<WindowsToBcp47MapV1Marker> + ?Sized,
+    {
+        let data = provider.load(Default::default())?.take_payload()?;
+        Ok(Self { data })
+    }
+
+    /// Returns a borrowed version of the mapper that can be queried.
+    ///
+    /// This avoids a small potential cost of reading the data pointer.
+    pub fn as_borrowed(&self) -> WindowsToBcp47MapperBorrowed {
+        WindowsToBcp47MapperBorrowed {
+            data: self.data.get(),
+        }
+    }
+}
+
+/// A borrowed wrapper around Windows-to-BCP47 time zone data, returned by
+/// [`WindowsToBcp47Mapper::as_borrowed()`]. More efficient to query.
+#[derive(Debug)]
+pub struct WindowsToBcp47MapperBorrowed<'a> {
+    data: &'a WindowsToBcp47MapV1<'a>,
+}
+
+impl<'a> WindowsToBcp47MapperBorrowed<'a> {
+    /// Looks up a BCP-47 time zone identifier based on an exact match for the given Windows
+    /// time zone identifier and an optional territory.
+    ///
+    /// See examples in [`WindowsToBcp47Mapper`].
+    pub fn get(&self, windows_id: &str, region: Option<Region>) -> Option<TimeZoneBcp47Id> {
+        let idx = region
+            .and_then(|region| self.lookup(windows_id, region))
+            .or_else(|| self.lookup(windows_id, region!("001")))?;
+        self.data.bcp47_ids.get(idx)
+    }
+
+    fn lookup(&self, windows_id: &str, region: Region) -> Option<usize> {
+        let mut cursor = self.data.map.cursor();
+        // Writing fails only if the input is not ASCII, in which case there is no match.
+        write!(cursor, "{windows_id}/{}", region.as_str()).ok()?;
+        cursor.take_value()
+    }
+}
+
+/// A mapper that supports mapping Windows time zone identifiers to BCP-47 identifiers and
+/// also the other way around.
+///
+/// # Examples
+///
+/// ```
+/// use icu::timezone::WindowsBcp47RoundTripMapper;
+///
+/// let mapper = WindowsBcp47RoundTripMapper::new();
+/// let mapper_borrowed = mapper.as_borrowed();
+///
+/// // Look up the time zone ID for "India Standard Time"
+/// let bcp47_id = mapper_borrowed.windows_to_bcp47("India Standard Time", None);
+/// assert_eq!(bcp47_id, Some("inccu".parse().unwrap()));
+///
+/// // Get the Windows time zone back
+/// let windows_id = mapper_borrowed.bcp47_to_windows(bcp47_id.unwrap());
+/// assert_eq!(windows_id, Some("India Standard Time"));
+///
+/// // Zones that are not the default for a Windows time zone map back as well
+/// let windows_id = mapper_borrowed.bcp47_to_windows("cavan".parse().unwrap());
+/// assert_eq!(windows_id, Some("Pacific Standard Time"));
+/// ```
+#[derive(Debug)]
+pub struct WindowsBcp47RoundTripMapper {
+    data1: DataPayload<WindowsToBcp47MapV1Marker>,
+    data2: DataPayload<Bcp47ToWindowsMapV1Marker>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for WindowsBcp47RoundTripMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowsBcp47RoundTripMapper {
+    /// Creates a new [`WindowsBcp47RoundTripMapper`] using compiled data.
+    ///
+    /// See [`WindowsBcp47RoundTripMapper`] for an example.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        WindowsBcp47RoundTripMapper {
+            data1: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_WINDOWS_TO_BCP47_V1,
+            ),
+            data2: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_TIME_ZONE_BCP47_TO_WINDOWS_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: TimeZoneError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<WindowsToBcp47MapV1Marker>
+            + DataProvider<Bcp47ToWindowsMapV1Marker>
+            + ?Sized,
+    {
+        let data1 = provider.load(Default::default())?.take_payload()?;
+        let data2 = provider.load(Default::default())?.take_payload()?;
+        Ok(Self { data1, data2 })
+    }
+
+    /// Returns a borrowed version of the mapper that can be queried.
+    ///
+    /// This avoids a small potential cost of reading the data pointer.
+    pub fn as_borrowed(&self) -> WindowsBcp47RoundTripMapperBorrowed {
+        WindowsBcp47RoundTripMapperBorrowed {
+            data1: self.data1.get(),
+            data2: self.data2.get(),
+        }
+    }
+}
+
+/// A borrowed wrapper around Windows-BCP47 time zone data, returned by
+/// [`WindowsBcp47RoundTripMapper::as_borrowed()`]. More efficient to query.
+#[derive(Debug)]
+pub struct WindowsBcp47RoundTripMapperBorrowed<'a> {
+    data1: &'a WindowsToBcp47MapV1<'a>,
+    data2: &'a Bcp47ToWindowsMapV1<'a>,
+}
+
+impl<'a> WindowsBcp47RoundTripMapperBorrowed<'a> {
+    /// Looks up a BCP-47 time zone identifier based on an exact match for the given Windows
+    /// time zone identifier and an optional territory.
+    ///
+    /// See examples in [`WindowsToBcp47Mapper`] or [`WindowsBcp47RoundTripMapper`].
+    pub fn windows_to_bcp47(
+        &self,
+        windows_id: &str,
+        region: Option<Region>,
+    ) -> Option<TimeZoneBcp47Id> {
+        WindowsToBcp47MapperBorrowed { data: self.data1 }.get(windows_id, region)
+    }
+
+    /// Looks up the Windows time zone identifier of a BCP-47 time zone identifier.
+    ///
+    /// See examples in [`WindowsBcp47RoundTripMapper`].
+    pub fn bcp47_to_windows(&self, bcp47_id: TimeZoneBcp47Id) -> Option<&str> {
+        let index = self.data2.bcp47_ids.binary_search(&bcp47_id).ok()?;
+        let windows_index = self.data2.windows_indices.get(index)?;
+        self.data2.windows_ids.get(usize::from(windows_index))
+    }
+}
@@ -0,0 +1,319 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::provider::{
+    TimeZoneBcp47Id, ZoneOffset, ZoneOffsetPeriodV1Marker, ZoneRule, ZoneTransitionRule,
+};
+use icu_calendar::types::Time;
+use icu_calendar::{Date, DateDuration, DateTime, Iso};
+use icu_provider::prelude::*;
+use zerovec::ule::AsULE;
+
+/// [`ZoneOffsetCalculator`] uses data from the [data provider] to calculate the UTC offset of
+/// a time zone at a point in time.
+///
+/// The data comes from the TZif files of the IANA time zone database rather than from CLDR,
+/// so there is no compiled data for it. It can be generated with the `tzdb` Cargo feature of
+/// `icu_datagen`.
+///
+/// [data provider]: icu_provider
+#[derive(Debug)]
+pub struct ZoneOffsetCalculator {
+    offset_period: DataPayload<ZoneOffsetPeriodV1Marker>,
+}
+
+impl ZoneOffsetCalculator {
+    /// Constructs a `ZoneOffsetCalculator` from an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+    ) -> Result<Self, TimeZoneError> {
+        Self::try_new_unstable(&provider.as_downcasting())
+    }
+
+    /// Constructs a `ZoneOffsetCalculator` from a [`BufferProvider`](icu_provider::BufferProvider).
+    ///
+    /// ✨ *Enabled with the `serde` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+    ) -> Result<Self, TimeZoneError> {
+        Self::try_new_unstable(&provider.as_deserializing())
+    }
+
+    /// Constructs a `ZoneOffsetCalculator` from a [`DataProvider`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<ZoneOffsetPeriodV1Marker> + ?Sized),
+    ) -> Result<Self, TimeZoneError> {
+        let offset_period = provider.load(Default::default())?.take_payload()?;
+        Ok(Self { offset_period })
+    }
+
+    /// Calculate the UTC offset of a time zone at a UTC datetime, and whether it is daylight
+    /// saving time.
+    ///
+    /// After the last transition in the data, the offset is computed from the daylight saving
+    /// time rule of the time zone, if it has one.
+    ///
+    /// Returns `None` if the time zone is not in the data.
+    pub fn compute_offset_from_time_zone(
+        &self,
+        time_zone_id: TimeZoneBcp47Id,
+        utc_datetime: &DateTime<Iso>,
+    ) -> Option<ZoneOffset> {
+        let seconds = i64::from(utc_datetime.minutes_since_local_unix_epoch()) * 60
+            + i64::from(utc_datetime.time.second.number());
+
+        let mut offset = None;
+        let mut after_last_transition = true;
+        for (start, period_offset) in self
+            .offset_period
+            .get()
+            .transitions
+            .get0(&time_zone_id)?
+            .iter1_copied()
+        {
+            if i64::from_unaligned(*start) > seconds {
+                after_last_transition = false;
+                break;
+            }
+            offset = Some(period_offset);
+        }
+
+        if after_last_transition {
+            if let Some(rule) = self.offset_period.get().rules.get_copied(&time_zone_id) {
+                return offset_from_rule(&rule, seconds).or(offset);
+            }
+        }
+        offset
+    }
+}
+
+/// Returns the offset at `seconds` since the unix epoch according to a daylight saving time rule.
+fn offset_from_rule(rule: &ZoneRule, seconds: i64) -> Option<ZoneOffset> {
+    // The rule applies to years of local standard time.
+    let local_minutes = (seconds + i64::from(rule.std_offset_seconds)).div_euclid(60);
+    let year = DateTime::from_minutes_since_local_unix_epoch(i32::try_from(local_minutes).ok()?)
+        .date
+        .year()
+        .number;
+
+    // The start is given in local standard time, and the end in local daylight saving time.
+    let start = local_seconds_for_rule(&rule.start, year)? - i64::from(rule.std_offset_seconds);
+    let end = local_seconds_for_rule(&rule.end, year)? - i64::from(rule.dst_offset_seconds);
+    let is_dst = if start <= end {
+        start <= seconds && seconds < end
+    } else {
+        // Southern hemisphere: daylight saving time spans the new year.
+        seconds < end || start <= seconds
+    };
+
+    Some(if is_dst {
+        ZoneOffset {
+            offset_seconds: rule.dst_offset_seconds,
+            is_dst: true,
+        }
+    } else {
+        ZoneOffset {
+            offset_seconds: rule.std_offset_seconds,
+            is_dst: false,
+        }
+    })
+}
+
+/// Returns the local time of a transition in `year`, in seconds since the local unix epoch.
+fn local_seconds_for_rule(rule: &ZoneTransitionRule, year: i32) -> Option<i64> {
+    let date = if rule.month == 0 {
+        let mut date = Date::try_new_iso_date(year, 1, 1).ok()?;
+        let days = if rule.week == 0 {
+            // `Jn`: 1-based, February 29th is never counted
+            let days = i32::from(rule.day) - 1;
+            if date.is_in_leap_year() && days >= 59 {
+                days + 1
+            } else {
+                days
+            }
+        } else {
+            // `n`: 0-based, February 29th is counted
+            i32::from(rule.day)
+        };
+        date.add(DateDuration::new(0, 0, 0, days));
+        date
+    } else {
+        let first = Date::try_new_iso_date(year, rule.month, 1).ok()?;
+        // `IsoWeekday` starts at Monday = 1, POSIX at Sunday = 0
+        let first_weekday = first.day_of_week() as u16 % 7;
+        let mut day =
+            1 + (7 + rule.day - first_weekday) % 7 + 7 * u16::from(rule.week).checked_sub(1)?;
+        // Week 5 is the last week of the month
+        while day > u16::from(first.days_in_month()) {
+            day -= 7;
+        }
+        Date::try_new_iso_date(year, rule.month, u8::try_from(day).ok()?).ok()?
+    };
+    let minutes = DateTime::new(date, Time::midnight()).minutes_since_local_unix_epoch();
+    Some(i64::from(minutes) * 60 + i64::from(rule.time_seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    fn calculator() -> ZoneOffsetCalculator {
+        let los_angeles = TimeZoneBcp47Id(tinystr!(8, "uslax"));
+        let santiago = TimeZoneBcp47Id(tinystr!(8, "clscl"));
+        let pst = ZoneOffset {
+            offset_seconds: -28800,
+            is_dst: false,
+        };
+        let pdt = ZoneOffset {
+            offset_seconds: -25200,
+            is_dst: true,
+        };
+        let clt = ZoneOffset {
+            offset_seconds: -14400,
+            is_dst: false,
+        };
+        ZoneOffsetCalculator {
+            offset_period: DataPayload::from_owned(crate::provider::ZoneOffsetPeriodV1 {
+                transitions: [
+                    (&los_angeles, &i64::MIN, &pst),
+                    // 2007-03-11T10:00:00Z
+                    (&los_angeles, &1173607200, &pdt),
+                    // 2007-11-04T09:00:00Z
+                    (&los_angeles, &1194166800, &pst),
+                    (&santiago, &i64::MIN, &clt),
+                ]
+                .into_iter()
+                .collect(),
+                rules: [
+                    (
+                        los_angeles,
+                        ZoneRule {
+                            std_offset_seconds: -28800,
+                            dst_offset_seconds: -25200,
+                            // M3.2.0
+                            start: ZoneTransitionRule {
+                                month: 3,
+                                week: 2,
+                                day: 0,
+                                time_seconds: 7200,
+                            },
+                            // M11.1.0
+                            end: ZoneTransitionRule {
+                                month: 11,
+                                week: 1,
+                                day: 0,
+                                time_seconds: 7200,
+                            },
+                        },
+                    ),
+                    (
+                        santiago,
+                        ZoneRule {
+                            std_offset_seconds: -14400,
+                            dst_offset_seconds: -10800,
+                            // M9.1.6/24
+                            start: ZoneTransitionRule {
+                                month: 9,
+                                week: 1,
+                                day: 6,
+                                time_seconds: 86400,
+                            },
+                            // M4.1.6/24
+                            end: ZoneTransitionRule {
+                                month: 4,
+                                week: 1,
+                                day: 6,
+                                time_seconds: 86400,
+                            },
+                        },
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        let calculator = calculator();
+        let offset = |id: &str, year, month, day, hour, minute| {
+            calculator
+                .compute_offset_from_time_zone(
+                    TimeZoneBcp47Id(id.parse().unwrap()),
+                    &DateTime::try_new_iso_datetime(year, month, day, hour, minute, 0).unwrap(),
+                )
+                .map(|offset| (offset.offset_seconds, offset.is_dst))
+        };
+
+        // From the transitions
+        assert_eq!(offset("uslax", 2000, 7, 1, 0, 0), Some((-28800, false)));
+        assert_eq!(offset("uslax", 2007, 7, 1, 0, 0), Some((-25200, true)));
+
+        // From the rule: 2024-03-10T02:00 PST to 2024-11-03T02:00 PDT
+        assert_eq!(offset("uslax", 2024, 3, 10, 9, 59), Some((-28800, false)));
+        assert_eq!(offset("uslax", 2024, 3, 10, 10, 0), Some((-25200, true)));
+        assert_eq!(offset("uslax", 2024, 11, 3, 8, 59), Some((-25200, true)));
+        assert_eq!(offset("uslax", 2024, 11, 3, 9, 0), Some((-28800, false)));
+        assert_eq!(offset("uslax", 2024, 12, 31, 23, 0), Some((-28800, false)));
+
+        // Daylight saving time across the new year: 2024-09-08T00:00 CLT to
+        // 2025-04-06T00:00 CLST
+        assert_eq!(offset("clscl", 2024, 7, 1, 0, 0), Some((-14400, false)));
+        assert_eq!(offset("clscl", 2024, 9, 8, 4, 0), Some((-10800, true)));
+        assert_eq!(offset("clscl", 2025, 1, 1, 0, 0), Some((-10800, true)));
+        assert_eq!(offset("clscl", 2025, 4, 6, 2, 59), Some((-10800, true)));
+        assert_eq!(offset("clscl", 2025, 4, 6, 3, 0), Some((-14400, false)));
+
+        assert_eq!(offset("jptyo", 2024, 7, 1, 0, 0), None);
+    }
+
+    #[test]
+    fn test_day_of_year_rules() {
+        let rule = |week, day| ZoneTransitionRule {
+            month: 0,
+            week,
+            day,
+            time_seconds: 0,
+        };
+        let date = |year, month, day| {
+            i64::from(
+                DateTime::try_new_iso_datetime(year, month, day, 0, 0, 0)
+                    .unwrap()
+                    .minutes_since_local_unix_epoch(),
+            ) * 60
+        };
+        // J60 is March 1st, even in leap years
+        assert_eq!(
+            local_seconds_for_rule(&rule(0, 60), 2023),
+            Some(date(2023, 3, 1))
+        );
+        assert_eq!(
+            local_seconds_for_rule(&rule(0, 60), 2024),
+            Some(date(2024, 3, 1))
+        );
+        // 59 is March 1st, or February 29th in leap years
+        assert_eq!(
+            local_seconds_for_rule(&rule(1, 59), 2023),
+            Some(date(2023, 3, 1))
+        );
+        assert_eq!(
+            local_seconds_for_rule(&rule(1, 59), 2024),
+            Some(date(2024, 2, 29))
+        );
+    }
+}
@@ -49,6 +49,8 @@ class ICU4XFixedDecimalFormatter {
    * 
    * The contents of the data struct will be consumed: if you wish to use the struct again it will have to be reconstructed.
    * Passing a consumed struct to this method will return an error.
+   * 
+   * The data struct has no symbols for infinity and NaN, so "∞" and "NaN" are used.
    */
   static diplomat::result<ICU4XFixedDecimalFormatter, ICU4XError> create_with_decimal_symbols_v1(const ICU4XDataStruct& data_struct, ICU4XFixedDecimalGroupingStrategy grouping_strategy);
 
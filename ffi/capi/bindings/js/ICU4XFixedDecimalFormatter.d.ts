@@ -28,6 +28,8 @@ export class ICU4XFixedDecimalFormatter {
    * Creates a new {@link ICU4XFixedDecimalFormatter `ICU4XFixedDecimalFormatter`} from preconstructed locale data in the form of an {@link ICU4XDataStruct `ICU4XDataStruct`} constructed from `ICU4XDataStruct::create_decimal_symbols()`.
 
    * The contents of the data struct will be consumed: if you wish to use the struct again it will have to be reconstructed. Passing a consumed struct to this method will return an error.
+
+   * The data struct has no symbols for infinity and NaN, so "∞" and "NaN" are used.
    * @throws {@link FFIError}<{@link ICU4XError}>
    */
   static create_with_decimal_symbols_v1(data_struct: ICU4XDataStruct, grouping_strategy: ICU4XFixedDecimalGroupingStrategy): ICU4XFixedDecimalFormatter | never;
@@ -7,7 +7,7 @@ pub mod ffi {
     use alloc::boxed::Box;
     use icu_decimal::{
         options::{FixedDecimalFormatterOptions, GroupingStrategy},
-        provider::DecimalSymbolsV1Marker,
+        provider::{DecimalSymbolsV1Marker, DecimalSymbolsV2, DecimalSymbolsV2Marker},
         FixedDecimalFormatter,
     };
     use icu_provider_adapters::any_payload::AnyPayloadProvider;
@@ -66,6 +66,8 @@ pub mod ffi {
         ///
         /// The contents of the data struct will be consumed: if you wish to use the struct again it will have to be reconstructed.
         /// Passing a consumed struct to this method will return an error.
+        ///
+        /// The data struct has no symbols for infinity and NaN, so "∞" and "NaN" are used.
         #[diplomat::attr(dart, disable)]
         pub fn create_with_decimal_symbols_v1(
             data_struct: &ICU4XDataStruct,
@@ -79,12 +81,24 @@ pub mod ffi {
             };
             let mut options = FixedDecimalFormatterOptions::default();
             options.grouping_strategy = grouping_strategy;
+            let symbols = data_struct
+                .0
+                // Note: This clone is free, since cloning AnyPayload is free.
+                .clone()
+                .downcast::<DecimalSymbolsV1Marker>()?
+                .map_project(|symbols, _| DecimalSymbolsV2 {
+                    minus_sign_affixes: symbols.minus_sign_affixes,
+                    plus_sign_affixes: symbols.plus_sign_affixes,
+                    decimal_separator: symbols.decimal_separator,
+                    grouping_separator: symbols.grouping_separator,
+                    grouping_sizes: symbols.grouping_sizes,
+                    digits: symbols.digits,
+                    infinity: "∞".into(),
+                    nan: "NaN".into(),
+                });
             Ok(Box::new(ICU4XFixedDecimalFormatter(
                 FixedDecimalFormatter::try_new_with_any_provider(
-                    &AnyPayloadProvider::from_any_payload::<DecimalSymbolsV1Marker>(
-                        // Note: This clone is free, since cloning AnyPayload is free.
-                        data_struct.0.clone(),
-                    ),
+                    &AnyPayloadProvider::from_payload::<DecimalSymbolsV2Marker>(symbols),
                     &Default::default(),
                     options,
                 )?,
@@ -36,11 +36,31 @@ mod compactdecimal_short_v1;
 #[doc(inline)]
 pub use __impl_compactdecimal_short_v1 as impl_compactdecimal_short_v1;
 #[macro_use]
+#[path = "macros/currency_accounting_v1.rs.data"]
+mod currency_accounting_v1;
+#[doc(inline)]
+pub use __impl_currency_accounting_v1 as impl_currency_accounting_v1;
+#[macro_use]
+#[path = "macros/currency_compact_v1.rs.data"]
+mod currency_compact_v1;
+#[doc(inline)]
+pub use __impl_currency_compact_v1 as impl_currency_compact_v1;
+#[macro_use]
 #[path = "macros/currency_essentials_v1.rs.data"]
 mod currency_essentials_v1;
 #[doc(inline)]
 pub use __impl_currency_essentials_v1 as impl_currency_essentials_v1;
 #[macro_use]
+#[path = "macros/currency_fractions_v1.rs.data"]
+mod currency_fractions_v1;
+#[doc(inline)]
+pub use __impl_currency_fractions_v1 as impl_currency_fractions_v1;
+#[macro_use]
+#[path = "macros/currency_long_names_v1.rs.data"]
+mod currency_long_names_v1;
+#[doc(inline)]
+pub use __impl_currency_long_names_v1 as impl_currency_long_names_v1;
+#[macro_use]
 #[path = "macros/displaynames_languages_v1.rs.data"]
 mod displaynames_languages_v1;
 #[doc(inline)]
@@ -66,11 +86,36 @@ mod displaynames_variants_v1;
 #[doc(inline)]
 pub use __impl_displaynames_variants_v1 as impl_displaynames_variants_v1;
 #[macro_use]
+#[path = "macros/numberrange_patterns_v1.rs.data"]
+mod numberrange_patterns_v1;
+#[doc(inline)]
+pub use __impl_numberrange_patterns_v1 as impl_numberrange_patterns_v1;
+#[macro_use]
 #[path = "macros/percent_essentials_v1.rs.data"]
 mod percent_essentials_v1;
 #[doc(inline)]
 pub use __impl_percent_essentials_v1 as impl_percent_essentials_v1;
 #[macro_use]
+#[path = "macros/percent_permille_v1.rs.data"]
+mod percent_permille_v1;
+#[doc(inline)]
+pub use __impl_percent_permille_v1 as impl_percent_permille_v1;
+#[macro_use]
+#[path = "macros/rbnf_numbering_v1.rs.data"]
+mod rbnf_numbering_v1;
+#[doc(inline)]
+pub use __impl_rbnf_numbering_v1 as impl_rbnf_numbering_v1;
+#[macro_use]
+#[path = "macros/rbnf_ordinal_v1.rs.data"]
+mod rbnf_ordinal_v1;
+#[doc(inline)]
+pub use __impl_rbnf_ordinal_v1 as impl_rbnf_ordinal_v1;
+#[macro_use]
+#[path = "macros/rbnf_spellout_v1.rs.data"]
+mod rbnf_spellout_v1;
+#[doc(inline)]
+pub use __impl_rbnf_spellout_v1 as impl_rbnf_spellout_v1;
+#[macro_use]
 #[path = "macros/relativetime_long_day_v1.rs.data"]
 mod relativetime_long_day_v1;
 #[doc(inline)]
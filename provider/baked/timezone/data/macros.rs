@@ -31,6 +31,11 @@ mod time_zone_bcp47_to_iana_v1;
 #[doc(inline)]
 pub use __impl_time_zone_bcp47_to_iana_v1 as impl_time_zone_bcp47_to_iana_v1;
 #[macro_use]
+#[path = "macros/time_zone_bcp47_to_windows_v1.rs.data"]
+mod time_zone_bcp47_to_windows_v1;
+#[doc(inline)]
+pub use __impl_time_zone_bcp47_to_windows_v1 as impl_time_zone_bcp47_to_windows_v1;
+#[macro_use]
 #[path = "macros/time_zone_iana_to_bcp47_v1.rs.data"]
 mod time_zone_iana_to_bcp47_v1;
 #[doc(inline)]
@@ -40,3 +45,18 @@ pub use __impl_time_zone_iana_to_bcp47_v1 as impl_time_zone_iana_to_bcp47_v1;
 mod time_zone_metazone_period_v1;
 #[doc(inline)]
 pub use __impl_time_zone_metazone_period_v1 as impl_time_zone_metazone_period_v1;
+#[macro_use]
+#[path = "macros/time_zone_metazone_primary_zones_v1.rs.data"]
+mod time_zone_metazone_primary_zones_v1;
+#[doc(inline)]
+pub use __impl_time_zone_metazone_primary_zones_v1 as impl_time_zone_metazone_primary_zones_v1;
+#[macro_use]
+#[path = "macros/time_zone_region_zones_v1.rs.data"]
+mod time_zone_region_zones_v1;
+#[doc(inline)]
+pub use __impl_time_zone_region_zones_v1 as impl_time_zone_region_zones_v1;
+#[macro_use]
+#[path = "macros/time_zone_windows_to_bcp47_v1.rs.data"]
+mod time_zone_windows_to_bcp47_v1;
+#[doc(inline)]
+pub use __impl_time_zone_windows_to_bcp47_v1 as impl_time_zone_windows_to_bcp47_v1;
@@ -150,6 +150,13 @@ pub struct Cli {
     )]
     segmenter_lstm_root: Option<PathBuf>,
 
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local directory of compiled TZif files, such as /usr/share/zoneinfo.\n\
+                  Required for time zone offset data. Requires the `tzdb` Cargo feature."
+    )]
+    tzdb_root: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value_t = TrieType::Small)]
     #[arg(
         help = "Whether to optimize CodePointTrie data structures for size (\"small\") or speed (\"fast\").\n\
@@ -257,6 +264,7 @@ impl Cli {
                 &self.segmenter_lstm_tag,
                 "segmenter-lstm",
             )?,
+            tzdb: self.tzdb_root.clone(),
             trie_type: match self.trie_type {
                 TrieType::Fast => config::TrieType::Fast,
                 TrieType::Small => config::TrieType::Small,
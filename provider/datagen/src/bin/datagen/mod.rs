@@ -79,6 +79,15 @@ fn main() -> eyre::Result<()> {
         _ => eyre::bail!("Downloading data from tags requires the `networking` Cargo feature"),
     };
 
+    if let Some(path) = config.tzdb {
+        #[cfg(feature = "tzdb")]
+        {
+            provider = provider.with_tzdb(path)?;
+        }
+        #[cfg(not(feature = "tzdb"))]
+        eyre::bail!("Time zone database data at {path:?} requires the `tzdb` Cargo feature");
+    }
+
     let mut driver = DatagenDriver::new();
     driver = match config.keys {
         config::KeyInclude::None => driver.with_keys([]),
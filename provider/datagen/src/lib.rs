@@ -110,6 +110,10 @@
 //! * `icu_experimental`
 //!   * enables data generation for keys defined in the unstable `icu_experimental` crate
 //!   * note that this features affects the behaviour of `all_keys`
+//! * `tzdb`
+//!   * enables [`DatagenProvider::with_tzdb`] and the `--tzdb-root` CLI argument, which read
+//!     time zone offsets from the TZif files of the IANA time zone database
+//!   * note that this feature affects the behaviour of `all_keys`
 //!
 //! The meta-feature `experimental_components` is available to activate all experimental components.
 
@@ -25,6 +25,7 @@ use std::sync::Arc;
 /// * [`is_missing_cldr_error`](Self::is_missing_cldr_error)
 /// * [`is_missing_icuexport_error`](Self::is_missing_icuexport_error)
 /// * [`is_missing_segmenter_lstm_error`](Self::is_missing_segmenter_lstm_error)
+/// * `is_missing_tzdb_error`, with the `tzdb` Cargo feature
 #[allow(clippy::exhaustive_structs)] // any information will be added to SourceData
 #[derive(Debug, Clone)]
 pub struct DatagenProvider {
@@ -71,13 +72,15 @@ impl DatagenProvider {
     /// will return errors.
     ///
     /// Use [`with_cldr`](Self::with_cldr), [`with_icuexport`](Self::with_icuexport),
-    /// [`with_segmenter_lstm`](Self::with_segmenter_lstm) to set data sources.
+    /// [`with_segmenter_lstm`](Self::with_segmenter_lstm), and `with_tzdb` to set data sources.
     pub fn new_custom() -> Self {
         Self {
             source: SourceData {
                 cldr_paths: None,
                 icuexport_paths: None,
                 segmenter_lstm_paths: None,
+                #[cfg(feature = "tzdb")]
+                tzdb_paths: None,
                 trie_type: Default::default(),
                 collation_han_database: Default::default(),
                 #[cfg(feature = "legacy_api")]
@@ -127,6 +130,21 @@ impl DatagenProvider {
         })
     }
 
+    /// Adds time zone database source data to the provider. The path should point to a local
+    /// directory or ZIP file of compiled TZif files, such as `/usr/share/zoneinfo` (see
+    /// [IANA](https://www.iana.org/time-zones)).
+    ///
+    /// ✨ *Enabled with the `tzdb` Cargo feature.*
+    #[cfg(feature = "tzdb")]
+    pub fn with_tzdb(self, root: PathBuf) -> Result<Self, DataError> {
+        Ok(Self {
+            source: SourceData {
+                tzdb_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new(root)?))),
+                ..self.source
+            },
+        })
+    }
+
     /// Adds CLDR source data to the provider. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -191,6 +209,9 @@ impl DatagenProvider {
     const MISSING_SEGMENTER_LSTM_ERROR: DataError =
         DataErrorKind::MissingSourceData.with_str_context("segmenter");
 
+    #[cfg(feature = "tzdb")]
+    const MISSING_TZDB_ERROR: DataError = DataErrorKind::MissingSourceData.with_str_context("tzdb");
+
     /// Identifies errors that are due to missing CLDR data.
     pub fn is_missing_cldr_error(mut e: DataError) -> bool {
         e.key = None;
@@ -209,6 +230,15 @@ impl DatagenProvider {
         e == Self::MISSING_SEGMENTER_LSTM_ERROR
     }
 
+    /// Identifies errors that are due to missing time zone database data.
+    ///
+    /// ✨ *Enabled with the `tzdb` Cargo feature.*
+    #[cfg(feature = "tzdb")]
+    pub fn is_missing_tzdb_error(mut e: DataError) -> bool {
+        e.key = None;
+        e == Self::MISSING_TZDB_ERROR
+    }
+
     pub(crate) fn cldr(&self) -> Result<&CldrCache, DataError> {
         self.source
             .cldr_paths
@@ -230,6 +260,14 @@ impl DatagenProvider {
             .ok_or(Self::MISSING_SEGMENTER_LSTM_ERROR)
     }
 
+    #[cfg(feature = "tzdb")]
+    pub(crate) fn tzdb(&self) -> Result<&SerdeCache, DataError> {
+        self.source
+            .tzdb_paths
+            .as_deref()
+            .ok_or(Self::MISSING_TZDB_ERROR)
+    }
+
     /// Set this to use tries optimized for speed instead of data size
     pub fn with_fast_tries(self) -> Self {
         Self {
@@ -319,6 +357,8 @@ pub struct SourceData {
     pub(crate) cldr_paths: Option<Arc<CldrCache>>,
     pub(crate) icuexport_paths: Option<Arc<SerdeCache>>,
     pub(crate) segmenter_lstm_paths: Option<Arc<SerdeCache>>,
+    #[cfg(feature = "tzdb")]
+    pub(crate) tzdb_paths: Option<Arc<SerdeCache>>,
     pub(crate) trie_type: TrieType,
     pub(crate) collation_han_database: CollationHanDatabase,
     #[cfg(feature = "legacy_api")]
@@ -258,11 +258,22 @@ registry!(
     icu_datetime::provider::time_zones::ExemplarCitiesV1Marker = "time_zone/exemplar_cities@1",
     #[cfg(all())]
     icu_decimal::provider::DecimalSymbolsV1Marker = "decimal/symbols@1",
+    icu_decimal::provider::DecimalSymbolsV2Marker = "decimal/symbols@2",
+    icu_decimal::provider::ScientificSymbolsV1Marker = "decimal/scientific@1",
     #[cfg(feature = "experimental_components")]
     icu_experimental::dimension::provider::currency::CurrencyEssentialsV1Marker =
         "currency/essentials@1",
+    icu_experimental::dimension::provider::currency::CurrencyFractionsV1Marker =
+        "currency/fractions@1",
+    icu_experimental::dimension::provider::currency::CurrencyLongNamesV1Marker =
+        "currency/long_names@1",
+    icu_experimental::dimension::provider::currency::CurrencyAccountingPatternsV1Marker =
+        "currency/accounting@1",
+    icu_experimental::dimension::provider::currency::ShortCurrencyCompactV1Marker =
+        "currency/compact@1",
     icu_experimental::dimension::provider::percent::PercentEssentialsV1Marker =
         "percent/essentials@1",
+    icu_experimental::dimension::provider::percent::PermilleSymbolV1Marker = "percent/permille@1",
     #[cfg(feature = "experimental_components")]
     icu_experimental::displaynames::provider::RegionDisplayNamesV1Marker = "displaynames/regions@1",
     icu_experimental::displaynames::provider::LanguageDisplayNamesV1Marker =
@@ -271,6 +282,10 @@ registry!(
     icu_experimental::displaynames::provider::ScriptDisplayNamesV1Marker = "displaynames/scripts@1",
     icu_experimental::displaynames::provider::VariantDisplayNamesV1Marker =
         "displaynames/variants@1",
+    icu_experimental::numberrange::provider::NumberRangePatternsV1Marker = "numberrange/patterns@1",
+    icu_experimental::rbnf::provider::NumberingSystemRulesV1Marker = "rbnf/numbering@1",
+    icu_experimental::rbnf::provider::OrdinalRulesV1Marker = "rbnf/ordinal@1",
+    icu_experimental::rbnf::provider::SpelloutRulesV1Marker = "rbnf/spellout@1",
     #[cfg(all())]
     icu_list::provider::AndListV1Marker = "list/and@1",
     icu_list::provider::OrListV1Marker = "list/or@1",
@@ -496,9 +511,15 @@ registry!(
     icu_segmenter::provider::WordBreakDataV1Marker = "segmenter/word@1",
     #[cfg(all())]
     icu_timezone::provider::MetazonePeriodV1Marker = "time_zone/metazone_period@1",
+    icu_timezone::provider::MetazonePrimaryZonesV1Marker = "time_zone/metazone_primary_zones@1",
     icu_timezone::provider::names::Bcp47ToIanaMapV1Marker = "time_zone/bcp47_to_iana@1",
+    icu_timezone::provider::names::Bcp47ToWindowsMapV1Marker = "time_zone/bcp47_to_windows@1",
     icu_timezone::provider::names::IanaToBcp47MapV1Marker = "time_zone/iana_to_bcp47@1",
     icu_timezone::provider::names::IanaToBcp47MapV2Marker = "time_zone/iana_to_bcp47@2",
+    icu_timezone::provider::names::WindowsToBcp47MapV1Marker = "time_zone/windows_to_bcp47@1",
+    icu_timezone::provider::RegionTimeZonesV1Marker = "time_zone/region_zones@1",
+    #[cfg(feature = "tzdb")]
+    icu_timezone::provider::ZoneOffsetPeriodV1Marker = "time_zone/offset_period@1",
     #[cfg(feature = "experimental_components")]
     icu_experimental::transliterate::provider::TransliteratorRulesV1Marker =
         "transliterator/rules@1",
@@ -47,7 +47,7 @@ impl SerdeCache {
         parser: fn(&[u8]) -> Result<S, DataError>,
     ) -> Result<&S, DataError>
     where
-        for<'de> S: serde::Deserialize<'de> + 'static + Send + Sync,
+        S: 'static + Send + Sync,
     {
         match self.cache.get(path) {
             Some(x) => x,
@@ -83,6 +83,14 @@ impl SerdeCache {
         })
     }
 
+    #[cfg(feature = "tzdb")]
+    pub fn read_and_parse_tzif(&self, path: &str) -> Result<&tzif::data::tzif::TzifData, DataError> {
+        self.read_and_parse(path, |bytes| {
+            tzif::parse_tzif_bytes(bytes)
+                .map_err(|e| DataError::custom("TZif parse").with_display_context(&e))
+        })
+    }
+
     pub fn list(&self, path: &str) -> Result<impl Iterator<Item = String>, DataError> {
         self.root.list(path)
     }
@@ -253,6 +253,7 @@ impl DatagenProvider {
                             ("cldr-core/supplemental/pluralRanges.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/pluralRanges.json").as_slice()),
                             ("cldr-core/supplemental/plurals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/plurals.json").as_slice()),
                             ("cldr-core/supplemental/weekData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/weekData.json").as_slice()),
+                            ("cldr-core/supplemental/windowsZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/windowsZones.json").as_slice()),
                             ("cldr-dates-full/main/ar/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/ca-gregorian.json").as_slice()),
                             ("cldr-dates-full/main/ar-EG/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/ca-gregorian.json").as_slice()),
                             ("cldr-dates-full/main/bn/ca-gregorian.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/bn/ca-gregorian.json").as_slice()),
@@ -467,6 +468,12 @@ impl DatagenProvider {
                             ("cldr-person-names-full/main/th/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/th/personNames.json").as_slice()),
                             ("cldr-person-names-full/main/tr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/tr/personNames.json").as_slice()),
                             ("cldr-person-names-full/main/und/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/und/personNames.json").as_slice()),
+                            ("cldr-rbnf/rbnf/en.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/en.json").as_slice()),
+                            ("cldr-rbnf/rbnf/es.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/es.json").as_slice()),
+                            ("cldr-rbnf/rbnf/fr.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/fr.json").as_slice()),
+                            ("cldr-rbnf/rbnf/ja.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/ja.json").as_slice()),
+                            ("cldr-rbnf/rbnf/root.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/root.json").as_slice()),
+                            ("cldr-rbnf/rbnf/zh.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/zh.json").as_slice()),
                             ("cldr-transforms-full/main/Any-Publishing/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Any-Publishing/metadata.json").as_slice()),
                             ("cldr-transforms-full/main/Any-Publishing/source.txt", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Any-Publishing/source.txt").as_slice()),
                             ("cldr-transforms-full/main/Bengali-Arabic/metadata.json", include_bytes!("../../tests/data/cldr/cldr-transforms-full/main/Bengali-Arabic/metadata.json").as_slice()),
@@ -660,6 +667,20 @@ impl DatagenProvider {
                             ("Thai_graphclust_model4_heavy/weights.json", include_bytes!("../../tests/data/lstm/Thai_graphclust_model4_heavy/weights.json").as_slice())
                         ].into_iter().collect(),
                     )))),
+                    #[cfg(feature = "tzdb")]
+                    tzdb_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                        [
+                            ("America/Bogota", include_bytes!("../../tests/data/tzdb/America/Bogota").as_slice()),
+                            ("America/Los_Angeles", include_bytes!("../../tests/data/tzdb/America/Los_Angeles").as_slice()),
+                            ("America/Whitehorse", include_bytes!("../../tests/data/tzdb/America/Whitehorse").as_slice()),
+                            ("Antarctica/Troll", include_bytes!("../../tests/data/tzdb/Antarctica/Troll").as_slice()),
+                            ("Arctic/Longyearbyen", include_bytes!("../../tests/data/tzdb/Arctic/Longyearbyen").as_slice()),
+                            ("Asia/Tokyo", include_bytes!("../../tests/data/tzdb/Asia/Tokyo").as_slice()),
+                            ("Chile/EasterIsland", include_bytes!("../../tests/data/tzdb/Chile/EasterIsland").as_slice()),
+                            ("PST8PDT", include_bytes!("../../tests/data/tzdb/PST8PDT").as_slice()),
+                            ("Pacific/Pohnpei", include_bytes!("../../tests/data/tzdb/Pacific/Pohnpei").as_slice())
+                        ].into_iter().collect(),
+                    )))),
                     ..DatagenProvider::new_custom().source
                 },
             })
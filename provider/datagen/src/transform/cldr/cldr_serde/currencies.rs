@@ -18,6 +18,27 @@ pub struct CurrencyPatterns {
 
     #[serde(rename = "symbol-alt-narrow")]
     pub narrow: Option<String>,
+
+    #[serde(rename = "displayName-count-zero")]
+    pub zero: Option<String>,
+
+    #[serde(rename = "displayName-count-one")]
+    pub one: Option<String>,
+
+    #[serde(rename = "displayName-count-two")]
+    pub two: Option<String>,
+
+    #[serde(rename = "displayName-count-few")]
+    pub few: Option<String>,
+
+    #[serde(rename = "displayName-count-many")]
+    pub many: Option<String>,
+
+    #[serde(rename = "displayName-count-other")]
+    pub other: Option<String>,
+
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
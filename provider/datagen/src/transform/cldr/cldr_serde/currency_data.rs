@@ -33,7 +33,7 @@ pub struct RoundingModes {
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct Fractions {
     #[serde(rename = "DEFAULT")]
-    default: RoundingModes,
+    pub default: RoundingModes,
 
     #[serde(flatten)]
     pub currencies: BTreeMap<ISOCode, RoundingModes>,
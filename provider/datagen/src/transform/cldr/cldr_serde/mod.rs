@@ -28,6 +28,8 @@ pub mod numbers;
 pub mod parent_locales;
 pub mod plural_ranges;
 pub mod plurals;
+#[cfg(feature = "experimental_components")]
+pub mod rbnf;
 pub mod time_zones;
 #[cfg(feature = "experimental_components")]
 pub mod transforms;
@@ -24,6 +24,13 @@ pub struct Symbols {
     pub plus_sign: String,
     #[serde(rename = "percentSign")]
     pub percent_sign: String,
+    #[serde(rename = "perMille")]
+    pub per_mille: String,
+    pub infinity: String,
+    pub nan: String,
+    pub exponential: String,
+    #[serde(rename = "superscriptingExponent")]
+    pub superscripting_exponent: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -96,6 +103,47 @@ pub struct CurrencyFormattingPatterns {
     /// Standard alphaNextToNumber pattern
     #[serde(rename = "standard-alphaNextToNumber")]
     pub standard_alpha_next_to_number: Option<String>,
+
+    /// Accounting pattern
+    pub accounting: Option<String>,
+
+    /// Accounting alphaNextToNumber pattern
+    #[serde(rename = "accounting-alphaNextToNumber")]
+    pub accounting_alpha_next_to_number: Option<String>,
+
+    /// Short compact patterns
+    pub short: Option<ShortCurrencyFormattingPatterns>,
+
+    /// Unit pattern for the "zero" plural category
+    #[serde(rename = "unitPattern-count-zero")]
+    pub unit_pattern_zero: Option<String>,
+
+    /// Unit pattern for the "one" plural category
+    #[serde(rename = "unitPattern-count-one")]
+    pub unit_pattern_one: Option<String>,
+
+    /// Unit pattern for the "two" plural category
+    #[serde(rename = "unitPattern-count-two")]
+    pub unit_pattern_two: Option<String>,
+
+    /// Unit pattern for the "few" plural category
+    #[serde(rename = "unitPattern-count-few")]
+    pub unit_pattern_few: Option<String>,
+
+    /// Unit pattern for the "many" plural category
+    #[serde(rename = "unitPattern-count-many")]
+    pub unit_pattern_many: Option<String>,
+
+    /// Unit pattern for the "other" plural category
+    #[serde(rename = "unitPattern-count-other")]
+    pub unit_pattern_other: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct ShortCurrencyFormattingPatterns {
+    /// Standard compact patterns, including the alphaNextToNumber alternatives,
+    /// whose counts end with `-alt-alphaNextToNumber`.
+    pub standard: DecimalFormat,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -104,6 +152,23 @@ pub struct PercentFormattingPatterns {
     pub standard: String,
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct MiscPatterns {
+    /// Pattern for an approximate number, such as "~{0}"
+    pub approximately: String,
+
+    /// Pattern for a number or more, such as "{0}+"
+    #[serde(rename = "atLeast")]
+    pub at_least: String,
+
+    /// Pattern for a number or less, such as "≤{0}"
+    #[serde(rename = "atMost")]
+    pub at_most: String,
+
+    /// Pattern for a range of numbers, such as "{0}–{1}"
+    pub range: String,
+}
+
 #[derive(PartialEq, Debug, Default)]
 pub struct NumberingSystemData {
     /// Map from numbering system to symbols
@@ -114,6 +179,8 @@ pub struct NumberingSystemData {
     pub currency_patterns: HashMap<TinyStr8, CurrencyFormattingPatterns>,
     /// Map from numbering system to percent patterns
     pub percent_patterns: HashMap<TinyStr8, PercentFormattingPatterns>,
+    /// Map from numbering system to miscellaneous patterns
+    pub misc_patterns: HashMap<TinyStr8, MiscPatterns>,
 }
 
 pub struct NumberingSystemDataVisitor;
@@ -157,6 +224,10 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: PercentFormattingPatterns = access.next_value()?;
                     result.percent_patterns.insert(numsys, value);
                 }
+                "miscPatterns" => {
+                    let value: MiscPatterns = access.next_value()?;
+                    result.misc_patterns.insert(numsys, value);
+                }
                 _ => {
                     // When needed, consume "scientificFormats", "percentFormats", ...
                     // For now, ignore them.
@@ -0,0 +1,36 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON rbnf.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-rbnf/rbnf/en.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// A map from rule set names, such as "%spellout-cardinal" or "%%th", to their rules.
+///
+/// Each rule is a pair of a descriptor, such as "100" or "-x", and the rule text.
+pub type RuleSets = BTreeMap<String, Vec<(String, String)>>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct RuleSetGroupings {
+    #[serde(rename = "SpelloutRules")]
+    pub spellout_rules: Option<RuleSets>,
+    #[serde(rename = "OrdinalRules")]
+    pub ordinal_rules: Option<RuleSets>,
+    #[serde(rename = "NumberingSystemRules")]
+    pub numbering_system_rules: Option<RuleSets>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Rbnf {
+    pub rbnf: RuleSetGroupings,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub rbnf: Rbnf,
+}
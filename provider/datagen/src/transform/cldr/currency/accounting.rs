@@ -0,0 +1,128 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::currency::essentials::{create_negative_pattern, create_pattern};
+use icu_experimental::dimension::provider::currency::*;
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+use tinystr::tinystr;
+
+impl DataProvider<CurrencyAccountingPatternsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<CurrencyAccountingPatternsV1Marker>, DataError> {
+        self.check_req::<CurrencyAccountingPatternsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let numbers_resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        // TODO(#3838): these patterns might be numbering system dependent.
+        let currency_formats = numbers_resource
+            .main
+            .value
+            .numbers
+            .numsys_data
+            .currency_patterns
+            .get(&tinystr!(8, "latn"))
+            .ok_or_else(|| DataError::custom("Could not find the currency patterns"))?;
+
+        let minus_sign = &numbers_resource
+            .main
+            .value
+            .numbers
+            .numsys_data
+            .symbols
+            .get(&tinystr!(8, "latn"))
+            .ok_or_else(|| DataError::custom("Could not find the number symbols"))?
+            .minus_sign;
+
+        let accounting = currency_formats.accounting.as_deref().unwrap_or_default();
+        let accounting_alpha_next_to_number = currency_formats
+            .accounting_alpha_next_to_number
+            .as_deref()
+            .unwrap_or_default();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyAccountingPatternsV1 {
+                pattern: create_pattern(accounting)?,
+                alpha_next_to_number_pattern: create_pattern(accounting_alpha_next_to_number)?,
+                negative_pattern: create_negative_pattern(accounting, minus_sign)?,
+                negative_alpha_next_to_number_pattern: create_negative_pattern(
+                    accounting_alpha_next_to_number,
+                    minus_sign,
+                )?,
+            })),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<CurrencyAccountingPatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let en: DataPayload<CurrencyAccountingPatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        en.get().pattern.as_ref().unwrap().clone().take_store(),
+        "\u{3}\u{2}"
+    );
+    assert_eq!(
+        en.get()
+            .negative_pattern
+            .as_ref()
+            .unwrap()
+            .clone()
+            .take_store(),
+        "\u{5}\u{4}()"
+    );
+    assert_eq!(
+        en.get()
+            .negative_alpha_next_to_number_pattern
+            .as_ref()
+            .unwrap()
+            .clone()
+            .take_store(),
+        "\u{5}\u{8}(\u{a0})"
+    );
+
+    let es: DataPayload<CurrencyAccountingPatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("es").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert!(es.get().pattern.is_some());
+    assert_eq!(es.get().alpha_next_to_number_pattern, None);
+    assert_eq!(es.get().negative_pattern, None);
+}
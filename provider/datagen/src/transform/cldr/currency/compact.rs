@@ -0,0 +1,141 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::cldr_serde::numbers::{CompactDecimalPattern, DecimalFormat};
+use crate::transform::cldr::decimal::compact_decimal_pattern::compact_currency_patterns;
+use icu_experimental::dimension::provider::currency::*;
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+use tinystr::tinystr;
+
+/// The suffix of the counts of the alphaNextToNumber alternatives, as in `1000-count-one-alt-alphaNextToNumber`.
+const ALPHA_NEXT_TO_NUMBER_SUFFIX: &str = "-alt-alphaNextToNumber";
+
+impl DataProvider<ShortCurrencyCompactV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<ShortCurrencyCompactV1Marker>, DataError> {
+        self.check_req::<ShortCurrencyCompactV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let numbers_resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        // TODO(#3838): these patterns might be numbering system dependent.
+        let currency_formats = numbers_resource
+            .main
+            .value
+            .numbers
+            .numsys_data
+            .currency_patterns
+            .get(&tinystr!(8, "latn"))
+            .ok_or_else(|| DataError::custom("Could not find the currency patterns"))?;
+
+        let mut standard = DecimalFormat::default();
+        let mut alpha_next_to_number = DecimalFormat::default();
+        for pattern in currency_formats
+            .short
+            .iter()
+            .flat_map(|short| short.standard.patterns.iter())
+        {
+            let (format, count) = match pattern
+                .compact_decimal_count
+                .strip_suffix(ALPHA_NEXT_TO_NUMBER_SUFFIX)
+            {
+                Some(count) => (&mut alpha_next_to_number, count),
+                None => (&mut standard, pattern.compact_decimal_count.as_str()),
+            };
+            format.patterns.push(CompactDecimalPattern {
+                compact_decimal_type: pattern.compact_decimal_type.clone(),
+                compact_decimal_count: count.to_string(),
+                pattern: pattern.pattern.clone(),
+            });
+        }
+
+        let convert = |format: &DecimalFormat| {
+            compact_currency_patterns(format)
+                .map(|data| data.patterns)
+                .map_err(|s| {
+                    DataError::custom("Could not create compact currency patterns")
+                        .with_display_context(&s)
+                })
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(ShortCurrencyCompactV1 {
+                standard_patterns: convert(&standard)?,
+                alpha_next_to_number_patterns: convert(&alpha_next_to_number)?,
+            })),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<ShortCurrencyCompactV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_experimental::compactdecimal::provider::Count;
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let en: DataPayload<ShortCurrencyCompactV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let thousands = en
+        .get()
+        .standard_patterns
+        .get_2d(&3, &Count::Other)
+        .unwrap();
+    assert_eq!(thousands.exponent, 3);
+    assert_eq!(thousands.index, 2);
+    assert_eq!(&thousands.literal_text, "¤K");
+
+    let thousands = en
+        .get()
+        .alpha_next_to_number_patterns
+        .get_2d(&3, &Count::Other)
+        .unwrap();
+    assert_eq!(thousands.index, 4);
+    assert_eq!(&thousands.literal_text, "¤\u{a0}K");
+
+    let fr: DataPayload<ShortCurrencyCompactV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let thousands = fr
+        .get()
+        .standard_patterns
+        .get_2d(&3, &Count::Other)
+        .unwrap();
+    assert_eq!(thousands.index, 0);
+    assert_eq!(&thousands.literal_text, "\u{a0}k\u{a0}¤");
+    assert!(fr.get().alpha_next_to_number_patterns.is_empty());
+}
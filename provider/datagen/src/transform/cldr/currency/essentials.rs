@@ -5,7 +5,7 @@
 use crate::provider::IterableDataProviderInternal;
 use crate::transform::cldr::cldr_serde;
 
-use crate::transform::cldr::decimal::decimal_pattern::DecimalPattern;
+use crate::transform::cldr::decimal::decimal_pattern::{DecimalPattern, DecimalSubPattern};
 
 use crate::DatagenProvider;
 
@@ -136,6 +136,16 @@ fn extract_currency_essentials<'data>(
         .get(&tinystr!(8, "latn"))
         .ok_or_else(|| DataError::custom("Could not find the standard pattern"))?;
 
+    let minus_sign = &numbers_resource
+        .main
+        .value
+        .numbers
+        .numsys_data
+        .symbols
+        .get(&tinystr!(8, "latn"))
+        .ok_or_else(|| DataError::custom("Could not find the number symbols"))?
+        .minus_sign;
+
     let standard = &currency_formats.standard;
     let standard_alpha_next_to_number = match &currency_formats.standard_alpha_next_to_number {
         Some(standard_alpha_next_to_number) => standard_alpha_next_to_number,
@@ -260,57 +270,89 @@ fn extract_currency_essentials<'data>(
             }
         };
 
-    /// Create a `DoublePlaceholderPattern` from a string pattern.
-    fn create_pattern<'data>(
-        pattern: &str,
-    ) -> Result<Option<DoublePlaceholderPattern<Cow<'data, str>>>, DataError> {
-        if pattern.is_empty() {
-            return Ok(None);
-        }
-
-        let decimal_pattern = DecimalPattern::from_str(pattern).map_err(|e| {
-            DataError::custom("Could not parse the pattern").with_display_context(&e)
-        })?;
-
-        // TODO(#4677): Handle the negative sub pattern.
-        let pattern_items = decimal_pattern
-            .positive
-            .to_pattern_items()
-            .into_iter()
-            .flat_map(|item| match item {
-                PatternItemCow::Placeholder(_) => vec![item],
-                PatternItemCow::Literal(s) if s.contains('¤') => {
-                    itertools::Itertools::intersperse(
-                        s.split('¤')
-                            .map(|s| PatternItemCow::Literal(s.to_string().into())),
-                        PatternItemCow::Placeholder(DoublePlaceholderKey::Place1),
-                    )
-                    .collect()
-                }
-                PatternItemCow::Literal(s) => vec![PatternItemCow::Literal(s)],
-            });
-
-        let pattern = Pattern::<DoublePlaceholder, _>::try_from_items(pattern_items.into_iter())
-            .map_err(|e| {
-                DataError::custom("Could not parse standard pattern").with_display_context(&e)
-            })?;
-
-        let pattern_store = pattern.take_store();
-        let borrowed_pattern: Pattern<DoublePlaceholder, Cow<'_, str>> =
-            Pattern::from_store_unchecked(Cow::Owned(pattern_store));
-
-        Ok(Some(borrowed_pattern.to_owned()))
-    }
-
     Ok(CurrencyEssentialsV1 {
         pattern_config_map: ZeroMap::from_iter(currency_patterns_map.iter()),
         standard_pattern: create_pattern(standard.as_str())?,
         standard_alpha_next_to_number_pattern: create_pattern(standard_alpha_next_to_number)?,
+        negative_standard_pattern: create_negative_pattern(standard.as_str(), minus_sign)?,
+        negative_standard_alpha_next_to_number_pattern: create_negative_pattern(
+            standard_alpha_next_to_number,
+            minus_sign,
+        )?,
         placeholders: VarZeroVec::from(&placeholders),
         default_pattern_config,
     })
 }
 
+/// Create a `DoublePlaceholderPattern` from a string pattern.
+pub(super) fn create_pattern<'data>(
+    pattern: &str,
+) -> Result<Option<DoublePlaceholderPattern<Cow<'data, str>>>, DataError> {
+    if pattern.is_empty() {
+        return Ok(None);
+    }
+
+    // The negative subpattern is read separately by `create_negative_pattern`.
+    create_subpattern(&parse_decimal_pattern(pattern)?.positive).map(Some)
+}
+
+/// Create a `DoublePlaceholderPattern` from the negative subpattern of a string pattern,
+/// such as "(¤#,##0.00)" in "¤#,##0.00;(¤#,##0.00)".
+///
+/// The `-` in the subpattern is replaced by the localized `minus_sign`.
+/// Returns `None` if the pattern has no negative subpattern.
+pub(super) fn create_negative_pattern<'data>(
+    pattern: &str,
+    minus_sign: &str,
+) -> Result<Option<DoublePlaceholderPattern<Cow<'data, str>>>, DataError> {
+    if pattern.is_empty() {
+        return Ok(None);
+    }
+
+    parse_decimal_pattern(pattern)?
+        .negative
+        .map(|mut subpattern| {
+            subpattern.prefix = subpattern.prefix.replace('-', minus_sign);
+            subpattern.suffix = subpattern.suffix.replace('-', minus_sign);
+            create_subpattern(&subpattern)
+        })
+        .transpose()
+}
+
+fn parse_decimal_pattern(pattern: &str) -> Result<DecimalPattern, DataError> {
+    DecimalPattern::from_str(pattern)
+        .map_err(|e| DataError::custom("Could not parse the pattern").with_display_context(&e))
+}
+
+fn create_subpattern<'data>(
+    subpattern: &DecimalSubPattern,
+) -> Result<DoublePlaceholderPattern<Cow<'data, str>>, DataError> {
+    let pattern_items = subpattern
+        .to_pattern_items()
+        .into_iter()
+        .flat_map(|item| match item {
+            PatternItemCow::Placeholder(_) => vec![item],
+            PatternItemCow::Literal(s) if s.contains('¤') => itertools::Itertools::intersperse(
+                s.split('¤')
+                    .map(|s| PatternItemCow::Literal(s.to_string().into())),
+                PatternItemCow::Placeholder(DoublePlaceholderKey::Place1),
+            )
+            .collect(),
+            PatternItemCow::Literal(s) => vec![PatternItemCow::Literal(s)],
+        });
+
+    let pattern = Pattern::<DoublePlaceholder, _>::try_from_items(pattern_items.into_iter())
+        .map_err(|e| {
+            DataError::custom("Could not parse standard pattern").with_display_context(&e)
+        })?;
+
+    let pattern_store = pattern.take_store();
+    let borrowed_pattern: Pattern<DoublePlaceholder, Cow<'_, str>> =
+        Pattern::from_store_unchecked(Cow::Owned(pattern_store));
+
+    Ok(borrowed_pattern.to_owned())
+}
+
 #[test]
 fn test_basic() {
     fn get_placeholders_of_currency(
@@ -384,6 +426,12 @@ fn test_basic() {
             .take_store(),
         "\u{3}\u{6}\u{a0}"
     );
+    assert!(en
+        .clone()
+        .get()
+        .to_owned()
+        .negative_standard_pattern
+        .is_none());
 
     let (en_usd_short, en_usd_narrow) =
         get_placeholders_of_currency(tinystr!(3, "USD").to_unvalidated(), &en, en_placeholders);
@@ -422,6 +470,16 @@ fn test_basic() {
         .to_owned()
         .standard_alpha_next_to_number_pattern
         .is_none());
+    assert_eq!(
+        ar_eg
+            .clone()
+            .get()
+            .to_owned()
+            .negative_standard_pattern
+            .unwrap()
+            .take_store(),
+        "\u{10}\u{15}\u{200f}\u{200e}-\u{a0}"
+    );
 
     let (ar_eg_egp_short, ar_eg_egp_narrow) = get_placeholders_of_currency(
         tinystr!(3, "EGP").to_unvalidated(),
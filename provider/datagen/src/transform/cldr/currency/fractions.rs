@@ -0,0 +1,95 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_experimental::dimension::provider::currency::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use tinystr::UnvalidatedTinyAsciiStr;
+use zerovec::ZeroMap;
+
+impl DataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyFractionsV1Marker>, DataError> {
+        self.check_req::<CurrencyFractionsV1Marker>(req)?;
+
+        let currency_data: &cldr_serde::currency_data::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/currencyData.json")?;
+
+        let fractions = &currency_data.supplemental.currency_data.fractions;
+
+        let default_digits = parse_digits(&fractions.default)?;
+
+        let mut digits_map = BTreeMap::<UnvalidatedTinyAsciiStr<3>, u8>::new();
+        for (iso, rounding_modes) in &fractions.currencies {
+            let digits = parse_digits(rounding_modes)?;
+            if digits != default_digits {
+                digits_map.insert(iso.to_unvalidated(), digits);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyFractionsV1 {
+                digits_map: ZeroMap::from_iter(digits_map),
+                default_digits,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// Returns the number of fraction digits of a currency, which defaults to 2 if not specified.
+fn parse_digits(
+    rounding_modes: &cldr_serde::currency_data::RoundingModes,
+) -> Result<u8, DataError> {
+    match &rounding_modes.digits {
+        Some(digits) => digits.parse().map_err(|_| {
+            DataError::custom("Could not parse the number of fraction digits")
+                .with_display_context(digits)
+        }),
+        None => Ok(2),
+    }
+}
+
+#[test]
+fn test_basic() {
+    use tinystr::tinystr;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let fractions: DataPayload<CurrencyFractionsV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let fractions = fractions.get();
+    assert_eq!(fractions.default_digits, 2);
+    assert_eq!(
+        fractions
+            .digits_map
+            .get_copied(&tinystr!(3, "JPY").to_unvalidated()),
+        Some(0)
+    );
+    assert_eq!(
+        fractions
+            .digits_map
+            .get_copied(&tinystr!(3, "BHD").to_unvalidated()),
+        Some(3)
+    );
+    assert_eq!(
+        fractions
+            .digits_map
+            .get_copied(&tinystr!(3, "USD").to_unvalidated()),
+        None
+    );
+}
@@ -0,0 +1,177 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use icu_experimental::dimension::provider::currency::*;
+use icu_pattern::DoublePlaceholderPattern;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use tinystr::tinystr;
+
+impl DataProvider<CurrencyLongNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyLongNamesV1Marker>, DataError> {
+        self.check_req::<CurrencyLongNamesV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let currencies_resource: &cldr_serde::currencies::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "currencies.json")?;
+
+        let numbers_resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        // TODO(#3838): these patterns might be numbering system dependent.
+        let currency_formats = numbers_resource
+            .main
+            .value
+            .numbers
+            .numsys_data
+            .currency_patterns
+            .get(&tinystr!(8, "latn"))
+            .ok_or_else(|| DataError::custom("Could not find the currency patterns"))?;
+
+        let other = currency_formats
+            .unit_pattern_other
+            .as_deref()
+            .ok_or_else(|| DataError::custom("Could not find the other unit pattern"))?;
+
+        // Patterns that are the same as the `other` pattern are not stored.
+        let optional_pattern = |pattern: &Option<String>| {
+            pattern
+                .as_deref()
+                .filter(|&pattern| pattern != other)
+                .map(parse_unit_pattern)
+                .transpose()
+        };
+
+        let unit_patterns = UnitPatterns {
+            zero: optional_pattern(&currency_formats.unit_pattern_zero)?,
+            one: optional_pattern(&currency_formats.unit_pattern_one)?,
+            two: optional_pattern(&currency_formats.unit_pattern_two)?,
+            few: optional_pattern(&currency_formats.unit_pattern_few)?,
+            many: optional_pattern(&currency_formats.unit_pattern_many)?,
+            other: parse_unit_pattern(other)?,
+        };
+
+        let display_names = currencies_resource
+            .main
+            .value
+            .numbers
+            .currencies
+            .iter()
+            .flat_map(|(iso, names)| {
+                // Use the generic display name if there is no name for the other category.
+                let other = names.other.as_ref().or(names.display_name.as_ref());
+                [
+                    (PluralCategory::Zero, names.zero.as_ref()),
+                    (PluralCategory::One, names.one.as_ref()),
+                    (PluralCategory::Two, names.two.as_ref()),
+                    (PluralCategory::Few, names.few.as_ref()),
+                    (PluralCategory::Many, names.many.as_ref()),
+                ]
+                .into_iter()
+                .filter(move |&(_, name)| name != other)
+                .chain([(PluralCategory::Other, other)])
+                .filter_map(move |(category, name)| Some((*iso, category, name?.as_str())))
+            })
+            .collect();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyLongNamesV1 {
+                unit_patterns,
+                display_names,
+            })),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<CurrencyLongNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+/// Parses a `unitPattern` such as "{0} {1}", where {0} is the number and {1} is the
+/// display name of the currency.
+fn parse_unit_pattern(
+    pattern: &str,
+) -> Result<DoublePlaceholderPattern<Cow<'static, str>>, DataError> {
+    let pattern = DoublePlaceholderPattern::<String>::try_from_str(pattern).map_err(|e| {
+        DataError::custom("Could not parse the unit pattern").with_display_context(&e)
+    })?;
+    Ok(DoublePlaceholderPattern::from_store_unchecked(Cow::Owned(
+        pattern.take_store(),
+    )))
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+    use icu_pattern::{DoublePlaceholderKey, PatternItem};
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let en: DataPayload<CurrencyLongNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let usd = tinystr!(3, "USD").to_unvalidated();
+    assert_eq!(
+        en.get().display_names.get_2d(&usd, &PluralCategory::One),
+        Some("US dollar")
+    );
+    assert_eq!(
+        en.get().display_names.get_2d(&usd, &PluralCategory::Other),
+        Some("US dollars")
+    );
+    assert_eq!(en.get().unit_patterns.one, None);
+    assert_eq!(
+        en.get().unit_patterns.other.iter().collect::<Vec<_>>(),
+        [
+            PatternItem::Placeholder(DoublePlaceholderKey::Place0),
+            PatternItem::Literal(" "),
+            PatternItem::Placeholder(DoublePlaceholderKey::Place1),
+        ]
+    );
+
+    let ru: DataPayload<CurrencyLongNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ru").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let rub = tinystr!(3, "RUB").to_unvalidated();
+    assert_eq!(
+        ru.get().display_names.get_2d(&rub, &PluralCategory::Few),
+        Some("российских рубля")
+    );
+    assert_eq!(
+        ru.get().display_names.get_2d(&rub, &PluralCategory::Many),
+        Some("российских рублей")
+    );
+    assert_eq!(
+        ru.get().display_names.get_2d(&rub, &PluralCategory::Other),
+        Some("российского рубля")
+    );
+}
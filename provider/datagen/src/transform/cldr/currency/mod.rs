@@ -2,4 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+pub mod accounting;
+pub mod compact;
 pub mod essentials;
+pub mod fractions;
+pub mod long_names;
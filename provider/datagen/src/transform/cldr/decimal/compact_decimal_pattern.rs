@@ -39,7 +39,10 @@ struct ParsedPlaceholder {
 
 /// Parses a compact decimal pattern string, performing any validation that can
 /// be done without the context of the associated type and count.
-fn parse(pattern: &str) -> Result<Option<ParsedPattern>, Cow<'static, str>> {
+///
+/// If `currency` is true, the pattern is a compact currency pattern, and the
+/// currency sign ¤ is kept in the literal text.
+fn parse(pattern: &str, currency: bool) -> Result<Option<ParsedPattern>, Cow<'static, str>> {
     let cldr_overrides: HashMap<String, String> = [
         // Unescaped - in yrl (Nheengatu).
         ("0 millón-ita", "0 millón'-'ita"),
@@ -94,6 +97,13 @@ fn parse(pattern: &str) -> Result<Option<ParsedPattern>, Cow<'static, str>> {
         for (i, chunk) in pattern.split('\'').enumerate() {
             let escaped = i % 2 == 1;
             if escaped {
+                if currency && chunk.contains('¤') {
+                    // A quoted ¤ would be indistinguishable from the currency sign.
+                    return Err(format!(
+                        "Quoted currency sign in compact currency pattern {pattern}"
+                    )
+                    .into());
+                }
                 if chunk.is_empty() {
                     // '' means '.
                     literal_text.push('\'');
@@ -104,10 +114,9 @@ fn parse(pattern: &str) -> Result<Option<ParsedPattern>, Cow<'static, str>> {
             } else {
                 // We are in unquoted text, so we need to check for the
                 // symbols defined in https://www.unicode.org/reports/tr35/tr35-numbers.html#Number_Pattern_Character_Definitions.
-                if chunk
-                    .chars()
-                    .any(|c| ('1'..'9').contains(&c) || "@#.-,E+%‰,¤*'".contains(c))
-                {
+                if chunk.chars().any(|c| {
+                    ('1'..'9').contains(&c) || "@#.-,E+%‰,*'".contains(c) || (c == '¤' && !currency)
+                }) {
                     return Err(
                         format!("Unsupported symbol in compact decimal pattern {pattern}").into(),
                     );
@@ -166,206 +175,219 @@ impl TryFrom<&DecimalFormat> for CompactDecimalPatternDataV1<'static> {
     type Error = Cow<'static, str>;
 
     fn try_from(other: &DecimalFormat) -> Result<Self, Self::Error> {
-        let mut parsed_patterns: BTreeMap<i8, BTreeMap<Count, Option<ParsedPattern>>> =
-            BTreeMap::new();
-        // First ingest the CLDR mapping.
-        for pattern in other.patterns.iter() {
-            let mut type_bytes = pattern.compact_decimal_type.bytes();
+        from_decimal_format(other, false)
+    }
+}
 
-            if !(type_bytes.next() == Some(b'1') && type_bytes.all(|b| b == b'0')) {
-                return Err(format!("Ill-formed type {}", pattern.compact_decimal_type).into());
+/// Converts the CLDR compact currency patterns, such as "¤0K", to a
+/// [`CompactDecimalPatternDataV1`] whose literal text keeps the currency sign ¤.
+pub(crate) fn compact_currency_patterns(
+    other: &DecimalFormat,
+) -> Result<CompactDecimalPatternDataV1<'static>, Cow<'static, str>> {
+    from_decimal_format(other, true)
+}
+
+fn from_decimal_format(
+    other: &DecimalFormat,
+    currency: bool,
+) -> Result<CompactDecimalPatternDataV1<'static>, Cow<'static, str>> {
+    let mut parsed_patterns: BTreeMap<i8, BTreeMap<Count, Option<ParsedPattern>>> = BTreeMap::new();
+    // First ingest the CLDR mapping.
+    for pattern in other.patterns.iter() {
+        let mut type_bytes = pattern.compact_decimal_type.bytes();
+
+        if !(type_bytes.next() == Some(b'1') && type_bytes.all(|b| b == b'0')) {
+            return Err(format!("Ill-formed type {}", pattern.compact_decimal_type).into());
+        }
+        let log10_type = i8::try_from(pattern.compact_decimal_type.len() - 1)
+            .map_err(|_| format!("Too many digits in type {}", pattern.compact_decimal_type))?;
+        let count = match &*pattern.compact_decimal_count {
+            "zero" => Count::Zero,
+            "one" => Count::One,
+            "two" => Count::Two,
+            "few" => Count::Few,
+            "many" => Count::Many,
+            "other" => Count::Other,
+            "1" => Count::Explicit1,
+            _ => {
+                return Err(format!(
+                    "Invalid count {} in type {}",
+                    pattern.compact_decimal_count, pattern.compact_decimal_type
+                )
+                .into())
             }
-            let log10_type = i8::try_from(pattern.compact_decimal_type.len() - 1)
-                .map_err(|_| format!("Too many digits in type {}", pattern.compact_decimal_type))?;
-            let count = match &*pattern.compact_decimal_count {
-                "zero" => Count::Zero,
-                "one" => Count::One,
-                "two" => Count::Two,
-                "few" => Count::Few,
-                "many" => Count::Many,
-                "other" => Count::Other,
-                "1" => Count::Explicit1,
-                _ => {
+        };
+        let plural_map = parsed_patterns.entry(log10_type).or_default();
+        plural_map
+            .insert(count, parse(&pattern.pattern, currency)?)
+            .map_or_else(
+                // TODO(egg): This should be try_insert.
+                || Ok(()),
+                |_| {
+                    Err(format!(
+                        "Plural case {count:?} is duplicated for type 10^{log10_type}"
+                    ))
+                },
+            )?;
+    }
+    // Figure out which plural cases are used, and make the map dense by
+    // filling out the implicit fallbacks to the 0 (noncompact) pattern.
+    let plural_cases: BTreeSet<Count> = parsed_patterns
+        .iter()
+        .flat_map(|(_, plural_map)| plural_map.keys())
+        .copied()
+        .filter(|count| count != &Count::Explicit1)
+        .collect();
+    for log10_type in 0..=parsed_patterns.iter().last().map_or(0, |(key, _)| *key) {
+        for plural_case in &plural_cases {
+            parsed_patterns
+                .entry(log10_type)
+                .or_default()
+                .entry(*plural_case)
+                .or_insert(None);
+        }
+    }
+    let mut patterns: BTreeMap<i8, BTreeMap<Count, Pattern>> = BTreeMap::new();
+    // Compute the exponents based on the numbers of 0s in the placeholders
+    // and the type values: the exponent is 3 for type=1000, "0K", as well
+    // as for type=10000, "00K", etc.
+    // Remove duplicates of the count=other case in the same iteration.
+    for (log10_type, parsed_plural_map) in parsed_patterns {
+        let plural_map = patterns.entry(log10_type).or_default();
+        let other_pattern = parsed_plural_map
+            .get(&Count::Other)
+            .ok_or_else(|| format!("Missing other case for type 10^{log10_type}"))?
+            .clone();
+        let exponent: i8;
+        match &other_pattern {
+            None => {
+                if !parsed_plural_map.iter().all(|(_, p)| p.is_none()) {
                     return Err(format!(
-                        "Invalid count {} in type {}",
-                        pattern.compact_decimal_count, pattern.compact_decimal_type
+                        "Non-0 pattern for type 10^{log10_type} whose pattern for count=other is 0"
                     )
-                    .into())
+                    .into());
                 }
-            };
-            let plural_map = parsed_patterns.entry(log10_type).or_default();
-            plural_map
-                .insert(count, parse(&pattern.pattern)?)
-                .map_or_else(
-                    // TODO(egg): This should be try_insert.
-                    || Ok(()),
-                    |_| {
-                        Err(format!(
-                            "Plural case {count:?} is duplicated for type 10^{log10_type}"
-                        ))
-                    },
-                )?;
-        }
-        // Figure out which plural cases are used, and make the map dense by
-        // filling out the implicit fallbacks to the 0 (noncompact) pattern.
-        let plural_cases: BTreeSet<Count> = parsed_patterns
-            .iter()
-            .flat_map(|(_, plural_map)| plural_map.keys())
-            .copied()
-            .filter(|count| count != &Count::Explicit1)
-            .collect();
-        for log10_type in 0..=parsed_patterns.iter().last().map_or(0, |(key, _)| *key) {
-            for plural_case in &plural_cases {
-                parsed_patterns
-                    .entry(log10_type)
-                    .or_default()
-                    .entry(*plural_case)
-                    .or_insert(None);
+                exponent = 0;
             }
-        }
-        let mut patterns: BTreeMap<i8, BTreeMap<Count, Pattern>> = BTreeMap::new();
-        // Compute the exponents based on the numbers of 0s in the placeholders
-        // and the type values: the exponent is 3 for type=1000, "0K", as well
-        // as for type=10000, "00K", etc.
-        // Remove duplicates of the count=other case in the same iteration.
-        for (log10_type, parsed_plural_map) in parsed_patterns {
-            let plural_map = patterns.entry(log10_type).or_default();
-            let other_pattern = parsed_plural_map
-                .get(&Count::Other)
-                .ok_or_else(|| format!("Missing other case for type 10^{log10_type}"))?
-                .clone();
-            let exponent: i8;
-            match &other_pattern {
-                None => {
-                    if !parsed_plural_map.iter().all(|(_, p)| p.is_none()) {
-                        return Err(format!(
-                            "Non-0 pattern for type 10^{log10_type} whose pattern for count=other is 0"
+            Some(other_pattern) => {
+                let other_placeholder = other_pattern.placeholder.as_ref().ok_or_else(|| {
+                    format!("Missing placeholder in other case of type 10^{log10_type}")
+                })?;
+                for (count, pattern) in &parsed_plural_map {
+                    if let Some(pattern) = pattern {
+                        if let Some(placeholder) = &pattern.placeholder {
+                            if placeholder.number_of_0s != other_placeholder.number_of_0s {
+                                return Err(
+                        format!(
+                            "Inconsistent placeholders within type 10^{}: {} 0s for other, {} 0s for {:?}",
+                            log10_type,
+                            other_placeholder.number_of_0s,
+                            placeholder.number_of_0s,
+                            count
                         )
-                        .into());
-                    }
-                    exponent = 0;
-                }
-                Some(other_pattern) => {
-                    let other_placeholder =
-                        other_pattern.placeholder.as_ref().ok_or_else(|| {
-                            format!("Missing placeholder in other case of type 10^{log10_type}")
-                        })?;
-                    for (count, pattern) in &parsed_plural_map {
-                        if let Some(pattern) = pattern {
-                            if let Some(placeholder) = &pattern.placeholder {
-                                if placeholder.number_of_0s != other_placeholder.number_of_0s {
-                                    return Err(
-                            format!(
-                                "Inconsistent placeholders within type 10^{}: {} 0s for other, {} 0s for {:?}",
-                                log10_type,
-                                other_placeholder.number_of_0s,
-                                placeholder.number_of_0s,
-                                count
-                            )
-                            .into()
-                        );
-                                }
+                        .into()
+                    );
                             }
                         }
                     }
-                    exponent = log10_type - other_placeholder.number_of_0s + 1;
-                    if exponent < 1 {
-                        return Err(format!(
-                            "Too many 0s in type 10^{}, ({}, implying nonpositive exponent c={})",
-                            log10_type, other_placeholder.number_of_0s, exponent
-                        )
-                        .into());
-                    }
                 }
-            }
-            for (count, optional_pattern) in parsed_plural_map {
-                // Omit duplicates of the other case.
-                if count != Count::Other && optional_pattern == other_pattern {
-                    continue;
+                exponent = log10_type - other_placeholder.number_of_0s + 1;
+                if exponent < 1 {
+                    return Err(format!(
+                        "Too many 0s in type 10^{}, ({}, implying nonpositive exponent c={})",
+                        log10_type, other_placeholder.number_of_0s, exponent
+                    )
+                    .into());
                 }
-                plural_map.insert(
-                    count,
-                    match optional_pattern {
-                        None => Pattern {
-                            exponent: 0,
-                            literal_text: std::borrow::Cow::Borrowed(""),
-                            index: 0,
-                        },
-                        Some(pattern) => Pattern {
-                            exponent,
-                            literal_text: pattern.literal_text,
-                            index: pattern
-                                .placeholder
-                                .map_or(Some(u8::MAX), |p| {
-                                    u8::try_from(p.index)
-                                        .ok()
-                                        .and_then(|i| (i < u8::MAX).then_some(i))
-                                })
-                                .ok_or_else(|| {
-                                    format!(
-                                        "Placeholder index is too large in type=10^{log10_type}, count={count:?}"
-                                    )
-                                })?,
-                        },
-                    },
-                );
             }
         }
-        if !patterns
-            .iter()
-            .tuple_windows()
-            .all(|((_, low), (_, high))| {
-                low.get(&Count::Other).map(|p| p.exponent)
-                    <= high.get(&Count::Other).map(|p| p.exponent)
-            })
-        {
-            Err(format!(
-                "Compact decimal exponents should be nondecreasing: {:?}",
-                patterns
-                    .values()
-                    .map(|plural_map| plural_map.get(&Count::Other).map(|p| p.exponent))
-                    .collect::<Vec<_>>(),
-            ))?;
-        }
-        // Deduplicate sequences of types that have the same plural map (up to =1), keeping the lowest type.
-        // The pattern 0 for type 1 is implicit.
-        let deduplicated_patterns = patterns
-            .iter()
-            .coalesce(
-                |(log10_low_type, low_plural_map), (log10_high_type, high_plural_map)| {
-                    if low_plural_map == high_plural_map
-                        || (low_plural_map.contains_key(&Count::Explicit1)
-                            && low_plural_map
-                                .iter()
-                                .filter(|(count, _)| **count != Count::Explicit1)
-                                .all(|(k, v)| high_plural_map.get(k) == Some(v))
-                            && high_plural_map
-                                .iter()
-                                .all(|(k, v)| low_plural_map.get(k) == Some(v)))
-                    {
-                        Ok((log10_low_type, low_plural_map))
-                    } else {
-                        Err((
-                            (log10_low_type, low_plural_map),
-                            (log10_high_type, high_plural_map),
-                        ))
-                    }
+        for (count, optional_pattern) in parsed_plural_map {
+            // Omit duplicates of the other case.
+            if count != Count::Other && optional_pattern == other_pattern {
+                continue;
+            }
+            plural_map.insert(
+                count,
+                match optional_pattern {
+                    None => Pattern {
+                        exponent: 0,
+                        literal_text: std::borrow::Cow::Borrowed(""),
+                        index: 0,
+                    },
+                    Some(pattern) => Pattern {
+                        exponent,
+                        literal_text: pattern.literal_text,
+                        index: pattern
+                            .placeholder
+                            .map_or(Some(u8::MAX), |p| {
+                                u8::try_from(p.index)
+                                    .ok()
+                                    .and_then(|i| (i < u8::MAX).then_some(i))
+                            })
+                            .ok_or_else(|| {
+                                format!(
+                                    "Placeholder index is too large in type=10^{log10_type}, count={count:?}"
+                                )
+                            })?,
+                    },
                 },
-            )
-            .filter(|(log10_type, plural_map)| {
-                **log10_type != 0 || !plural_map.iter().all(|(_, pattern)| pattern.exponent == 0)
-            });
-        // Turn the BTreeMap of BTreeMaps into a ZeroMap2d.
-        Ok(CompactDecimalPatternDataV1 {
-            patterns: deduplicated_patterns
-                .flat_map(|(log10_type, plural_map)| {
-                    plural_map.iter().map(|(count, pattern)| {
-                        (*log10_type, *count, encode_varule_to_box(pattern))
-                    })
-                })
-                .collect(),
+            );
+        }
+    }
+    if !patterns
+        .iter()
+        .tuple_windows()
+        .all(|((_, low), (_, high))| {
+            low.get(&Count::Other).map(|p| p.exponent)
+                <= high.get(&Count::Other).map(|p| p.exponent)
         })
+    {
+        Err(format!(
+            "Compact decimal exponents should be nondecreasing: {:?}",
+            patterns
+                .values()
+                .map(|plural_map| plural_map.get(&Count::Other).map(|p| p.exponent))
+                .collect::<Vec<_>>(),
+        ))?;
     }
+    // Deduplicate sequences of types that have the same plural map (up to =1), keeping the lowest type.
+    // The pattern 0 for type 1 is implicit.
+    let deduplicated_patterns = patterns
+        .iter()
+        .coalesce(
+            |(log10_low_type, low_plural_map), (log10_high_type, high_plural_map)| {
+                if low_plural_map == high_plural_map
+                    || (low_plural_map.contains_key(&Count::Explicit1)
+                        && low_plural_map
+                            .iter()
+                            .filter(|(count, _)| **count != Count::Explicit1)
+                            .all(|(k, v)| high_plural_map.get(k) == Some(v))
+                        && high_plural_map
+                            .iter()
+                            .all(|(k, v)| low_plural_map.get(k) == Some(v)))
+                {
+                    Ok((log10_low_type, low_plural_map))
+                } else {
+                    Err((
+                        (log10_low_type, low_plural_map),
+                        (log10_high_type, high_plural_map),
+                    ))
+                }
+            },
+        )
+        .filter(|(log10_type, plural_map)| {
+            **log10_type != 0 || !plural_map.iter().all(|(_, pattern)| pattern.exponent == 0)
+        });
+    // Turn the BTreeMap of BTreeMaps into a ZeroMap2d.
+    Ok(CompactDecimalPatternDataV1 {
+        patterns: deduplicated_patterns
+            .flat_map(|(log10_type, plural_map)| {
+                plural_map
+                    .iter()
+                    .map(|(count, pattern)| (*log10_type, *count, encode_varule_to_box(pattern)))
+            })
+            .collect(),
+    })
 }
 
 #[cfg(test)]
@@ -579,21 +601,24 @@ mod tests {
     #[test]
     fn test_pattern_syntax_errors() {
         assert_eq!(
-            parse("M.").err().unwrap(),
+            parse("M.", false).err().unwrap(),
             "Unsupported symbol in compact decimal pattern M."
         );
-        assert_eq!(parse("M'.'").unwrap().unwrap().literal_text, "M.");
+        assert_eq!(parse("M'.'", false).unwrap().unwrap().literal_text, "M.");
         assert_eq!(
-            parse("0 0").err().unwrap(),
+            parse("0 0", false).err().unwrap(),
             "Multiple placeholders in compact decimal pattern 0 0"
         );
-        assert_eq!(parse("0 '0'").unwrap().unwrap().literal_text, " 0");
+        assert_eq!(parse("0 '0'", false).unwrap().unwrap().literal_text, " 0");
         let zeros = str::repeat("0", 256);
         assert_eq!(
-            parse(&zeros[..128]).err().unwrap(),
+            parse(&zeros[..128], false).err().unwrap(),
             String::from("Too many 0s in pattern ") + &zeros[..128]
         );
-        assert_eq!(parse(&zeros[..127]).unwrap().unwrap().literal_text, "");
+        assert_eq!(
+            parse(&zeros[..127], false).unwrap().unwrap().literal_text,
+            ""
+        );
     }
 
     #[test]
@@ -14,7 +14,7 @@ use tinystr::TinyAsciiStr;
 #[cfg(feature = "experimental_components")]
 mod compact;
 #[cfg(feature = "experimental_components")]
-mod compact_decimal_pattern;
+pub(crate) mod compact_decimal_pattern;
 pub(crate) mod decimal_pattern;
 mod symbols;
 
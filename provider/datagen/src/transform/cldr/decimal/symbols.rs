@@ -15,6 +15,86 @@ use tinystr::TinyAsciiStr;
 impl DataProvider<DecimalSymbolsV1Marker> for crate::DatagenProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalSymbolsV1Marker>, DataError> {
         self.check_req::<DecimalSymbolsV1Marker>(req)?;
+
+        let DecimalSymbolsV2 {
+            minus_sign_affixes,
+            plus_sign_affixes,
+            decimal_separator,
+            grouping_separator,
+            grouping_sizes,
+            digits,
+            infinity: _,
+            nan: _,
+        } = self.load_decimal_symbols(req)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(DecimalSymbolsV1 {
+                minus_sign_affixes,
+                plus_sign_affixes,
+                decimal_separator,
+                grouping_separator,
+                grouping_sizes,
+                digits,
+            })),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<DecimalSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        self.supported_locales_for_numbers()
+    }
+}
+
+impl DataProvider<DecimalSymbolsV2Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalSymbolsV2Marker>, DataError> {
+        self.check_req::<DecimalSymbolsV2Marker>(req)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(self.load_decimal_symbols(req)?)),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<DecimalSymbolsV2Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        self.supported_locales_for_numbers()
+    }
+}
+
+impl DataProvider<ScientificSymbolsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<ScientificSymbolsV1Marker>, DataError> {
+        self.check_req::<ScientificSymbolsV1Marker>(req)?;
+
+        let numbers_with_numsys = self.load_numbers_with_numsys(req)?;
+        let nsname = numbers_with_numsys.1;
+
+        let result = ScientificSymbolsV1::try_from(numbers_with_numsys).map_err(|s| {
+            DataError::custom("Could not create scientific symbols")
+                .with_display_context(&s)
+                .with_display_context(&nsname)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<ScientificSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        self.supported_locales_for_numbers()
+    }
+}
+
+impl crate::DatagenProvider {
+    fn load_numbers_with_numsys(
+        &self,
+        req: DataRequest,
+    ) -> Result<NumbersWithNumsys<'_>, DataError> {
         let langid = req.locale.get_langid();
 
         let resource: &cldr_serde::numbers::Resource = self
@@ -32,32 +112,32 @@ impl DataProvider<DecimalSymbolsV1Marker> for crate::DatagenProvider {
             None => numbers.default_numbering_system,
         };
 
-        let mut result =
-            DecimalSymbolsV1::try_from(NumbersWithNumsys(numbers, nsname)).map_err(|s| {
-                DataError::custom("Could not create decimal symbols")
-                    .with_display_context(&s)
-                    .with_display_context(&nsname)
-            })?;
+        Ok(NumbersWithNumsys(numbers, nsname))
+    }
 
-        result.digits = self.get_digits_for_numbering_system(nsname)?;
+    fn load_decimal_symbols(
+        &self,
+        req: DataRequest,
+    ) -> Result<DecimalSymbolsV2<'static>, DataError> {
+        let numbers_with_numsys = self.load_numbers_with_numsys(req)?;
+        let nsname = numbers_with_numsys.1;
 
-        Ok(DataResponse {
-            metadata: Default::default(),
-            payload: Some(DataPayload::from_owned(result)),
-        })
-    }
-}
+        let mut result = DecimalSymbolsV2::try_from(numbers_with_numsys).map_err(|s| {
+            DataError::custom("Could not create decimal symbols")
+                .with_display_context(&s)
+                .with_display_context(&nsname)
+        })?;
 
-impl IterableDataProviderInternal<DecimalSymbolsV1Marker> for crate::DatagenProvider {
-    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
-        self.supported_locales_for_numbers()
+        result.digits = self.get_digits_for_numbering_system(nsname)?;
+
+        Ok(result)
     }
 }
 
 #[derive(Debug)]
 struct NumbersWithNumsys<'a>(pub &'a cldr_serde::numbers::Numbers, pub TinyAsciiStr<8>);
 
-impl TryFrom<NumbersWithNumsys<'_>> for DecimalSymbolsV1<'static> {
+impl TryFrom<NumbersWithNumsys<'_>> for DecimalSymbolsV2<'static> {
     type Error = Cow<'static, str>;
 
     fn try_from(other: NumbersWithNumsys<'_>) -> Result<Self, Self::Error> {
@@ -88,6 +168,26 @@ impl TryFrom<NumbersWithNumsys<'_>> for DecimalSymbolsV1<'static> {
                 min_grouping: numbers.minimum_grouping_digits,
             },
             digits: Default::default(), // to be filled in
+            infinity: Cow::Owned(symbols.infinity.clone()),
+            nan: Cow::Owned(symbols.nan.clone()),
+        })
+    }
+}
+
+impl TryFrom<NumbersWithNumsys<'_>> for ScientificSymbolsV1<'static> {
+    type Error = Cow<'static, str>;
+
+    fn try_from(other: NumbersWithNumsys<'_>) -> Result<Self, Self::Error> {
+        let NumbersWithNumsys(numbers, nsname) = other;
+        let symbols = numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .ok_or("Could not find symbols for numbering system")?;
+
+        Ok(Self {
+            exponential: Cow::Owned(symbols.exponential.clone()),
+            superscripting_exponent: Cow::Owned(symbols.superscripting_exponent.clone()),
         })
     }
 }
@@ -109,4 +209,37 @@ fn test_basic() {
 
     assert_eq!(ar_decimal.get().decimal_separator, "٫");
     assert_eq!(ar_decimal.get().digits[0], '٠');
+
+    let ar_decimal: DataPayload<DecimalSymbolsV2Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ar-EG").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(ar_decimal.get().decimal_separator, "٫");
+    assert_eq!(ar_decimal.get().digits[0], '٠');
+    assert_eq!(ar_decimal.get().infinity, "∞");
+    assert_eq!(ar_decimal.get().nan, "ليس\u{a0}رقم");
+}
+
+#[test]
+fn test_scientific() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let ar_scientific: DataPayload<ScientificSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ar-EG").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(ar_scientific.get().exponential, "أس");
+    assert_eq!(ar_scientific.get().superscripting_exponent, "×");
 }
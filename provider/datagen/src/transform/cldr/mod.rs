@@ -17,9 +17,13 @@ pub mod fallback;
 pub mod list;
 pub mod locale_canonicalizer;
 #[cfg(feature = "experimental_components")]
+pub mod numberrange;
+#[cfg(feature = "experimental_components")]
 pub mod percent;
 pub mod plurals;
 #[cfg(feature = "experimental_components")]
+pub mod rbnf;
+#[cfg(feature = "experimental_components")]
 pub mod relativetime;
 pub mod source;
 pub mod time_zones;
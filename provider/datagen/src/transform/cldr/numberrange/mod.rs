@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+
+use icu_experimental::numberrange::provider::*;
+use icu_pattern::{
+    DoublePlaceholder, DoublePlaceholderPattern, Pattern, PatternBackend, PatternItem,
+    PatternItemCow, SinglePlaceholder,
+};
+use icu_provider::prelude::*;
+use icu_provider::DataProvider;
+use tinystr::tinystr;
+
+impl DataProvider<NumberRangePatternsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<NumberRangePatternsV1Marker>, DataError> {
+        self.check_req::<NumberRangePatternsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let numbers_resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        // TODO(#3838): these patterns might be numbering system dependent.
+        let misc_patterns = numbers_resource
+            .main
+            .value
+            .numbers
+            .numsys_data
+            .misc_patterns
+            .get(&tinystr!(8, "latn"))
+            .ok_or_else(|| DataError::custom("Could not find the misc patterns"))?;
+
+        let range = parse_pattern::<DoublePlaceholder>(&misc_patterns.range)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(NumberRangePatternsV1 {
+                range_with_spacing: with_spacing(&range)?,
+                range,
+                approximately: parse_pattern::<SinglePlaceholder>(&misc_patterns.approximately)?,
+                at_least: parse_pattern::<SinglePlaceholder>(&misc_patterns.at_least)?,
+                at_most: parse_pattern::<SinglePlaceholder>(&misc_patterns.at_most)?,
+            })),
+        })
+    }
+}
+
+/// Parses a pattern such as "{0}–{1}" from the CLDR `miscPatterns`.
+fn parse_pattern<'data, B>(pattern: &str) -> Result<Pattern<B, Cow<'data, str>>, DataError>
+where
+    B: PatternBackend<Store = str>,
+    B::PlaceholderKey: FromStr,
+    <B::PlaceholderKey as FromStr>::Err: Debug,
+{
+    let pattern = Pattern::<B, String>::try_from_str(pattern)
+        .map_err(|e| DataError::custom("Could not parse the pattern").with_display_context(&e))?;
+    Ok(Pattern::from_store_unchecked(Cow::Owned(
+        pattern.take_store(),
+    )))
+}
+
+/// Returns the range pattern with spaces around its separator, for numbers that keep a sign
+/// or an affix next to it, such as "-5 – -3".
+///
+/// CLDR has no pattern for these ranges, so like ICU, a space is added on each side of the
+/// separator that does not already have whitespace.
+fn with_spacing<'data>(
+    range: &DoublePlaceholderPattern<Cow<'data, str>>,
+) -> Result<DoublePlaceholderPattern<Cow<'data, str>>, DataError> {
+    let mut placeholders = 0;
+    let pattern =
+        Pattern::<DoublePlaceholder, String>::try_from_items(range.iter().map(|item| match item {
+            PatternItem::Placeholder(key) => {
+                placeholders += 1;
+                PatternItemCow::Placeholder(key)
+            }
+            PatternItem::Literal(s) if placeholders == 1 => {
+                let before = if s.starts_with(char::is_whitespace) {
+                    ""
+                } else {
+                    " "
+                };
+                let after = if s.ends_with(char::is_whitespace) {
+                    ""
+                } else {
+                    " "
+                };
+                PatternItemCow::Literal(Cow::Owned(format!("{before}{s}{after}")))
+            }
+            PatternItem::Literal(s) => PatternItemCow::Literal(Cow::Borrowed(s)),
+        }))
+        .map_err(|e| {
+            DataError::custom("Could not add spacing to the range pattern").with_display_context(&e)
+        })?;
+    Ok(Pattern::from_store_unchecked(Cow::Owned(
+        pattern.take_store(),
+    )))
+}
+
+impl IterableDataProviderInternal<NumberRangePatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+    use writeable::assert_writeable_eq;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let en: DataPayload<NumberRangePatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let en = en.get();
+
+    assert_writeable_eq!(en.range.interpolate(("3", "5")), "3–5");
+    assert_writeable_eq!(en.range_with_spacing.interpolate(("3", "5")), "3 – 5");
+    assert_writeable_eq!(en.approximately.interpolate(("5",)), "~5");
+    assert_writeable_eq!(en.at_least.interpolate(("5",)), "5+");
+    assert_writeable_eq!(en.at_most.interpolate(("5",)), "≤5");
+
+    let ja: DataPayload<NumberRangePatternsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ja").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let ja = ja.get();
+
+    assert_writeable_eq!(ja.range.interpolate(("3", "5")), "3～5");
+    assert_writeable_eq!(ja.range_with_spacing.interpolate(("3", "5")), "3 ～ 5");
+    assert_writeable_eq!(ja.approximately.interpolate(("5",)), "約 5");
+    assert_writeable_eq!(ja.at_least.interpolate(("5",)), "5 以上");
+    assert_writeable_eq!(ja.at_most.interpolate(("5",)), "5 以下");
+}
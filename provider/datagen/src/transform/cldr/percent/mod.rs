@@ -42,6 +42,46 @@ impl IterableDataProviderInternal<PercentEssentialsV1Marker> for crate::DatagenP
     }
 }
 
+impl DataProvider<PermilleSymbolV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<PermilleSymbolV1Marker>, DataError> {
+        self.check_req::<PermilleSymbolV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let numbers_resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        // TODO(#3838): these symbols might be numbering system dependent.
+        let symbols = numbers_resource
+            .main
+            .value
+            .numbers
+            .numsys_data
+            .symbols
+            .get(&tinystr!(8, "latn"))
+            .ok_or_else(|| DataError::custom("Could not find the permille symbol"))?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(PermilleSymbolV1 {
+                permille_sign_symbol: symbols.per_mille.clone().into(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProviderInternal<PermilleSymbolV1Marker> for crate::DatagenProvider {
+    fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
 fn extract_percent_essentials<'data>(
     numbers_resource: &cldr_serde::numbers::Resource,
 ) -> Result<PercentEssentialsV1<'data>, DataError> {
@@ -194,3 +234,21 @@ fn test_basic() {
         "\u{200e}%\u{200e}" // "٪؜"
     );
 }
+
+#[test]
+fn test_permille() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let en: DataPayload<PermilleSymbolV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(en.get().permille_sign_symbol, "‰");
+}
@@ -0,0 +1,242 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::provider::IterableDataProviderInternal;
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::cldr_serde::rbnf::RuleSets;
+use icu_experimental::rbnf::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::str::FromStr;
+use zerovec::ule::UnvalidatedStr;
+
+/// The rule set used by ICU to parse numbers leniently, which is not needed for formatting.
+const LENIENT_PARSE: &str = "%%lenient-parse";
+
+impl crate::DatagenProvider {
+    fn rbnf_resource(
+        &self,
+        langid: &LanguageIdentifier,
+    ) -> Result<&cldr_serde::rbnf::Resource, DataError> {
+        let file_name = if *langid == LanguageIdentifier::UND {
+            "root.json".to_owned()
+        } else {
+            format!("{langid}.json")
+        };
+        self.cldr()?.rbnf().read_and_parse(&file_name)
+    }
+}
+
+fn convert(rule_sets: &RuleSets) -> RuleBasedNumberFormatV1<'static> {
+    RuleBasedNumberFormatV1 {
+        rule_sets: rule_sets
+            .iter()
+            .filter(|(name, _)| *name != LENIENT_PARSE)
+            .map(|(name, rules)| {
+                let rules = rules
+                    .iter()
+                    .map(|(descriptor, text)| format!("{descriptor}: {text}"))
+                    .collect::<String>();
+                (UnvalidatedStr::from_str(name), rules)
+            })
+            .collect(),
+    }
+}
+
+macro_rules! make_data_provider {
+    ($($marker: ident => $grouping: ident),+ $(,)?) => {
+        $(
+            impl DataProvider<$marker> for crate::DatagenProvider {
+                fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    self.check_req::<$marker>(req)?;
+                    let rule_sets = self
+                        .rbnf_resource(&req.locale.get_langid())?
+                        .rbnf
+                        .rbnf
+                        .$grouping
+                        .as_ref()
+                        .ok_or_else(|| DataErrorKind::MissingLocale.with_req($marker::KEY, req))?;
+
+                    Ok(DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(DataPayload::from_owned(convert(rule_sets))),
+                    })
+                }
+            }
+
+            impl IterableDataProviderInternal<$marker> for crate::DatagenProvider {
+                fn supported_locales_impl(&self) -> Result<HashSet<DataLocale>, DataError> {
+                    let mut locales = HashSet::new();
+                    for file_name in self.cldr()?.rbnf().list()? {
+                        let Some(name) = file_name.strip_suffix(".json") else {
+                            continue;
+                        };
+                        let langid = if name == "root" {
+                            LanguageIdentifier::UND
+                        } else {
+                            LanguageIdentifier::from_str(name).map_err(|e| {
+                                DataError::custom("Invalid rbnf file name")
+                                    .with_display_context(&e)
+                            })?
+                        };
+                        if self.rbnf_resource(&langid)?.rbnf.rbnf.$grouping.is_some() {
+                            locales.insert(DataLocale::from(langid));
+                        }
+                    }
+                    Ok(locales)
+                }
+            }
+        )+
+    };
+}
+
+make_data_provider!(
+    SpelloutRulesV1Marker => spellout_rules,
+    OrdinalRulesV1Marker => ordinal_rules,
+);
+
+impl DataProvider<NumberingSystemRulesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<NumberingSystemRulesV1Marker>, DataError> {
+        self.check_req::<NumberingSystemRulesV1Marker>(req)?;
+        let root = self.rbnf_resource(&LanguageIdentifier::UND)?;
+        let rule_sets = root
+            .rbnf
+            .rbnf
+            .numbering_system_rules
+            .as_ref()
+            .ok_or_else(|| DataError::custom("Missing NumberingSystemRules"))?;
+        let numbering_systems: &cldr_serde::numbering_systems::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/numberingSystems.json")?;
+        let rbnf_files = self.cldr()?.rbnf().list()?.collect::<HashSet<_>>();
+
+        let mut systems = BTreeMap::new();
+        for (nu, system) in &numbering_systems.supplemental.numbering_systems {
+            let Some(rules) = &system.rules else {
+                continue;
+            };
+            // Rules are referenced by their name in the root `NumberingSystemRules`, such as
+            // "roman-upper", or as "ja/SpelloutRules/spellout-cardinal".
+            let target = match rules.split('/').collect::<Vec<_>>()[..] {
+                [name] => {
+                    let name = format!("%{name}");
+                    rule_sets.contains_key(&name).then_some(name)
+                }
+                [locale, "SpelloutRules", name] => {
+                    let langid = LanguageIdentifier::from_str(locale).map_err(|e| {
+                        DataError::custom("Invalid numbering system rules").with_display_context(&e)
+                    })?;
+                    let name = format!("%{name}");
+                    let has_rule_set = rbnf_files.contains(&format!("{langid}.json"))
+                        && self
+                            .rbnf_resource(&langid)?
+                            .rbnf
+                            .rbnf
+                            .spellout_rules
+                            .as_ref()
+                            .map_or(false, |rule_sets| rule_sets.contains_key(&name));
+                    has_rule_set.then(|| format!("{langid}/{name}"))
+                }
+                _ => {
+                    return Err(DataError::custom("Invalid numbering system rules")
+                        .with_display_context(rules))
+                }
+            };
+            // Rule sets missing from the CLDR data are skipped, so that the numbering system is
+            // formatted with digits.
+            if let Some(target) = target {
+                systems.insert(nu.to_unvalidated(), target);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(NumberingSystemRulesV1 {
+                systems: systems
+                    .iter()
+                    .map(|(nu, target)| (nu, target.as_str()))
+                    .collect(),
+                rules: convert(rule_sets),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<NumberingSystemRulesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::new_testing();
+
+    let en: DataPayload<SpelloutRulesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let rule_sets = &en.get().rule_sets;
+
+    assert_eq!(
+        rule_sets.get(UnvalidatedStr::from_str("%%tieth")),
+        Some("0: ieth;1: y-=%spellout-ordinal=;")
+    );
+    assert_eq!(rule_sets.get(UnvalidatedStr::from_str(LENIENT_PARSE)), None);
+
+    let und: DataPayload<OrdinalRulesV1Marker> = provider
+        .load(DataRequest {
+            locale: &Default::default(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        und.get()
+            .rule_sets
+            .get(UnvalidatedStr::from_str("%digits-ordinal")),
+        Some("-x: −→→;0: =#,##0=;")
+    );
+
+    let numbering: DataPayload<NumberingSystemRulesV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let systems = &numbering.get().systems;
+
+    assert_eq!(
+        systems.get(&tinystr::tinystr!(8, "roman").to_unvalidated()),
+        Some("%roman-upper")
+    );
+    assert_eq!(
+        systems.get(&tinystr::tinystr!(8, "jpan").to_unvalidated()),
+        Some("ja/%spellout-cardinal")
+    );
+    // There are no rules for Greek numerals in the test data.
+    assert_eq!(
+        systems.get(&tinystr::tinystr!(8, "grek").to_unvalidated()),
+        None
+    );
+    // Numeric numbering systems have no rules.
+    assert_eq!(
+        systems.get(&tinystr::tinystr!(8, "latn").to_unvalidated()),
+        None
+    );
+}
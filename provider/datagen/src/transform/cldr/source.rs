@@ -64,6 +64,10 @@ impl CldrCache {
         CldrDirNoLang(self, "cldr-bcp47/bcp47".to_string())
     }
 
+    pub fn rbnf(&self) -> CldrDirNoLang<'_> {
+        CldrDirNoLang(self, "cldr-rbnf/rbnf".to_owned())
+    }
+
     pub fn displaynames(&self) -> CldrDirLang<'_> {
         CldrDirLang(self, "cldr-localenames".to_owned())
     }
@@ -223,6 +227,10 @@ impl<'a> CldrDirNoLang<'a> {
             .serde_cache
             .read_and_parse_json(&format!("{}/{}", self.1, file_name))
     }
+
+    pub fn list(&self) -> Result<impl Iterator<Item = String>, DataError> {
+        self.0.serde_cache.list(&self.1)
+    }
 }
 
 pub(crate) struct CldrDirLang<'a>(&'a CldrCache, String);
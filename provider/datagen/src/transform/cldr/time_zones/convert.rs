@@ -89,7 +89,7 @@ pub(crate) fn compute_canonical_tzids_btreemap(
 /// Returns a map from metazone long identifier to metazone BCP-47 ID.
 ///
 /// For example: "America_Central" to "amce"
-fn compute_meta_zone_ids_btreemap(
+pub(crate) fn compute_meta_zone_ids_btreemap(
     meta_zone_ids_resource: &BTreeMap<MetazoneId, MetazoneAliasData>,
 ) -> BTreeMap<String, MetazoneId> {
     let mut meta_zone_ids = BTreeMap::new();
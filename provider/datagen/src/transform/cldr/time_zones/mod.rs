@@ -17,6 +17,8 @@ use std::collections::HashSet;
 
 mod convert;
 mod names;
+mod regions;
+mod windows;
 
 #[derive(Debug, Copy, Clone)]
 struct CldrTimeZonesData<'a> {
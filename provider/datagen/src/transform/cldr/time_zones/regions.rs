@@ -0,0 +1,265 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::convert::{compute_bcp47_tzids_btreemap, compute_meta_zone_ids_btreemap};
+use crate::transform::cldr::cldr_serde;
+use icu_locid::subtags::{region, Region};
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_timezone::provider::*;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use zerovec::ZeroSlice;
+
+impl DataProvider<RegionTimeZonesV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<RegionTimeZonesV1Marker>, DataError> {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+        let bcp47_tzids_resource = &resource.keyword.u.time_zones.values;
+        let iana2bcp = compute_bcp47_tzids_btreemap(bcp47_tzids_resource);
+
+        let resource: &cldr_serde::time_zones::windows_zones::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/windowsZones.json")?;
+
+        // windowsZones.json lists the regions in which each IANA time zone is used.
+        let mut bcp2regions = BTreeMap::<TimeZoneBcp47Id, BTreeSet<Region>>::new();
+        for map_zone in resource
+            .supplemental
+            .windows_zones
+            .map_timezones
+            .iter()
+            .map(|m| &m.map_zone)
+        {
+            let region = map_zone.territory.parse::<Region>().map_err(|_| {
+                DataError::custom("Invalid region in windowsZones.json")
+                    .with_display_context(&map_zone.territory)
+            })?;
+            for iana in map_zone.zone_type.split(' ') {
+                let bcp47 = *iana2bcp.get(iana).ok_or_else(|| {
+                    DataError::custom("Unknown IANA time zone in windowsZones.json")
+                        .with_display_context(iana)
+                })?;
+                let regions = bcp2regions.entry(bcp47).or_default();
+                // "001" is the default for the Windows time zone, and "ZZ" is used for
+                // zones that don't belong to any region, like "Etc/GMT+5".
+                if region != region!("001") && region != region!("ZZ") {
+                    regions.insert(region);
+                }
+            }
+        }
+
+        // metaZones.json lists the primary zone of a metazone in a region, which
+        // covers some zones that are missing from windowsZones.json.
+        let resource: &cldr_serde::time_zones::meta_zones::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/metaZones.json")?;
+        for map_zone in resource
+            .supplemental
+            .meta_zones
+            .meta_zones_territory
+            .0
+            .iter()
+            .map(|m| &m.map_zone)
+        {
+            let region = map_zone.territory.parse::<Region>().map_err(|_| {
+                DataError::custom("Invalid region in metaZones.json")
+                    .with_display_context(&map_zone.territory)
+            })?;
+            if region == region!("001") {
+                continue;
+            }
+            let bcp47 = *iana2bcp.get(&map_zone.zone_type).ok_or_else(|| {
+                DataError::custom("Unknown IANA time zone in metaZones.json")
+                    .with_display_context(&map_zone.zone_type)
+            })?;
+            bcp2regions.entry(bcp47).or_default().insert(region);
+        }
+
+        let mut region2bcp = BTreeMap::<Region, Vec<TimeZoneBcp47Id>>::new();
+        for (bcp47, data) in bcp47_tzids_resource {
+            if data.deprecated == Some(true) || data.alias.is_none() {
+                continue;
+            }
+            match bcp2regions.get(bcp47) {
+                Some(regions) => {
+                    for region in regions {
+                        region2bcp.entry(*region).or_default().push(*bcp47);
+                    }
+                }
+                // Zones without territory data in either file, like Ciudad Juárez, fall back
+                // to the region of their ID. Five-letter IDs are UN/LOCODEs, which start with
+                // the region of the city. Other IDs, like "utce01" or "est5edt", have no region.
+                None => {
+                    if let Some(region) = locode_region(bcp47) {
+                        region2bcp.entry(region).or_default().push(*bcp47);
+                    }
+                }
+            }
+        }
+
+        let data_struct = RegionTimeZonesV1(
+            region2bcp
+                .into_iter()
+                .map(|(region, zones)| {
+                    (
+                        region.into_tinystr().to_unvalidated(),
+                        ZeroSlice::from_boxed_slice(zones.into_boxed_slice()),
+                    )
+                })
+                .collect(),
+        );
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+/// Returns the region of a BCP-47 time zone ID that is a UN/LOCODE, such as `MX` for `mxcjs`.
+fn locode_region(bcp47: &TimeZoneBcp47Id) -> Option<Region> {
+    if bcp47.0.len() != 5 {
+        return None;
+    }
+    Region::try_from_bytes(bcp47.0.as_bytes().get(..2)?.to_ascii_uppercase().as_slice()).ok()
+}
+
+impl IterableDataProvider<RegionTimeZonesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<MetazonePrimaryZonesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _: DataRequest,
+    ) -> Result<DataResponse<MetazonePrimaryZonesV1Marker>, DataError> {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+        let iana2bcp = compute_bcp47_tzids_btreemap(&resource.keyword.u.time_zones.values);
+
+        let resource: &cldr_serde::time_zones::meta_zones::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/metaZones.json")?;
+        let meta_zone_ids =
+            compute_meta_zone_ids_btreemap(&resource.supplemental.meta_zones.meta_zone_ids.0);
+
+        let mut primary_zones = BTreeMap::new();
+        for map_zone in resource
+            .supplemental
+            .meta_zones
+            .meta_zones_territory
+            .0
+            .iter()
+            .map(|m| &m.map_zone)
+        {
+            let metazone = match meta_zone_ids.get(&map_zone.other) {
+                Some(metazone) => *metazone,
+                // Historical metazones without an ID are not used by ICU4X
+                None => continue,
+            };
+            let region = map_zone.territory.parse::<Region>().map_err(|_| {
+                DataError::custom("Invalid region in metaZones.json")
+                    .with_display_context(&map_zone.territory)
+            })?;
+            let bcp47 = iana2bcp.get(&map_zone.zone_type).ok_or_else(|| {
+                DataError::custom("Unknown IANA time zone in metaZones.json")
+                    .with_display_context(&map_zone.zone_type)
+            })?;
+            primary_zones.insert((metazone, region), *bcp47);
+        }
+
+        let data_struct = MetazonePrimaryZonesV1(
+            primary_zones
+                .into_iter()
+                .map(|((metazone, region), bcp47)| {
+                    (metazone, region.into_tinystr().to_unvalidated(), bcp47)
+                })
+                .collect(),
+        );
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<MetazonePrimaryZonesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    #[test]
+    fn region_and_primary_zones() {
+        let provider = crate::DatagenProvider::new_testing();
+
+        let region_zones: DataPayload<RegionTimeZonesV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let zones = |region: Region| {
+            region_zones
+                .get()
+                .0
+                .get(&region.into_tinystr().to_unvalidated())
+                .unwrap()
+                .iter()
+                .map(|id| id.0)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(zones(region!("JP")), [tinystr!(8, "jptyo")]);
+        assert_eq!(
+            zones(region!("PS")),
+            [tinystr!(8, "gazastrp"), tinystr!(8, "hebron")]
+        );
+        // Ciudad Juárez has no territory data in windowsZones.json or metaZones.json,
+        // so its region comes from its UN/LOCODE
+        assert!(zones(region!("MX")).contains(&tinystr!(8, "mxcjs")));
+        assert!(zones(region!("AQ")).contains(&tinystr!(8, "aqtrl")));
+        // Curaçao is in CW even though its UN/LOCODE is in AN
+        assert_eq!(zones(region!("CW")), [tinystr!(8, "ancur")]);
+        // Etc zones are not in any region
+        assert!(region_zones
+            .get()
+            .0
+            .get(&tinystr!(3, "ZZ").to_unvalidated())
+            .is_none());
+
+        let primary_zones: DataPayload<MetazonePrimaryZonesV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let primary_zone = |metazone: &str, region: Region| {
+            primary_zones
+                .get()
+                .0
+                .get_2d(
+                    &metazone.parse().unwrap(),
+                    &region.into_tinystr().to_unvalidated(),
+                )
+                .map(|id| id.0)
+        };
+        assert_eq!(
+            primary_zone("ampa", region!("001")),
+            Some(tinystr!(8, "uslax"))
+        );
+        assert_eq!(
+            primary_zone("ampa", region!("CA")),
+            Some(tinystr!(8, "cavan"))
+        );
+        assert_eq!(primary_zone("ampa", region!("US")), None);
+    }
+}
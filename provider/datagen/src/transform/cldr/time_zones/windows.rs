@@ -0,0 +1,205 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::convert::compute_bcp47_tzids_btreemap;
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_timezone::provider::names::*;
+use icu_timezone::TimeZoneBcp47Id;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use zerotrie::ZeroTrieSimpleAscii;
+use zerovec::ZeroVec;
+
+impl crate::DatagenProvider {
+    /// Returns a map from (Windows time zone ID, territory) to BCP-47 ID.
+    ///
+    /// The first IANA ID listed for an entry determines the BCP-47 ID for that territory,
+    /// but all listed IANA IDs map back to the Windows time zone ID.
+    #[allow(clippy::type_complexity)] // a pair of maps
+    fn windows_zones(
+        &self,
+    ) -> Result<
+        (
+            BTreeMap<(String, String), TimeZoneBcp47Id>,
+            BTreeMap<TimeZoneBcp47Id, String>,
+        ),
+        DataError,
+    > {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+        let iana2bcp = compute_bcp47_tzids_btreemap(&resource.keyword.u.time_zones.values);
+
+        let resource: &cldr_serde::time_zones::windows_zones::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/windowsZones.json")?;
+
+        let mut windows2bcp = BTreeMap::new();
+        let mut bcp2windows = BTreeMap::new();
+        for map_zone in resource
+            .supplemental
+            .windows_zones
+            .map_timezones
+            .iter()
+            .map(|m| &m.map_zone)
+        {
+            for (i, iana) in map_zone.zone_type.split(' ').enumerate() {
+                let bcp47 = *iana2bcp.get(iana).ok_or_else(|| {
+                    DataError::custom("Unknown IANA time zone in windowsZones.json")
+                        .with_display_context(iana)
+                })?;
+                if i == 0 {
+                    windows2bcp.insert(
+                        (map_zone.other.clone(), map_zone.territory.clone()),
+                        bcp47,
+                    );
+                }
+                if let Some(other) = bcp2windows.insert(bcp47, map_zone.other.clone()) {
+                    if other != map_zone.other {
+                        return Err(DataError::custom(
+                            "BCP-47 time zone maps to multiple Windows time zones",
+                        )
+                        .with_debug_context(&bcp47));
+                    }
+                }
+            }
+        }
+        Ok((windows2bcp, bcp2windows))
+    }
+}
+
+impl DataProvider<WindowsToBcp47MapV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<WindowsToBcp47MapV1Marker>, DataError> {
+        let (windows2bcp, _) = self.windows_zones()?;
+
+        // Sort and deduplicate the BCP-47 IDs:
+        let bcp_set: BTreeSet<TimeZoneBcp47Id> = windows2bcp.values().copied().collect();
+        let bcp47_ids: ZeroVec<TimeZoneBcp47Id> = bcp_set.iter().copied().collect();
+
+        // Transform the map to use BCP indices:
+        #[allow(clippy::unwrap_used)] // structures are derived from each other
+        let map: BTreeMap<Vec<u8>, usize> = windows2bcp
+            .iter()
+            .map(|((windows, territory), bcp)| {
+                (
+                    format!("{windows}/{territory}").into_bytes(),
+                    bcp47_ids.binary_search(bcp).unwrap(),
+                )
+            })
+            .collect();
+
+        let data_struct = WindowsToBcp47MapV1 {
+            map: ZeroTrieSimpleAscii::try_from(&map)
+                .map_err(|e| {
+                    DataError::custom("Could not create ZeroTrie from windowsZones.json data")
+                        .with_display_context(&e)
+                })?
+                .convert_store(),
+            bcp47_ids,
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<WindowsToBcp47MapV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<Bcp47ToWindowsMapV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<Bcp47ToWindowsMapV1Marker>, DataError> {
+        let (_, bcp2windows) = self.windows_zones()?;
+
+        // Sort and deduplicate the Windows IDs:
+        let windows_set: BTreeSet<&str> = bcp2windows.values().map(String::as_str).collect();
+        let windows_vec: Vec<&str> = windows_set.into_iter().collect();
+
+        let bcp47_ids: ZeroVec<TimeZoneBcp47Id> = bcp2windows.keys().copied().collect();
+        #[allow(clippy::unwrap_used)] // structures are derived from each other
+        let windows_indices = bcp2windows
+            .values()
+            .map(|windows| {
+                u16::try_from(windows_vec.binary_search(&windows.as_str()).unwrap())
+                    .map_err(|_| DataError::custom("Too many Windows time zones"))
+            })
+            .collect::<Result<ZeroVec<u16>, DataError>>()?;
+
+        let data_struct = Bcp47ToWindowsMapV1 {
+            bcp47_ids,
+            windows_indices,
+            windows_ids: windows_vec.as_slice().into(),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<Bcp47ToWindowsMapV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    #[test]
+    fn windows_zones_round_trip() {
+        let provider = crate::DatagenProvider::new_testing();
+
+        let windows2bcp: DataPayload<WindowsToBcp47MapV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let bcp2windows: DataPayload<Bcp47ToWindowsMapV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let lookup = |key: &str| {
+            windows2bcp
+                .get()
+                .bcp47_ids
+                .get(windows2bcp.get().map.get(key).unwrap())
+                .unwrap()
+        };
+        assert_eq!(
+            lookup("Eastern Standard Time/001"),
+            TimeZoneBcp47Id(tinystr!(8, "usnyc"))
+        );
+        assert_eq!(
+            lookup("Eastern Standard Time/CA"),
+            TimeZoneBcp47Id(tinystr!(8, "cator"))
+        );
+
+        // Every BCP-47 ID in the forward map maps back to a Windows time zone
+        for bcp47 in windows2bcp.get().bcp47_ids.iter() {
+            assert!(bcp2windows.get().bcp47_ids.binary_search(&bcp47).is_ok());
+        }
+
+        // Secondary zones in a territory map back, too
+        let index = bcp2windows
+            .get()
+            .bcp47_ids
+            .binary_search(&TimeZoneBcp47Id(tinystr!(8, "usdet")))
+            .unwrap();
+        let windows_index = bcp2windows.get().windows_indices.get(index).unwrap();
+        assert_eq!(
+            bcp2windows.get().windows_ids.get(windows_index as usize),
+            Some("Eastern Standard Time")
+        );
+    }
+}
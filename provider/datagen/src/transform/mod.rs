@@ -5,6 +5,8 @@
 pub mod cldr;
 pub mod icuexport;
 pub mod segmenter;
+#[cfg(feature = "tzdb")]
+pub mod tzdb;
 
 use crate::DatagenProvider;
 use icu_provider::datagen::*;
@@ -0,0 +1,214 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by the TZif files of the IANA
+//! time zone database.
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_timezone::provider::*;
+use std::collections::BTreeMap;
+use tzif::data::posix::{PosixTzString, TransitionDate, TransitionDay};
+use tzif::data::time::Seconds;
+use tzif::data::tzif::{LocalTimeTypeRecord, TzifData};
+
+impl DataProvider<ZoneOffsetPeriodV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<ZoneOffsetPeriodV1Marker>, DataError> {
+        let tzdb = self.tzdb()?;
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.cldr()?.bcp47().read_and_parse("timezone.json")?;
+
+        let mut transitions = BTreeMap::new();
+        let mut rules = BTreeMap::new();
+        for (&bcp47, data) in resource.keyword.u.time_zones.values.iter() {
+            if data.deprecated == Some(true) {
+                continue;
+            }
+            // The IANA names in CLDR are in canonical order, but older TZDB versions might not
+            // have a file for the canonical name.
+            let iana = data
+                .iana
+                .iter()
+                .map(String::as_str)
+                .chain(data.alias.iter().flat_map(|alias| alias.split(' ')))
+                .find(|iana| tzdb.file_exists(iana).unwrap_or(false));
+            let iana = match iana {
+                Some(iana) => iana,
+                None => {
+                    log::debug!("No TZif file for time zone {bcp47:?}");
+                    continue;
+                }
+            };
+            let tzif = tzdb.read_and_parse_tzif(iana)?;
+
+            for (time, offset) in compute_transitions(tzif)? {
+                transitions.insert((bcp47, time), offset);
+            }
+            if let Some(footer) = &tzif.footer {
+                if let Some(rule) = convert_rule(footer)? {
+                    rules.insert(bcp47, rule);
+                }
+            }
+        }
+
+        let data_struct = ZoneOffsetPeriodV1 {
+            transitions: transitions
+                .iter()
+                .map(|((bcp47, time), offset)| (bcp47, time, offset))
+                .collect(),
+            rules: rules.into_iter().collect(),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<ZoneOffsetPeriodV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+fn convert_offset(record: &LocalTimeTypeRecord) -> Result<ZoneOffset, DataError> {
+    Ok(ZoneOffset {
+        offset_seconds: convert_seconds(record.utoff)?,
+        is_dst: record.is_dst,
+    })
+}
+
+fn convert_seconds(seconds: Seconds) -> Result<i32, DataError> {
+    i32::try_from(seconds.0)
+        .map_err(|_| DataError::custom("TZif offset out of range").with_debug_context(&seconds))
+}
+
+/// Returns the times from which each offset is used, skipping transitions that only change
+/// the time zone designation.
+fn compute_transitions(tzif: &TzifData) -> Result<Vec<(i64, ZoneOffset)>, DataError> {
+    let block = tzif.data_block2.as_ref().unwrap_or(&tzif.data_block1);
+    let initial = block
+        .local_time_type_records
+        .first()
+        .ok_or_else(|| DataError::custom("TZif without local time types"))?;
+
+    let mut transitions = vec![(i64::MIN, convert_offset(initial)?)];
+    for (time, &ty) in block.transition_times.iter().zip(&block.transition_types) {
+        let record = block
+            .local_time_type_records
+            .get(ty)
+            .ok_or_else(|| DataError::custom("Invalid TZif transition type"))?;
+        let offset = convert_offset(record)?;
+        if transitions.last().map(|&(_, last)| last) != Some(offset) {
+            transitions.push((time.0, offset));
+        }
+    }
+    Ok(transitions)
+}
+
+/// Returns the daylight saving time rule of a TZif footer, if it has one.
+fn convert_rule(posix: &PosixTzString) -> Result<Option<ZoneRule>, DataError> {
+    let dst = match &posix.dst_info {
+        Some(dst) => dst,
+        None => return Ok(None),
+    };
+    // POSIX offsets are positive west of Greenwich.
+    Ok(Some(ZoneRule {
+        std_offset_seconds: -convert_seconds(posix.std_info.offset)?,
+        dst_offset_seconds: -convert_seconds(dst.variant_info.offset)?,
+        start: convert_transition_date(&dst.start_date)?,
+        end: convert_transition_date(&dst.end_date)?,
+    }))
+}
+
+fn convert_transition_date(date: &TransitionDate) -> Result<ZoneTransitionRule, DataError> {
+    let (month, week, day) = match date.day {
+        TransitionDay::Mwd(month, week, day) => (month as u8, week as u8, day),
+        TransitionDay::NoLeap(day) => (0, 0, day),
+        TransitionDay::WithLeap(day) => (0, 1, day),
+    };
+    Ok(ZoneTransitionRule {
+        month,
+        week,
+        day,
+        time_seconds: convert_seconds(date.time)?,
+    })
+}
+
+#[test]
+fn test_basic() {
+    use icu_timezone::TimeZoneBcp47Id;
+    use tinystr::tinystr;
+    use zerovec::ule::AsULE;
+
+    let provider = crate::DatagenProvider::new_testing();
+    let offsets: DataPayload<ZoneOffsetPeriodV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let bogota = TimeZoneBcp47Id(tinystr!(8, "cobog"));
+    let los_angeles = TimeZoneBcp47Id(tinystr!(8, "uslax"));
+    let tokyo = TimeZoneBcp47Id(tinystr!(8, "jptyo"));
+
+    let offset = |id: &TimeZoneBcp47Id, time: i64| {
+        let (_, offset) = offsets
+            .get()
+            .transitions
+            .get0(id)
+            .unwrap()
+            .iter1_copied()
+            .map(|(start, offset)| (i64::from_unaligned(*start), offset))
+            .take_while(|&(start, _)| start <= time)
+            .last()
+            .unwrap();
+        (offset.offset_seconds, offset.is_dst)
+    };
+
+    // Local mean time before the first transition
+    assert_eq!(offset(&los_angeles, -3_000_000_000), (-28378, false));
+    assert_eq!(offset(&los_angeles, 0), (-28800, false));
+    // 2000-07-01T00:00:00Z
+    assert_eq!(offset(&los_angeles, 962409600), (-25200, true));
+    // 2024-07-01T00:00:00Z
+    assert_eq!(offset(&bogota, 1719792000), (-18000, false));
+    assert_eq!(offset(&tokyo, 1719792000), (32400, false));
+
+    assert_eq!(
+        offsets.get().rules.get_copied(&los_angeles),
+        Some(ZoneRule {
+            std_offset_seconds: -28800,
+            dst_offset_seconds: -25200,
+            start: ZoneTransitionRule {
+                month: 3,
+                week: 2,
+                day: 0,
+                time_seconds: 7200,
+            },
+            end: ZoneTransitionRule {
+                month: 11,
+                week: 1,
+                day: 0,
+                time_seconds: 7200,
+            },
+        })
+    );
+    assert_eq!(offsets.get().rules.get_copied(&bogota), None);
+
+    // The test data for Los Angeles ends in 2007, so later offsets come from the rule
+    let calculator = icu_timezone::ZoneOffsetCalculator::try_new_unstable(&provider).unwrap();
+    let offset = |id, datetime: &icu_calendar::DateTime<icu_calendar::Iso>| {
+        let offset = calculator
+            .compute_offset_from_time_zone(id, datetime)
+            .unwrap();
+        (offset.offset_seconds, offset.is_dst)
+    };
+    let datetime = icu_calendar::DateTime::try_new_iso_datetime(2024, 7, 1, 0, 0, 0).unwrap();
+    assert_eq!(offset(los_angeles, &datetime), (-25200, true));
+    assert_eq!(offset(bogota, &datetime), (-18000, false));
+    let datetime = icu_calendar::DateTime::try_new_iso_datetime(2024, 12, 1, 0, 0, 0).unwrap();
+    assert_eq!(offset(los_angeles, &datetime), (-28800, false));
+}
@@ -86,11 +86,31 @@ mod compactdecimal_short_v1;
 #[doc(inline)]
 pub use __impl_compactdecimal_short_v1 as impl_compactdecimal_short_v1;
 #[macro_use]
+#[path = "macros/currency_accounting_v1.rs.data"]
+mod currency_accounting_v1;
+#[doc(inline)]
+pub use __impl_currency_accounting_v1 as impl_currency_accounting_v1;
+#[macro_use]
+#[path = "macros/currency_compact_v1.rs.data"]
+mod currency_compact_v1;
+#[doc(inline)]
+pub use __impl_currency_compact_v1 as impl_currency_compact_v1;
+#[macro_use]
 #[path = "macros/currency_essentials_v1.rs.data"]
 mod currency_essentials_v1;
 #[doc(inline)]
 pub use __impl_currency_essentials_v1 as impl_currency_essentials_v1;
 #[macro_use]
+#[path = "macros/currency_fractions_v1.rs.data"]
+mod currency_fractions_v1;
+#[doc(inline)]
+pub use __impl_currency_fractions_v1 as impl_currency_fractions_v1;
+#[macro_use]
+#[path = "macros/currency_long_names_v1.rs.data"]
+mod currency_long_names_v1;
+#[doc(inline)]
+pub use __impl_currency_long_names_v1 as impl_currency_long_names_v1;
+#[macro_use]
 #[path = "macros/datetime_buddhist_datelengths_v1.rs.data"]
 mod datetime_buddhist_datelengths_v1;
 #[doc(inline)]
@@ -461,11 +481,21 @@ mod datetime_week_data_v2;
 #[doc(inline)]
 pub use __impl_datetime_week_data_v2 as impl_datetime_week_data_v2;
 #[macro_use]
+#[path = "macros/decimal_scientific_v1.rs.data"]
+mod decimal_scientific_v1;
+#[doc(inline)]
+pub use __impl_decimal_scientific_v1 as impl_decimal_scientific_v1;
+#[macro_use]
 #[path = "macros/decimal_symbols_v1.rs.data"]
 mod decimal_symbols_v1;
 #[doc(inline)]
 pub use __impl_decimal_symbols_v1 as impl_decimal_symbols_v1;
 #[macro_use]
+#[path = "macros/decimal_symbols_v2.rs.data"]
+mod decimal_symbols_v2;
+#[doc(inline)]
+pub use __impl_decimal_symbols_v2 as impl_decimal_symbols_v2;
+#[macro_use]
 #[path = "macros/displaynames_languages_v1.rs.data"]
 mod displaynames_languages_v1;
 #[doc(inline)]
@@ -591,11 +621,21 @@ mod normalizer_uts46d_v1;
 #[doc(inline)]
 pub use __impl_normalizer_uts46d_v1 as impl_normalizer_uts46d_v1;
 #[macro_use]
+#[path = "macros/numberrange_patterns_v1.rs.data"]
+mod numberrange_patterns_v1;
+#[doc(inline)]
+pub use __impl_numberrange_patterns_v1 as impl_numberrange_patterns_v1;
+#[macro_use]
 #[path = "macros/percent_essentials_v1.rs.data"]
 mod percent_essentials_v1;
 #[doc(inline)]
 pub use __impl_percent_essentials_v1 as impl_percent_essentials_v1;
 #[macro_use]
+#[path = "macros/percent_permille_v1.rs.data"]
+mod percent_permille_v1;
+#[doc(inline)]
+pub use __impl_percent_permille_v1 as impl_percent_permille_v1;
+#[macro_use]
 #[path = "macros/plurals_cardinal_v1.rs.data"]
 mod plurals_cardinal_v1;
 #[doc(inline)]
@@ -1211,6 +1251,21 @@ mod props_xdigit_v1;
 #[doc(inline)]
 pub use __impl_props_xdigit_v1 as impl_props_xdigit_v1;
 #[macro_use]
+#[path = "macros/rbnf_numbering_v1.rs.data"]
+mod rbnf_numbering_v1;
+#[doc(inline)]
+pub use __impl_rbnf_numbering_v1 as impl_rbnf_numbering_v1;
+#[macro_use]
+#[path = "macros/rbnf_ordinal_v1.rs.data"]
+mod rbnf_ordinal_v1;
+#[doc(inline)]
+pub use __impl_rbnf_ordinal_v1 as impl_rbnf_ordinal_v1;
+#[macro_use]
+#[path = "macros/rbnf_spellout_v1.rs.data"]
+mod rbnf_spellout_v1;
+#[doc(inline)]
+pub use __impl_rbnf_spellout_v1 as impl_rbnf_spellout_v1;
+#[macro_use]
 #[path = "macros/relativetime_long_day_v1.rs.data"]
 mod relativetime_long_day_v1;
 #[doc(inline)]
@@ -1371,6 +1426,11 @@ mod time_zone_bcp47_to_iana_v1;
 #[doc(inline)]
 pub use __impl_time_zone_bcp47_to_iana_v1 as impl_time_zone_bcp47_to_iana_v1;
 #[macro_use]
+#[path = "macros/time_zone_bcp47_to_windows_v1.rs.data"]
+mod time_zone_bcp47_to_windows_v1;
+#[doc(inline)]
+pub use __impl_time_zone_bcp47_to_windows_v1 as impl_time_zone_bcp47_to_windows_v1;
+#[macro_use]
 #[path = "macros/time_zone_exemplar_cities_v1.rs.data"]
 mod time_zone_exemplar_cities_v1;
 #[doc(inline)]
@@ -1406,6 +1466,21 @@ mod time_zone_metazone_period_v1;
 #[doc(inline)]
 pub use __impl_time_zone_metazone_period_v1 as impl_time_zone_metazone_period_v1;
 #[macro_use]
+#[path = "macros/time_zone_metazone_primary_zones_v1.rs.data"]
+mod time_zone_metazone_primary_zones_v1;
+#[doc(inline)]
+pub use __impl_time_zone_metazone_primary_zones_v1 as impl_time_zone_metazone_primary_zones_v1;
+#[macro_use]
+#[path = "macros/time_zone_offset_period_v1.rs.data"]
+mod time_zone_offset_period_v1;
+#[doc(inline)]
+pub use __impl_time_zone_offset_period_v1 as impl_time_zone_offset_period_v1;
+#[macro_use]
+#[path = "macros/time_zone_region_zones_v1.rs.data"]
+mod time_zone_region_zones_v1;
+#[doc(inline)]
+pub use __impl_time_zone_region_zones_v1 as impl_time_zone_region_zones_v1;
+#[macro_use]
 #[path = "macros/time_zone_specific_long_v1.rs.data"]
 mod time_zone_specific_long_v1;
 #[doc(inline)]
@@ -1416,6 +1491,11 @@ mod time_zone_specific_short_v1;
 #[doc(inline)]
 pub use __impl_time_zone_specific_short_v1 as impl_time_zone_specific_short_v1;
 #[macro_use]
+#[path = "macros/time_zone_windows_to_bcp47_v1.rs.data"]
+mod time_zone_windows_to_bcp47_v1;
+#[doc(inline)]
+pub use __impl_time_zone_windows_to_bcp47_v1 as impl_time_zone_windows_to_bcp47_v1;
+#[macro_use]
 #[path = "macros/transliterator_rules_v1.rs.data"]
 mod transliterator_rules_v1;
 #[doc(inline)]
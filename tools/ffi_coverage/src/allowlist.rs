@@ -253,6 +253,50 @@ lazy_static::lazy_static! {
         "icu::plurals::PluralRulesWithRanges::try_new_cardinal",
         "icu::plurals::PluralRulesWithRanges::try_new_ordinal",
 
+        // Not planned until someone needs them
+        "fixed_decimal::ExtendedDecimal",
+        "fixed_decimal::FixedDecimal::add",
+        "fixed_decimal::FixedDecimal::add_assign",
+        "fixed_decimal::FixedDecimal::checked_add",
+        "fixed_decimal::FixedDecimal::checked_mul",
+        "fixed_decimal::FixedDecimal::checked_sub",
+        "fixed_decimal::FixedDecimal::mul",
+        "fixed_decimal::FixedDecimal::mul_assign",
+        "fixed_decimal::FixedDecimal::sub",
+        "fixed_decimal::FixedDecimal::sub_assign",
+        "icu::calendar::Date::to_ixdtf",
+        "icu::calendar::Date::try_from_ixdtf",
+        "icu::calendar::Date::try_from_str",
+        "icu::calendar::DateTime::to_ixdtf",
+        "icu::calendar::DateTime::try_from_ixdtf",
+        "icu::calendar::DateTime::try_from_str",
+        "icu::calendar::ixdtf",
+        "icu::decimal::FixedDecimalFormatter::format_extended",
+        "icu::decimal::FixedDecimalFormatter::format_extended_to_string",
+        "icu::decimal::FormattedExtendedDecimal",
+        "icu::decimal::FormattedFixedDecimal::value",
+        "icu::decimal::FormattedScientificDecimal",
+        "icu::decimal::ScientificDecimalFormatter",
+        "icu::decimal::options::ExponentStyle",
+        "icu::decimal::options::RoundingIncrement",
+        "icu::decimal::options::RoundingMode",
+        "icu::decimal::options::RoundingPriority",
+        "icu::decimal::options::ScientificDecimalFormatterOptions",
+        "icu::decimal::options::ScientificNotation",
+        "icu::datetime::time_zone::TimeZoneNames",
+        "icu::datetime::time_zone::TimeZoneParser",
+        "icu::timezone::CustomTimeZone::try_from_ixdtf",
+        "icu::timezone::CustomTimeZone::try_from_str",
+        "icu::timezone::IanaBcp47RoundTripMapperBorrowed",
+        "icu::timezone::IanaToBcp47MapperBorrowed",
+        "icu::timezone::ixdtf",
+        "icu::timezone::RegionTimeZoneMapper",
+        "icu::timezone::WindowsBcp47RoundTripMapper",
+        "icu::timezone::WindowsBcp47RoundTripMapperBorrowed",
+        "icu::timezone::WindowsToBcp47Mapper",
+        "icu::timezone::WindowsToBcp47MapperBorrowed",
+        "icu::timezone::ZoneOffsetCalculator",
+
         // Not planned for 2.0
         // We aren't exposing these collections directly, we instead expose them in a domain specific
         // way like CodePointSetDataBuilder. We may eventually add these as utilities for users.
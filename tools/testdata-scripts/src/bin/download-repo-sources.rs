@@ -193,6 +193,17 @@ fn main() -> eyre::Result<()> {
         &mut Default::default(),
     )?;
 
+    // There are no release artifacts of compiled TZif files, so we use the ones of the `tzif` crate
+    std::fs::remove_dir_all(out_root.join("tests/data/tzdb"))?;
+    for path in TZDB_GLOB {
+        let target = out_root.join("tests/data/tzdb").join(path);
+        std::fs::create_dir_all(target.parent().unwrap())?;
+        std::fs::copy(
+            out_root.join("../../utils/tzif/testdata").join(path),
+            target,
+        )?;
+    }
+
     let cldr_data = cldr_data
         .iter()
         .map(|path| {
@@ -217,6 +228,13 @@ fn main() -> eyre::Result<()> {
         })
         .collect::<Vec<_>>()
         .join(",\n                            ");
+    let tzdb_data = TZDB_GLOB
+        .iter()
+        .map(|path| {
+            format!(r#"("{path}", include_bytes!("../../tests/data/tzdb/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                            ");
 
     write!(&mut crlify::BufWriterWithLineEndingFix::new(File::create(out_root.join("src/tests/data.rs")).unwrap()), "\
 // This file is part of ICU4X. For terms of use, please see the file
@@ -256,6 +274,12 @@ impl DatagenProvider {{
                             {lstm_data}
                         ].into_iter().collect(),
                     )))),
+                    #[cfg(feature = "tzdb")]
+                    tzdb_paths: Some(Arc::new(SerdeCache::new(AbstractFs::Memory(
+                        [
+                            {tzdb_data}
+                        ].into_iter().collect(),
+                    )))),
                     ..DatagenProvider::new_custom().source
                 }},
             }})
@@ -8,7 +8,7 @@ use core::cmp;
 use core::cmp::Ordering;
 use core::convert::TryFrom;
 use core::fmt;
-use core::ops::RangeInclusive;
+use core::ops::{Add, AddAssign, Mul, MulAssign, RangeInclusive, Sub, SubAssign};
 
 use core::str::FromStr;
 
@@ -84,7 +84,7 @@ compile_error!("The fixed_decimal crate only works if usizes are at least the si
 /// dec.multiply_pow10(-2);
 /// assert_eq!("2.50", dec.to_string());
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FixedDecimal {
     /// List of digits; digits\[0\] is the most significant.
     ///
@@ -136,7 +136,7 @@ pub enum Sign {
 
 /// Configuration for when to render the minus sign or plus sign.
 #[non_exhaustive]
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub enum SignDisplay {
     /// Render the sign according to locale preferences. In most cases, this means a minus sign
     /// will be shown on negative numbers, and no sign will be shown on positive numbers.
@@ -220,7 +220,7 @@ impl_from_unsigned_integer_type!(u8);
 ///
 /// Forces a rounding operation to round to only multiples of the specified increment.
 ///
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
 #[non_exhaustive]
 pub enum RoundingIncrement {
     /// Round the last digit to any digit (0-9).
@@ -2886,6 +2886,309 @@ impl FixedDecimal {
         Ok(())
     }
 
+    /// Adds another `FixedDecimal` to this `FixedDecimal`, returning the exact sum.
+    ///
+    /// The result has at least as many fraction digits as either operand, and retains any leading
+    /// zeros the operands are padded with.
+    ///
+    /// Returns an error if a nonzero digit of the result would be out of the range of magnitudes
+    /// that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let price = FixedDecimal::from_str("19.99").unwrap();
+    /// let shipping = FixedDecimal::from_str("5.5").unwrap();
+    ///
+    /// let total = price.checked_add(&shipping).expect("in range");
+    /// assert_eq!("25.49", total.to_string());
+    /// ```
+    pub fn checked_add(&self, other: &FixedDecimal) -> Result<Self, Error> {
+        self.add_signed(other, false)
+    }
+
+    /// Subtracts another `FixedDecimal` from this `FixedDecimal`, returning the exact difference.
+    ///
+    /// The result has at least as many fraction digits as either operand, and retains any leading
+    /// zeros the operands are padded with.
+    ///
+    /// Returns an error if a nonzero digit of the result would be out of the range of magnitudes
+    /// that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let balance = FixedDecimal::from_str("10.00").unwrap();
+    /// let payment = FixedDecimal::from_str("12.5").unwrap();
+    ///
+    /// let remaining = balance.checked_sub(&payment).expect("in range");
+    /// assert_eq!("-2.50", remaining.to_string());
+    /// ```
+    pub fn checked_sub(&self, other: &FixedDecimal) -> Result<Self, Error> {
+        self.add_signed(other, true)
+    }
+
+    /// Multiplies this `FixedDecimal` by another `FixedDecimal`, returning the exact product.
+    ///
+    /// The number of fraction digits of the result is at least the sum of the number of fraction
+    /// digits of the operands, and the result retains any leading zeros the operands are padded
+    /// with.
+    ///
+    /// Returns an error if a nonzero digit of the result would be out of the range of magnitudes
+    /// that can be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let quantity = FixedDecimal::from(3);
+    /// let price = FixedDecimal::from_str("-1.25").unwrap();
+    ///
+    /// let total = quantity.checked_mul(&price).expect("in range");
+    /// assert_eq!("-3.75", total.to_string());
+    ///
+    /// let limit = FixedDecimal::from(1).multiplied_pow10(i16::MAX);
+    /// assert!(limit.checked_mul(&limit).is_err());
+    /// ```
+    pub fn checked_mul(&self, other: &FixedDecimal) -> Result<Self, Error> {
+        let mut result = if self.is_zero() || other.is_zero() {
+            Self::default()
+        } else {
+            let (digits, lowest) = Self::mul_abs(self, other);
+            let mut result = Self::from_ascending_digits(&digits, lowest)?;
+            if self.is_negative() != other.is_negative() {
+                result.sign = Sign::Negative;
+            }
+            result
+        };
+        let lower_magnitude = cmp::max(
+            self.lower_magnitude as i32 + other.lower_magnitude as i32,
+            i16::MIN as i32,
+        ) as i16;
+        result.upper_magnitude = cmp::max(
+            result.upper_magnitude,
+            cmp::max(
+                self.padded_upper_magnitude(),
+                other.padded_upper_magnitude(),
+            ),
+        );
+        result.lower_magnitude = cmp::min(result.lower_magnitude, lower_magnitude);
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// Adds `other` to `self`, or subtracts it if `negate_other` is set.
+    fn add_signed(&self, other: &FixedDecimal, negate_other: bool) -> Result<Self, Error> {
+        let self_negative = self.is_negative();
+        let other_negative = other.is_negative() != negate_other;
+        let mut result = if self.is_zero() || other.is_zero() {
+            let (nonzero, negative) = if other.is_zero() {
+                (self, self_negative)
+            } else {
+                (other, other_negative)
+            };
+            Self {
+                digits: nonzero.digits.clone(),
+                magnitude: nonzero.magnitude,
+                upper_magnitude: nonzero.upper_magnitude,
+                lower_magnitude: nonzero.lower_magnitude,
+                sign: if negative && !nonzero.is_zero() {
+                    Sign::Negative
+                } else {
+                    Sign::None
+                },
+            }
+        } else if self_negative == other_negative {
+            let (digits, lowest) = Self::add_abs(self, other);
+            let mut result = Self::from_ascending_digits(&digits, lowest)?;
+            if self_negative {
+                result.sign = Sign::Negative;
+            }
+            result
+        } else {
+            let (larger, smaller, negative) = match self.cmp_abs(other) {
+                Ordering::Equal => (None, None, false),
+                Ordering::Greater => (Some(self), Some(other), self_negative),
+                Ordering::Less => (Some(other), Some(self), other_negative),
+            };
+            match (larger, smaller) {
+                (Some(larger), Some(smaller)) => {
+                    let (digits, lowest) = Self::sub_abs(larger, smaller);
+                    let mut result = Self::from_ascending_digits(&digits, lowest)?;
+                    if negative {
+                        result.sign = Sign::Negative;
+                    }
+                    result
+                }
+                _ => Self::default(),
+            }
+        };
+        result.upper_magnitude = cmp::max(
+            result.upper_magnitude,
+            cmp::max(
+                self.padded_upper_magnitude(),
+                other.padded_upper_magnitude(),
+            ),
+        );
+        result.lower_magnitude = cmp::min(
+            result.lower_magnitude,
+            cmp::min(self.lower_magnitude, other.lower_magnitude),
+        );
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// The upper magnitude if the number is padded with leading zeros, or 0 otherwise.
+    fn padded_upper_magnitude(&self) -> i16 {
+        if self.upper_magnitude > self.magnitude {
+            self.upper_magnitude
+        } else {
+            0
+        }
+    }
+
+    /// Whether the number is strictly negative.
+    fn is_negative(&self) -> bool {
+        self.sign == Sign::Negative && !self.is_zero()
+    }
+
+    /// The magnitude of the smallest nonzero digit, which may be lower than `i16::MIN` during
+    /// intermediate computations.
+    fn nonzero_magnitude_end_i32(&self) -> i32 {
+        self.magnitude as i32 - self.digits.len() as i32 + 1
+    }
+
+    /// Same as [`Self::digit_at`], but accepts magnitudes outside of the range of `i16`.
+    fn digit_at_i32(&self, magnitude: i32) -> u8 {
+        let j = self.magnitude as i32 - magnitude;
+        if j < 0 {
+            0
+        } else {
+            self.digits.get(j as usize).copied().unwrap_or(0)
+        }
+    }
+
+    /// Compares the absolute values of two numbers.
+    fn cmp_abs(&self, other: &FixedDecimal) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .magnitude
+                .cmp(&other.magnitude)
+                .then_with(|| self.digits.as_slice().cmp(other.digits.as_slice())),
+        }
+    }
+
+    /// Adds the absolute values of two nonzero numbers.
+    ///
+    /// Returns the digits in ascending order of magnitude, and the magnitude of the first digit.
+    fn add_abs(a: &FixedDecimal, b: &FixedDecimal) -> (SmallVec<[u8; 8]>, i32) {
+        let lowest = cmp::min(a.nonzero_magnitude_end_i32(), b.nonzero_magnitude_end_i32());
+        let highest = cmp::max(a.magnitude, b.magnitude) as i32;
+        let mut digits = SmallVec::new();
+        let mut carry = 0;
+        for magnitude in lowest..=highest {
+            let sum = a.digit_at_i32(magnitude) + b.digit_at_i32(magnitude) + carry;
+            digits.push(sum % 10);
+            carry = sum / 10;
+        }
+        digits.push(carry);
+        (digits, lowest)
+    }
+
+    /// Subtracts the absolute value of `b` from the absolute value of `a`, where `|a| > |b|`,
+    /// and both are nonzero.
+    ///
+    /// Returns the digits in ascending order of magnitude, and the magnitude of the first digit.
+    fn sub_abs(a: &FixedDecimal, b: &FixedDecimal) -> (SmallVec<[u8; 8]>, i32) {
+        debug_assert_eq!(a.cmp_abs(b), Ordering::Greater);
+        let lowest = cmp::min(a.nonzero_magnitude_end_i32(), b.nonzero_magnitude_end_i32());
+        let mut digits = SmallVec::new();
+        let mut borrow = 0;
+        for magnitude in lowest..=(a.magnitude as i32) {
+            let subtrahend = b.digit_at_i32(magnitude) + borrow;
+            let minuend = a.digit_at_i32(magnitude);
+            if minuend >= subtrahend {
+                digits.push(minuend - subtrahend);
+                borrow = 0;
+            } else {
+                digits.push(minuend + 10 - subtrahend);
+                borrow = 1;
+            }
+        }
+        debug_assert_eq!(borrow, 0);
+        (digits, lowest)
+    }
+
+    /// Multiplies the absolute values of two nonzero numbers.
+    ///
+    /// Returns the digits in ascending order of magnitude, and the magnitude of the first digit.
+    fn mul_abs(a: &FixedDecimal, b: &FixedDecimal) -> (SmallVec<[u8; 8]>, i32) {
+        let lowest = a.nonzero_magnitude_end_i32() + b.nonzero_magnitude_end_i32();
+        let mut digits: SmallVec<[u8; 8]> = SmallVec::new();
+        digits.resize(a.digits.len() + b.digits.len(), 0);
+        for (i, a_digit) in a.digits.iter().rev().enumerate() {
+            let mut carry = 0;
+            for (j, b_digit) in b.digits.iter().rev().enumerate() {
+                if let Some(digit) = digits.get_mut(i + j) {
+                    // At most 9 + 9 * 9 + 9, so this cannot overflow
+                    let product = *digit + a_digit * b_digit + carry;
+                    *digit = product % 10;
+                    carry = product / 10;
+                }
+            }
+            if let Some(digit) = digits.get_mut(i + b.digits.len()) {
+                *digit = carry;
+            }
+        }
+        (digits, lowest)
+    }
+
+    /// Creates a `FixedDecimal` from digits in ascending order of magnitude, the first of which
+    /// has magnitude `lowest`.
+    ///
+    /// The magnitude range of the result is the smallest one containing all nonzero digits and
+    /// magnitude 0.
+    fn from_ascending_digits(digits: &[u8], lowest: i32) -> Result<Self, Error> {
+        let (first, last) = match (
+            digits.iter().position(|&d| d != 0),
+            digits.iter().rposition(|&d| d != 0),
+        ) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Ok(Self::default()),
+        };
+        let magnitude = i16::try_from(lowest + last as i32).map_err(|_| Error::Limit)?;
+        let end = i16::try_from(lowest + first as i32).map_err(|_| Error::Limit)?;
+        let result = Self {
+            digits: digits
+                .iter()
+                .take(last + 1)
+                .skip(first)
+                .rev()
+                .copied()
+                .collect(),
+            magnitude,
+            upper_magnitude: cmp::max(magnitude, 0),
+            lower_magnitude: cmp::min(end, 0),
+            sign: Sign::None,
+        };
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
     /// Appends a slice of digits to the end of `self.digits` with optional inner zeroes.
     ///
     /// This function does not check invariants.
@@ -2938,6 +3241,137 @@ impl FixedDecimal {
     }
 }
 
+/// Orders `FixedDecimal`s by their numeric value.
+///
+/// Numbers with the same numeric value but a different representation, such as `1.0` and `1.00`
+/// or `0` and `-0`, are ordered by their sign ([`Sign::Negative`] < [`Sign::None`] <
+/// [`Sign::Positive`]), then by descending number of fraction digits, then by ascending number
+/// of integer digits. This makes the order consistent with equality.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let a = FixedDecimal::from_str("-1.5").unwrap();
+/// let b = FixedDecimal::from_str("0.25").unwrap();
+/// let c = FixedDecimal::from_str("1.2").unwrap();
+/// let d = FixedDecimal::from_str("1.20").unwrap();
+///
+/// assert!(a < b);
+/// assert!(b < c);
+/// assert!(d < c);
+/// ```
+impl Ord for FixedDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn sign_order(sign: Sign) -> u8 {
+            match sign {
+                Sign::Negative => 0,
+                Sign::None => 1,
+                Sign::Positive => 2,
+            }
+        }
+        let value_order = match (self.is_negative(), other.is_negative()) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.cmp_abs(other),
+            (true, true) => other.cmp_abs(self),
+        };
+        value_order
+            .then_with(|| sign_order(self.sign).cmp(&sign_order(other.sign)))
+            .then_with(|| self.lower_magnitude.cmp(&other.lower_magnitude))
+            .then_with(|| self.upper_magnitude.cmp(&other.upper_magnitude))
+    }
+}
+
+impl PartialOrd for FixedDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Adds two `FixedDecimal`s exactly.
+///
+/// NOTE: if the operation causes overflow, the result will be zero. Use
+/// [`FixedDecimal::checked_add`] to detect overflow.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let mut total = FixedDecimal::from_str("0.10").unwrap();
+/// total += FixedDecimal::from_str("0.2").unwrap();
+/// assert_eq!("0.30", total.to_string());
+/// ```
+impl Add for FixedDecimal {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(&rhs).unwrap_or_default()
+    }
+}
+
+impl AddAssign for FixedDecimal {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.checked_add(&rhs).unwrap_or_default();
+    }
+}
+
+/// Subtracts two `FixedDecimal`s exactly.
+///
+/// NOTE: if the operation causes overflow, the result will be zero. Use
+/// [`FixedDecimal::checked_sub`] to detect overflow.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+///
+/// let difference = FixedDecimal::from(3) - FixedDecimal::from(5);
+/// assert_eq!("-2", difference.to_string());
+/// ```
+impl Sub for FixedDecimal {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(&rhs).unwrap_or_default()
+    }
+}
+
+impl SubAssign for FixedDecimal {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.checked_sub(&rhs).unwrap_or_default();
+    }
+}
+
+/// Multiplies two `FixedDecimal`s exactly.
+///
+/// NOTE: if the operation causes overflow, the result will be zero. Use
+/// [`FixedDecimal::checked_mul`] to detect overflow.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let product = FixedDecimal::from_str("1.5").unwrap() * FixedDecimal::from_str("-0.4").unwrap();
+/// assert_eq!("-0.60", product.to_string());
+/// ```
+impl Mul for FixedDecimal {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(&rhs).unwrap_or_default()
+    }
+}
+
+impl MulAssign for FixedDecimal {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.checked_mul(&rhs).unwrap_or_default();
+    }
+}
+
 /// Render the `FixedDecimal` as a string of ASCII digits with a possible decimal point.
 ///
 /// # Examples
@@ -5501,3 +5935,162 @@ fn test_rounding_increment() {
     dec.half_even_to_increment(-2, RoundingIncrement::MultiplesOf25);
     assert_eq!("2.50", dec.to_string());
 }
+
+#[test]
+fn test_arithmetic() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input_1: &'static str,
+        pub input_2: &'static str,
+        pub sum: &'static str,
+        pub difference: &'static str,
+        pub product: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input_1: "0",
+            input_2: "0",
+            sum: "0",
+            difference: "0",
+            product: "0",
+        },
+        TestCase {
+            input_1: "123",
+            input_2: "0",
+            sum: "123",
+            difference: "123",
+            product: "0",
+        },
+        TestCase {
+            input_1: "0",
+            input_2: "-4.5",
+            sum: "-4.5",
+            difference: "4.5",
+            product: "0.0",
+        },
+        TestCase {
+            input_1: "999",
+            input_2: "1",
+            sum: "1000",
+            difference: "998",
+            product: "999",
+        },
+        TestCase {
+            input_1: "1.25",
+            input_2: "0.75",
+            sum: "2.00",
+            difference: "0.50",
+            product: "0.9375",
+        },
+        TestCase {
+            input_1: "0.001",
+            input_2: "1000",
+            sum: "1000.001",
+            difference: "-999.999",
+            product: "1.000",
+        },
+        TestCase {
+            input_1: "-7",
+            input_2: "3",
+            sum: "-4",
+            difference: "-10",
+            product: "-21",
+        },
+        TestCase {
+            input_1: "-7",
+            input_2: "-3",
+            sum: "-10",
+            difference: "-4",
+            product: "21",
+        },
+        TestCase {
+            input_1: "5.5",
+            input_2: "5.50",
+            sum: "11.00",
+            difference: "0.00",
+            product: "30.250",
+        },
+        TestCase {
+            input_1: "00123.400",
+            input_2: "0.6",
+            sum: "00124.000",
+            difference: "00122.800",
+            product: "00074.0400",
+        },
+        TestCase {
+            input_1: "+12",
+            input_2: "-12",
+            sum: "0",
+            difference: "24",
+            product: "-144",
+        },
+        TestCase {
+            input_1: "123456789012345678901234567890",
+            input_2: "987654321098765432109876543210",
+            sum: "1111111110111111111011111111100",
+            difference: "-864197532086419753208641975320",
+            product: "121932631137021795226185032733622923332237463801111263526900",
+        },
+    ];
+    for cas in &cases {
+        let input_1 = FixedDecimal::from_str(cas.input_1).unwrap();
+        let input_2 = FixedDecimal::from_str(cas.input_2).unwrap();
+
+        let sum = input_1.checked_add(&input_2).unwrap();
+        assert_eq!(cas.sum, sum.to_string(), "{cas:?}");
+        assert_eq!(sum, input_2.checked_add(&input_1).unwrap(), "{cas:?}");
+        assert_eq!(sum, input_1.clone() + input_2.clone(), "{cas:?}");
+
+        let difference = input_1.checked_sub(&input_2).unwrap();
+        assert_eq!(cas.difference, difference.to_string(), "{cas:?}");
+        assert_eq!(difference, input_1.clone() - input_2.clone(), "{cas:?}");
+
+        let product = input_1.checked_mul(&input_2).unwrap();
+        assert_eq!(cas.product, product.to_string(), "{cas:?}");
+        assert_eq!(product, input_2.checked_mul(&input_1).unwrap(), "{cas:?}");
+        assert_eq!(product, input_1.clone() * input_2.clone(), "{cas:?}");
+    }
+}
+
+#[test]
+fn test_arithmetic_limits() {
+    let max = FixedDecimal::from(9).multiplied_pow10(i16::MAX);
+    let min = FixedDecimal::from(1).multiplied_pow10(i16::MIN + 1);
+
+    assert_eq!(max.checked_add(&max), Err(Error::Limit));
+    assert_eq!(max.clone() + max.clone(), FixedDecimal::default());
+    assert_eq!(
+        max.clone().with_sign(Sign::Negative).checked_sub(&max),
+        Err(Error::Limit)
+    );
+    assert_eq!(min.checked_mul(&min), Err(Error::Limit));
+    assert_eq!(max.checked_mul(&FixedDecimal::from(2)), Err(Error::Limit));
+
+    // Digits that stay in range are fine, even with very wide operands
+    let sum = max.checked_add(&min).unwrap();
+    assert_eq!(sum.nonzero_magnitude_start(), i16::MAX);
+    assert_eq!(sum.nonzero_magnitude_end(), i16::MIN + 1);
+    let product = max.checked_mul(&min).unwrap();
+    assert_eq!(product.nonzero_magnitude_start(), 0);
+    assert_eq!(product.digit_at(0), 9);
+    assert_eq!(product.magnitude_range(), (i16::MIN + 1)..=0);
+}
+
+#[test]
+fn test_ord() {
+    let ascending = [
+        "-100", "-99.5", "-1.00", "-1.0", "-1", "-0.01", "-0", "0.0", "0", "00", "+0", "0.001",
+        "1.50", "1.5", "01.5", "+1.5", "2", "10", "1000000",
+    ];
+    let decimals = ascending
+        .iter()
+        .map(|s| FixedDecimal::from_str(s).unwrap())
+        .collect::<Vec<_>>();
+    for (i, a) in decimals.iter().enumerate() {
+        for (j, b) in decimals.iter().enumerate() {
+            assert_eq!(a.cmp(b), i.cmp(&j), "{a} <=> {b}");
+            assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)), "{a} <=> {b}");
+            assert_eq!(a == b, i == j, "{a} == {b}");
+        }
+    }
+}
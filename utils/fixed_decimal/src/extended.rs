@@ -0,0 +1,379 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use core::str::FromStr;
+
+use crate::Error;
+use crate::FixedDecimal;
+use crate::Sign;
+use crate::SignDisplay;
+
+/// A [`FixedDecimal`] extended with the special values of IEEE 754 floating point numbers:
+/// positive and negative infinity, and NaN.
+///
+/// Negative zero is represented by a [`FixedDecimal`] with [`Sign::Negative`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::ExtendedDecimal;
+/// use fixed_decimal::FixedDecimal;
+/// use fixed_decimal::Sign;
+/// # use std::str::FromStr;
+///
+/// let finite = ExtendedDecimal::from(FixedDecimal::from(-42));
+/// assert!(finite.is_finite());
+/// assert_eq!("-42", finite.to_string());
+///
+/// let infinity = ExtendedDecimal::Infinity(Sign::Negative);
+/// assert!(infinity.is_infinite());
+/// assert_eq!("-inf", infinity.to_string());
+///
+/// assert!(ExtendedDecimal::from_str("NaN").unwrap().is_nan());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+// These are the kinds of values of IEEE 754 floating point numbers.
+pub enum ExtendedDecimal {
+    /// A finite number.
+    Finite(FixedDecimal),
+    /// An infinity with the given sign.
+    Infinity(Sign),
+    /// Not a number.
+    NaN,
+}
+
+impl ExtendedDecimal {
+    /// Returns whether the value is a finite number.
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Self::Finite(_))
+    }
+
+    /// Returns whether the value is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        matches!(self, Self::Infinity(_))
+    }
+
+    /// Returns whether the value is NaN.
+    pub fn is_nan(&self) -> bool {
+        matches!(self, Self::NaN)
+    }
+
+    /// Returns the sign. NaN does not have a sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ExtendedDecimal;
+    /// use fixed_decimal::Sign;
+    /// # use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     ExtendedDecimal::from_str("-0").unwrap().sign(),
+    ///     Sign::Negative
+    /// );
+    /// assert_eq!(
+    ///     ExtendedDecimal::from_str("+inf").unwrap().sign(),
+    ///     Sign::Positive
+    /// );
+    /// assert_eq!(ExtendedDecimal::NaN.sign(), Sign::None);
+    /// ```
+    pub fn sign(&self) -> Sign {
+        match self {
+            Self::Finite(decimal) => decimal.sign(),
+            Self::Infinity(sign) => *sign,
+            Self::NaN => Sign::None,
+        }
+    }
+
+    /// Changes the sign of this number to one specified by the given sign display strategy.
+    ///
+    /// Infinities are treated like nonzero numbers. NaN is never given a sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ExtendedDecimal;
+    /// use fixed_decimal::Sign;
+    /// use fixed_decimal::SignDisplay;
+    ///
+    /// let mut infinity = ExtendedDecimal::Infinity(Sign::None);
+    /// infinity.apply_sign_display(SignDisplay::ExceptZero);
+    /// assert_eq!("+inf", infinity.to_string());
+    ///
+    /// let mut nan = ExtendedDecimal::NaN;
+    /// nan.apply_sign_display(SignDisplay::Always);
+    /// assert_eq!("NaN", nan.to_string());
+    /// ```
+    pub fn apply_sign_display(&mut self, sign_display: SignDisplay) {
+        match self {
+            Self::Finite(decimal) => decimal.apply_sign_display(sign_display),
+            Self::Infinity(sign) => {
+                *sign = match (sign_display, *sign) {
+                    (SignDisplay::Never, _) => Sign::None,
+                    (_, Sign::Negative) => Sign::Negative,
+                    (SignDisplay::Always | SignDisplay::ExceptZero, _) => Sign::Positive,
+                    _ => Sign::None,
+                }
+            }
+            Self::NaN => (),
+        }
+    }
+
+    /// Changes the sign of this number to one specified by the given sign display strategy,
+    /// consuming self and returning a new object.
+    pub fn with_sign_display(mut self, sign_display: SignDisplay) -> Self {
+        self.apply_sign_display(sign_display);
+        self
+    }
+}
+
+impl From<FixedDecimal> for ExtendedDecimal {
+    fn from(decimal: FixedDecimal) -> Self {
+        Self::Finite(decimal)
+    }
+}
+
+#[cfg(feature = "ryu")]
+impl ExtendedDecimal {
+    /// Construct an [`ExtendedDecimal`] from an f64.
+    ///
+    /// Unlike [`FixedDecimal::try_from_f64`], infinite and NaN values are supported. The sign
+    /// of zero is retained.
+    ///
+    /// See [`FixedDecimal::try_from_f64`] for the meaning of the `precision` argument.
+    ///
+    /// ✨ *Enabled with the `ryu` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ExtendedDecimal;
+    /// use fixed_decimal::FloatPrecision;
+    /// use fixed_decimal::Sign;
+    ///
+    /// let decimal =
+    ///     ExtendedDecimal::try_from_f64(f64::NEG_INFINITY, FloatPrecision::Floating)
+    ///         .expect("infinity is supported");
+    /// assert_eq!(decimal, ExtendedDecimal::Infinity(Sign::Negative));
+    ///
+    /// let decimal = ExtendedDecimal::try_from_f64(-0.0, FloatPrecision::Integer)
+    ///     .expect("negative zero is supported");
+    /// assert_eq!("-0", decimal.to_string());
+    /// ```
+    pub fn try_from_f64(float: f64, precision: crate::FloatPrecision) -> Result<Self, Error> {
+        if float.is_nan() {
+            Ok(Self::NaN)
+        } else if float.is_infinite() {
+            Ok(Self::Infinity(if float.is_sign_negative() {
+                Sign::Negative
+            } else {
+                Sign::None
+            }))
+        } else {
+            FixedDecimal::try_from_f64(float, precision).map(Self::Finite)
+        }
+    }
+}
+
+/// Render the [`ExtendedDecimal`] as a string of ASCII characters. Finite numbers are rendered
+/// like [`FixedDecimal`], infinities as `inf` with a sign, and NaN as `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// # use fixed_decimal::ExtendedDecimal;
+/// # use fixed_decimal::FixedDecimal;
+/// # use fixed_decimal::Sign;
+/// # use writeable::assert_writeable_eq;
+/// #
+/// assert_writeable_eq!(ExtendedDecimal::from(FixedDecimal::from(42)), "42");
+/// assert_writeable_eq!(ExtendedDecimal::Infinity(Sign::Positive), "+inf");
+/// assert_writeable_eq!(ExtendedDecimal::NaN, "NaN");
+/// ```
+impl writeable::Writeable for ExtendedDecimal {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            Self::Finite(decimal) => decimal.write_to(sink),
+            Self::Infinity(sign) => {
+                match sign {
+                    Sign::Negative => sink.write_char('-')?,
+                    Sign::Positive => sink.write_char('+')?,
+                    Sign::None => (),
+                }
+                sink.write_str("inf")
+            }
+            Self::NaN => sink.write_str("NaN"),
+        }
+    }
+
+    fn writeable_length_hint(&self) -> writeable::LengthHint {
+        match self {
+            Self::Finite(decimal) => decimal.writeable_length_hint(),
+            Self::Infinity(sign) => {
+                writeable::LengthHint::exact(3 + (*sign != Sign::None) as usize)
+            }
+            Self::NaN => writeable::LengthHint::exact(3),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(ExtendedDecimal);
+
+impl FromStr for ExtendedDecimal {
+    type Err = Error;
+    fn from_str(input_str: &str) -> Result<Self, Self::Err> {
+        Self::try_from(input_str.as_bytes())
+    }
+}
+
+/// Parses an [`ExtendedDecimal`]. In addition to the syntax accepted by [`FixedDecimal`],
+/// `inf` and `infinity` with an optional sign, and `nan`, are accepted, ignoring ASCII case.
+impl TryFrom<&[u8]> for ExtendedDecimal {
+    type Error = Error;
+    fn try_from(input_str: &[u8]) -> Result<Self, Self::Error> {
+        let (sign, unsigned) = match input_str {
+            [b'-', rest @ ..] => (Sign::Negative, rest),
+            [b'+', rest @ ..] => (Sign::Positive, rest),
+            _ => (Sign::None, input_str),
+        };
+        if unsigned.eq_ignore_ascii_case(b"inf") || unsigned.eq_ignore_ascii_case(b"infinity") {
+            Ok(Self::Infinity(sign))
+        } else if input_str.eq_ignore_ascii_case(b"nan") {
+            Ok(Self::NaN)
+        } else {
+            FixedDecimal::try_from(input_str).map(Self::Finite)
+        }
+    }
+}
+
+#[test]
+fn test_from_str() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input_str: &'static str,
+        pub expected: Result<ExtendedDecimal, Error>,
+        pub output_str: Option<&'static str>,
+    }
+    let cases = [
+        TestCase {
+            input_str: "-00.50",
+            expected: Ok(ExtendedDecimal::Finite(
+                FixedDecimal::from(-50).multiplied_pow10(-2).padded_start(2),
+            )),
+            output_str: None,
+        },
+        TestCase {
+            input_str: "-0",
+            expected: Ok(ExtendedDecimal::Finite(
+                FixedDecimal::from(0).with_sign(Sign::Negative),
+            )),
+            output_str: None,
+        },
+        TestCase {
+            input_str: "inf",
+            expected: Ok(ExtendedDecimal::Infinity(Sign::None)),
+            output_str: None,
+        },
+        TestCase {
+            input_str: "+Infinity",
+            expected: Ok(ExtendedDecimal::Infinity(Sign::Positive)),
+            output_str: Some("+inf"),
+        },
+        TestCase {
+            input_str: "-INF",
+            expected: Ok(ExtendedDecimal::Infinity(Sign::Negative)),
+            output_str: Some("-inf"),
+        },
+        TestCase {
+            input_str: "NaN",
+            expected: Ok(ExtendedDecimal::NaN),
+            output_str: None,
+        },
+        TestCase {
+            input_str: "nan",
+            expected: Ok(ExtendedDecimal::NaN),
+            output_str: Some("NaN"),
+        },
+        TestCase {
+            input_str: "-nan",
+            expected: Err(Error::Syntax),
+            output_str: None,
+        },
+        TestCase {
+            input_str: "infinite",
+            expected: Err(Error::Syntax),
+            output_str: None,
+        },
+    ];
+    for cas in &cases {
+        let actual = ExtendedDecimal::from_str(cas.input_str);
+        assert_eq!(cas.expected, actual, "{cas:?}");
+        if let Ok(actual) = actual {
+            assert_eq!(
+                cas.output_str.unwrap_or(cas.input_str),
+                actual.to_string(),
+                "{cas:?}"
+            );
+            writeable::assert_writeable_eq!(actual, cas.output_str.unwrap_or(cas.input_str));
+        }
+    }
+}
+
+#[test]
+fn test_sign_display() {
+    use SignDisplay::*;
+    let cases = [
+        (Auto, ["-inf", "inf", "inf", "NaN"]),
+        (Never, ["inf", "inf", "inf", "NaN"]),
+        (Always, ["-inf", "+inf", "+inf", "NaN"]),
+        (ExceptZero, ["-inf", "+inf", "+inf", "NaN"]),
+        (Negative, ["-inf", "inf", "inf", "NaN"]),
+    ];
+    for (sign_display, expected) in cases {
+        let inputs = [
+            ExtendedDecimal::Infinity(Sign::Negative),
+            ExtendedDecimal::Infinity(Sign::None),
+            ExtendedDecimal::Infinity(Sign::Positive),
+            ExtendedDecimal::NaN,
+        ];
+        for (input, expected) in inputs.into_iter().zip(expected) {
+            assert_eq!(
+                expected,
+                input.with_sign_display(sign_display).to_string(),
+                "{sign_display:?}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "ryu")]
+#[test]
+fn test_float() {
+    use crate::FloatPrecision;
+
+    assert_eq!(
+        ExtendedDecimal::try_from_f64(f64::INFINITY, FloatPrecision::Integer),
+        Ok(ExtendedDecimal::Infinity(Sign::None))
+    );
+    assert_eq!(
+        ExtendedDecimal::try_from_f64(f64::NAN, FloatPrecision::Integer),
+        Ok(ExtendedDecimal::NaN)
+    );
+    assert_eq!(
+        ExtendedDecimal::try_from_f64(-0.0, FloatPrecision::Floating).map(|d| d.sign()),
+        Ok(Sign::Negative)
+    );
+    assert_eq!(
+        ExtendedDecimal::try_from_f64(1.5, FloatPrecision::Integer),
+        Err(Error::Limit)
+    );
+    assert_eq!(
+        ExtendedDecimal::try_from_f64(1.5, FloatPrecision::Magnitude(-2)).map(|d| d.to_string()),
+        Ok("1.50".into())
+    );
+}
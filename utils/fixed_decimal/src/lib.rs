@@ -53,6 +53,7 @@
 
 mod compact;
 mod decimal;
+mod extended;
 mod integer;
 mod ops;
 mod scientific;
@@ -71,6 +72,7 @@ pub use decimal::RoundingIncrement;
 pub use decimal::Sign;
 pub use decimal::SignDisplay;
 use displaydoc::Display;
+pub use extended::ExtendedDecimal;
 pub use integer::FixedInteger;
 pub use scientific::ScientificDecimal;
 
@@ -85,6 +87,9 @@ pub enum FixedDecimalError {
     /// This error is also returned when constructing a FixedInteger from a FixedDecimal with a
     /// fractional part.
     ///
+    /// It is also returned by the checked arithmetic methods, such as
+    /// [`FixedDecimal::checked_add`], if the result is out of range.
+    ///
     /// # Examples
     ///
     /// ```
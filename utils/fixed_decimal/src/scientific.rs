@@ -28,6 +28,37 @@ impl ScientificDecimal {
             exponent,
         }
     }
+
+    /// Returns the significand, the number that is multiplied by a power of ten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ScientificDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let decimal = ScientificDecimal::from_str("1.729e3").unwrap();
+    /// assert_eq!("1.729", decimal.significand().to_string());
+    /// ```
+    pub fn significand(&self) -> &FixedDecimal {
+        &self.significand
+    }
+
+    /// Returns the exponent, the power of ten by which the significand is multiplied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::ScientificDecimal;
+    /// # use std::str::FromStr;
+    /// use writeable::Writeable;
+    ///
+    /// let decimal = ScientificDecimal::from_str("1.729e-03").unwrap();
+    /// assert_eq!("-03", decimal.exponent().write_to_string());
+    /// ```
+    pub fn exponent(&self) -> &FixedInteger {
+        &self.exponent
+    }
 }
 
 /// Render the [`ScientificDecimal`] as a string of ASCII digits with a possible decimal point,
@@ -2,6 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+mod duration;
+
+pub use duration::{DurationParser, ParsedDuration};
+
 // An enum for Parser errors.
 #[non_exhaustive]
 #[derive(PartialEq, Clone, Copy, Debug)]
@@ -19,6 +23,27 @@ pub enum ParseError {
     DateSeparator,
     TimeSeparator,
     DecimalSeparator,
+    UtcOffsetSign,
+    UtcOffsetHour,
+    UtcOffsetMinute,
+    UtcOffsetSecond,
+    UtcOffsetSeparator,
+    AnnotationOpen,
+    AnnotationClose,
+    AnnotationKey,
+    AnnotationValue,
+    TimeZoneAnnotation,
+    UnrecognizedCriticalAnnotation,
+    CriticalDuplicateCalendar,
+    DateWeek,
+    DateWeekDay,
+    DateOrdinal,
+    DurationPrefix,
+    DurationEmpty,
+    DurationValue,
+    DurationDesignator,
+    DurationOrder,
+    DurationFraction,
 }
 
 // An enum for date time separator.
@@ -57,11 +82,11 @@ impl DecimalSeparator {
 
 /// [`ParsedDateTime`] is the parsed result from the DateTimeParser.
 ///
-/// The structure contains all the information needed for IXDTF. Now it only supports date and time
-/// fields.
+/// The structure contains all the information needed for IXDTF: the date and time fields, the
+/// UTC offset, the time zone annotation, and any other annotations.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ParsedDateTime {
+pub struct ParsedDateTime<'a> {
     pub year: Option<i32>,
     pub month: Option<u8>,
     pub day: Option<u8>,
@@ -69,6 +94,293 @@ pub struct ParsedDateTime {
     pub minute: Option<u8>,
     pub second: Option<u8>,
     pub nano_second: Option<i32>,
+    /// The ISO week of a week date such as `2024-W11-1`.
+    pub week: Option<u8>,
+    /// The ISO day of the week of a week date, from 1 (Monday) to 7 (Sunday).
+    pub week_day: Option<u8>,
+    /// The day of the year of an ordinal date such as `2024-071`.
+    pub day_of_year: Option<u16>,
+    /// The UTC offset following the time, such as `+05:30` or `Z`.
+    pub offset: Option<Offset>,
+    /// The bracketed time zone annotation, such as `[Asia/Kolkata]` or `[+05:30]`.
+    pub time_zone: Option<TimeZoneAnnotation<'a>>,
+    /// The bracketed key-value annotations, such as `[u-ca=hebrew]`.
+    pub annotations: Annotations<'a>,
+}
+
+/// A UTC offset following the time in an IXDTF string.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Offset {
+    /// The `Z` designator, meaning the time is in UTC and the local offset is unknown.
+    Z,
+    /// A numeric offset, such as `+05:30`.
+    Numeric(UtcOffset),
+}
+
+/// A numeric UTC offset, such as `+05:30` or `-03:00:00.5`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UtcOffset {
+    /// `1` for offsets east of UTC (including `+00:00`), `-1` otherwise.
+    pub sign: i8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nano_second: i32,
+}
+
+/// A bracketed time zone annotation, such as `[Asia/Kolkata]` or `[!+05:30]`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeZoneAnnotation<'a> {
+    /// Whether the annotation was marked critical with `!`.
+    pub critical: bool,
+    pub time_zone: TimeZoneRecord<'a>,
+}
+
+/// The time zone of a [`TimeZoneAnnotation`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeZoneRecord<'a> {
+    /// An IANA time zone name, such as `Asia/Kolkata`.
+    Name(&'a [u8]),
+    /// A UTC offset with minute precision, such as `+05:30`.
+    Offset(UtcOffset),
+}
+
+/// A bracketed key-value annotation, such as `[u-ca=hebrew]` or `[!u-ca=hebrew]`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Annotation<'a> {
+    /// Whether the annotation was marked critical with `!`.
+    pub critical: bool,
+    pub key: &'a [u8],
+    pub value: &'a [u8],
+}
+
+/// The key-value annotations of an IXDTF string, in the order they appear.
+///
+/// # Examples
+/// ```
+/// use ixdtf::parser::DateTimeParser;
+///
+/// let dt = "2024-03-11[u-ca=hebrew][foo=bar]".as_bytes();
+/// let parsed = DateTimeParser::new(dt).parse().unwrap();
+///
+/// assert_eq!(parsed.annotations.calendar(), Some("hebrew".as_bytes()));
+/// assert_eq!(parsed.annotations.iter().count(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Annotations<'a> {
+    // The validated annotation section, e.g. `[u-ca=hebrew][foo=bar]`
+    bytes: &'a [u8],
+}
+
+impl<'a> Annotations<'a> {
+    /// Returns an iterator over the annotations.
+    pub fn iter(&self) -> impl Iterator<Item = Annotation<'a>> {
+        let mut bytes = self.bytes;
+        core::iter::from_fn(move || {
+            // The bytes were validated during parsing, so this never fails.
+            let (annotation, remains) = parse_annotation(bytes).ok()?;
+            bytes = remains;
+            Some(annotation)
+        })
+    }
+
+    /// Returns the value of the first `u-ca` annotation, if any.
+    pub fn calendar(&self) -> Option<&'a [u8]> {
+        self.iter()
+            .find(|annotation| annotation.key == CALENDAR_KEY)
+            .map(|annotation| annotation.value)
+    }
+
+    /// Returns whether there are no annotations.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+/// The only annotation key currently recognized, see RFC 9557 section 4.
+const CALENDAR_KEY: &[u8] = b"u-ca";
+
+// Splits off exactly `n` ASCII digits, returning their value.
+fn parse_digits(bytes: &[u8], n: usize) -> Option<(u32, &[u8])> {
+    let digits = bytes.get(..n)?;
+    if !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let value = digits
+        .iter()
+        .fold(0, |value, digit| value * 10 + (digit - b'0') as u32);
+    Some((value, bytes.get(n..)?))
+}
+
+// Splits off the given byte if `bytes` starts with it.
+fn strip_byte(bytes: &[u8], byte: u8) -> (bool, &[u8]) {
+    match bytes.split_first() {
+        Some((first, remains)) if *first == byte => (true, remains),
+        _ => (false, bytes),
+    }
+}
+
+// Parses a fractional part of up to 9 digits, returning it in nanoseconds.
+fn parse_fraction(bytes: &[u8]) -> Option<(i32, &[u8])> {
+    let len = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if len == 0 || len > 9 {
+        return None;
+    }
+    let (fraction, remains) = parse_digits(bytes, len)?;
+    Some((fraction as i32 * 10_i32.pow(9 - len as u32), remains))
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The number of weeks in an ISO week-numbering year: 53 if the year starts or ends on a
+// Thursday, 52 otherwise.
+fn weeks_in_year(year: i32) -> u32 {
+    // The day of the week of December 31, where 4 is Thursday
+    let p = |y: i32| (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)).rem_euclid(7);
+    if p(year) == 4 || p(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+// Parses a numeric UTC offset: `±HH`, `±HH:MM`, `±HHMM`, `±HH:MM:SS(.fff)` or `±HHMMSS(.fff)`.
+fn parse_utc_offset(bytes: &[u8]) -> Result<(UtcOffset, &[u8]), ParseError> {
+    let (sign, bytes) = match bytes.split_first() {
+        Some((b'+', remains)) => (1, remains),
+        Some((b'-', remains)) => (-1, remains),
+        _ => return Err(ParseError::UtcOffsetSign),
+    };
+    let (hour, bytes) = match parse_digits(bytes, 2) {
+        Some((hour, remains)) if hour <= 23 => (hour as u8, remains),
+        _ => return Err(ParseError::UtcOffsetHour),
+    };
+    let mut offset = UtcOffset {
+        sign,
+        hour,
+        minute: 0,
+        second: 0,
+        nano_second: 0,
+    };
+
+    let (extended, remains) = strip_byte(bytes, b':');
+    let (minute, bytes) = match parse_digits(remains, 2) {
+        Some((minute, remains)) if minute <= 59 => (minute as u8, remains),
+        Some(_) => return Err(ParseError::UtcOffsetMinute),
+        None if extended => return Err(ParseError::UtcOffsetMinute),
+        None => return Ok((offset, bytes)),
+    };
+    offset.minute = minute;
+
+    let (had_separator, remains) = strip_byte(bytes, b':');
+    if had_separator != extended {
+        if had_separator || bytes.first().map_or(false, u8::is_ascii_digit) {
+            return Err(ParseError::UtcOffsetSeparator);
+        }
+        return Ok((offset, bytes));
+    }
+    let (second, bytes) = match parse_digits(remains, 2) {
+        Some((second, remains)) if second <= 59 => (second as u8, remains),
+        Some(_) => return Err(ParseError::UtcOffsetSecond),
+        None if extended => return Err(ParseError::UtcOffsetSecond),
+        None => return Ok((offset, bytes)),
+    };
+    offset.second = second;
+
+    match bytes.split_first() {
+        Some((b'.' | b',', remains)) => {
+            let (nano_second, remains) = parse_fraction(remains).ok_or(ParseError::FractionPart)?;
+            offset.nano_second = nano_second;
+            Ok((offset, remains))
+        }
+        _ => Ok((offset, bytes)),
+    }
+}
+
+// Parses a bracketed key-value annotation such as `[!u-ca=hebrew]`.
+fn parse_annotation(bytes: &[u8]) -> Result<(Annotation<'_>, &[u8]), ParseError> {
+    let bytes = match bytes.split_first() {
+        Some((b'[', remains)) => remains,
+        _ => return Err(ParseError::AnnotationOpen),
+    };
+    let (critical, bytes) = strip_byte(bytes, b'!');
+
+    // key = [a-z_] *[a-z0-9_-]
+    let key_len = bytes
+        .iter()
+        .take_while(|&&b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-')
+        .count();
+    let (key, bytes) = bytes.split_at(key_len);
+    match key.first() {
+        Some(first) if first.is_ascii_lowercase() || *first == b'_' => {}
+        _ => return Err(ParseError::AnnotationKey),
+    }
+    let bytes = match bytes.split_first() {
+        Some((b'=', remains)) => remains,
+        _ => return Err(ParseError::AnnotationKey),
+    };
+
+    // value = 1*alphanum *("-" 1*alphanum)
+    let value_len = bytes
+        .iter()
+        .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'-')
+        .count();
+    let (value, bytes) = bytes.split_at(value_len);
+    if value.is_empty()
+        || value.starts_with(b"-")
+        || value.ends_with(b"-")
+        || value.windows(2).any(|w| w == b"--")
+    {
+        return Err(ParseError::AnnotationValue);
+    }
+    let bytes = match bytes.split_first() {
+        Some((b']', remains)) => remains,
+        _ => return Err(ParseError::AnnotationClose),
+    };
+
+    Ok((
+        Annotation {
+            critical,
+            key,
+            value,
+        },
+        bytes,
+    ))
+}
+
+// Validates an IANA time zone name: `/`-separated components of `[A-Za-z._][A-Za-z0-9._+-]*`,
+// excluding `.` and `..`.
+fn is_valid_time_zone_name(name: &[u8]) -> bool {
+    name.split(|&b| b == b'/').all(|component| {
+        match component.first() {
+            Some(first) if first.is_ascii_alphabetic() || *first == b'.' || *first == b'_' => {}
+            _ => return false,
+        }
+        component != b"."
+            && component != b".."
+            && component
+                .iter()
+                .all(|&b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'+' | b'-'))
+    })
 }
 
 /// [`DateTimeParser`] is the parser to parse IXDTF bytes.
@@ -87,7 +399,7 @@ pub struct DateTimeParser<'a> {
 
 impl<'a> DateTimeParser<'a> {
     /// Create a new instance of [`DateTimeParser`].
-    pub fn new(bytes: &'a [u8]) -> DateTimeParser {
+    pub fn new(bytes: &'a [u8]) -> DateTimeParser<'a> {
         return DateTimeParser { bytes };
     }
 
@@ -232,7 +544,7 @@ impl<'a> DateTimeParser<'a> {
     }
 
     fn parse_time_minute(&mut self) -> Result<Option<u8>, ParseError> {
-        if self.bytes.is_empty() {
+        if self.is_time_end() {
             return Ok(None);
         }
         let mut minute: u8 = 0;
@@ -259,7 +571,7 @@ impl<'a> DateTimeParser<'a> {
     }
 
     fn parse_time_second(&mut self) -> Result<Option<u8>, ParseError> {
-        if self.bytes.is_empty() {
+        if self.is_time_end() {
             return Ok(None);
         }
         let mut second: u8 = 0;
@@ -278,7 +590,7 @@ impl<'a> DateTimeParser<'a> {
             }
             cnt += 1;
         }
-        if !(1..=60).contains(&second) {
+        if !(0..=60).contains(&second) {
             return Err(ParseError::TimeSecond);
         }
         self.bytes = mut_inner_remains;
@@ -286,26 +598,8 @@ impl<'a> DateTimeParser<'a> {
     }
 
     fn parse_fraction_part(&mut self) -> Result<Option<i32>, ParseError> {
-        let mut fraction: i32 = 0;
-        let mut cnt = 0;
-        let mut mut_inner_remains = self.bytes;
-        while cnt < 9 {
-            if let Some((inner_first, inner_remains)) = mut_inner_remains.split_first() {
-                if (&b'0'..=&b'9').contains(&inner_first) {
-                    mut_inner_remains = inner_remains;
-                    fraction = fraction * 10 + *inner_first as i32 - b'0' as i32;
-                } else {
-                    return Err(ParseError::FractionPart);
-                }
-            } else {
-                break;
-            }
-            cnt += 1;
-        }
-        if cnt == 0 {
-            return Err(ParseError::FractionPart);
-        }
-        self.bytes = mut_inner_remains;
+        let (fraction, remains) = parse_fraction(self.bytes).ok_or(ParseError::FractionPart)?;
+        self.bytes = remains;
         Ok(Some(fraction))
     }
 
@@ -320,17 +614,91 @@ impl<'a> DateTimeParser<'a> {
         return false;
     }
 
-    /// Parse the IXDTF bytes to human readable results, stored in [`ParsedDateTime`].
-    pub fn parse(&mut self) -> Result<ParsedDateTime, ParseError> {
-        let mut result = ParsedDateTime {
-            year: None,
-            month: None,
-            day: None,
-            hour: None,
-            minute: None,
-            second: None,
-            nano_second: None,
+    fn is_time_end(&self) -> bool {
+        // Whether the time is followed by nothing, a UTC offset or an annotation.
+        matches!(
+            self.bytes.first(),
+            None | Some(b'Z' | b'z' | b'+' | b'-' | b'[')
+        )
+    }
+
+    fn parse_offset(&mut self) -> Result<Option<Offset>, ParseError> {
+        match self.bytes.split_first() {
+            Some((b'Z' | b'z', remains)) => {
+                self.bytes = remains;
+                Ok(Some(Offset::Z))
+            }
+            Some((b'+' | b'-', _)) => {
+                let (offset, remains) = parse_utc_offset(self.bytes)?;
+                self.bytes = remains;
+                Ok(Some(Offset::Numeric(offset)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn parse_time_zone_annotation(&mut self) -> Result<Option<TimeZoneAnnotation<'a>>, ParseError> {
+        let content = match self.bytes.split_first() {
+            Some((b'[', remains)) => remains,
+            _ => return Ok(None),
+        };
+        let len = match content.iter().position(|&b| b == b']') {
+            Some(len) => len,
+            None => return Err(ParseError::AnnotationClose),
         };
+        let (content, remains) = content.split_at(len);
+        // A key-value annotation rather than a time zone
+        if content.contains(&b'=') {
+            return Ok(None);
+        }
+        let (critical, content) = strip_byte(content, b'!');
+        let time_zone = if matches!(content.first(), Some(b'+' | b'-')) {
+            match parse_utc_offset(content) {
+                // Offset annotations have at most minute precision, i.e. `±HH:MM`
+                Ok((offset, [])) if content.len() <= 6 => TimeZoneRecord::Offset(offset),
+                _ => return Err(ParseError::TimeZoneAnnotation),
+            }
+        } else if is_valid_time_zone_name(content) {
+            TimeZoneRecord::Name(content)
+        } else {
+            return Err(ParseError::TimeZoneAnnotation);
+        };
+        // Skip the closing bracket
+        self.bytes = remains.get(1..).unwrap_or_default();
+        Ok(Some(TimeZoneAnnotation {
+            critical,
+            time_zone,
+        }))
+    }
+
+    fn parse_annotations(&mut self) -> Result<Annotations<'a>, ParseError> {
+        let start = self.bytes;
+        let mut calendar: Option<Annotation> = None;
+        while self.bytes.first() == Some(&b'[') {
+            let (annotation, remains) = parse_annotation(self.bytes)?;
+            if annotation.key == CALENDAR_KEY {
+                match calendar {
+                    // A calendar marked critical must not be repeated.
+                    Some(first) if first.critical || annotation.critical => {
+                        return Err(ParseError::CriticalDuplicateCalendar)
+                    }
+                    Some(_) => {}
+                    None => calendar = Some(annotation),
+                }
+            } else if annotation.critical {
+                return Err(ParseError::UnrecognizedCriticalAnnotation);
+            }
+            self.bytes = remains;
+        }
+        let len = start.len() - self.bytes.len();
+        Ok(Annotations {
+            bytes: start.get(..len).unwrap_or_default(),
+        })
+    }
+
+    /// Parse the IXDTF bytes to human readable results, stored in [`ParsedDateTime`].
+    pub fn parse(&mut self) -> Result<ParsedDateTime<'a>, ParseError> {
+        let mut result = Self::new_result();
         if self.bytes.is_empty() {
             return Ok(result);
         }
@@ -374,80 +742,295 @@ impl<'a> DateTimeParser<'a> {
             Ok(day) => day,
             Err(e) => return Err(e),
         };
+        self.parse_time_after_date(&mut result)?;
+        self.parse_suffix(&mut result)?;
+        return Ok(result);
+    }
+
+    /// Parse a year and month, such as `2024-03` or `202403`, optionally followed by
+    /// annotations.
+    ///
+    /// # Examples
+    /// ```
+    /// use ixdtf::parser::DateTimeParser;
+    ///
+    /// let parsed = DateTimeParser::new("2024-03[u-ca=iso8601]".as_bytes())
+    ///     .parse_year_month()
+    ///     .unwrap();
+    /// assert_eq!((parsed.year, parsed.month, parsed.day), (Some(2024), Some(3), None));
+    /// ```
+    pub fn parse_year_month(&mut self) -> Result<ParsedDateTime<'a>, ParseError> {
+        let mut result = Self::new_result();
+        result.year = self.parse_date_year()?;
+        self.bytes = strip_byte(self.bytes, b'-').1;
+        result.month = self.parse_date_month()?;
+        self.parse_suffix(&mut result)?;
+        Ok(result)
+    }
+
+    /// Parse a month and day, such as `--03-11`, `--0311` or `03-11`, optionally followed by
+    /// annotations.
+    ///
+    /// The day is validated against the month, allowing February 29.
+    ///
+    /// # Examples
+    /// ```
+    /// use ixdtf::parser::{DateTimeParser, ParseError};
+    ///
+    /// let parsed = DateTimeParser::new("--03-11".as_bytes())
+    ///     .parse_month_day()
+    ///     .unwrap();
+    /// assert_eq!((parsed.year, parsed.month, parsed.day), (None, Some(3), Some(11)));
+    ///
+    /// assert_eq!(
+    ///     DateTimeParser::new("--04-31".as_bytes()).parse_month_day(),
+    ///     Err(ParseError::DateDay)
+    /// );
+    /// ```
+    pub fn parse_month_day(&mut self) -> Result<ParsedDateTime<'a>, ParseError> {
+        let mut result = Self::new_result();
+        if let Some(remains) = self.bytes.strip_prefix(b"--") {
+            self.bytes = remains;
+        }
+        result.month = self.parse_date_month()?;
+        self.bytes = strip_byte(self.bytes, b'-').1;
+        let day = self.parse_date_day()?;
+        if let (Some(month), Some(day)) = (result.month, day) {
+            // Any year, so February has 29 days
+            if day > days_in_month(2000, month) {
+                return Err(ParseError::DateDay);
+            }
+        }
+        result.day = day;
+        self.parse_suffix(&mut result)?;
+        Ok(result)
+    }
+
+    /// Parse a time without a date, such as `T10:30` or `10:30:00+05:30`, optionally followed by
+    /// annotations.
+    ///
+    /// # Examples
+    /// ```
+    /// use ixdtf::parser::DateTimeParser;
+    ///
+    /// let parsed = DateTimeParser::new("T10:30".as_bytes()).parse_time().unwrap();
+    /// assert_eq!((parsed.hour, parsed.minute), (Some(10), Some(30)));
+    /// assert_eq!(parsed.year, None);
+    /// ```
+    pub fn parse_time(&mut self) -> Result<ParsedDateTime<'a>, ParseError> {
+        let mut result = Self::new_result();
+        if let Some((b'T' | b't', remains)) = self.bytes.split_first() {
+            self.bytes = remains;
+        }
+        self.parse_time_fields(&mut result)?;
+        self.parse_suffix(&mut result)?;
+        Ok(result)
+    }
+
+    /// Parse an ISO week date, such as `2024-W11-1` or `2024W11`, optionally followed by a time
+    /// and annotations.
+    ///
+    /// The year is the ISO week-numbering year. Week 53 is only accepted in years that have it.
+    ///
+    /// # Examples
+    /// ```
+    /// use ixdtf::parser::{DateTimeParser, ParseError};
+    ///
+    /// let parsed = DateTimeParser::new("2024-W11-1".as_bytes())
+    ///     .parse_week_date()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     (parsed.year, parsed.week, parsed.week_day),
+    ///     (Some(2024), Some(11), Some(1))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     DateTimeParser::new("2024-W53-1".as_bytes()).parse_week_date(),
+    ///     Err(ParseError::DateWeek)
+    /// );
+    /// ```
+    pub fn parse_week_date(&mut self) -> Result<ParsedDateTime<'a>, ParseError> {
+        let mut result = Self::new_result();
+        let year = self.parse_date_year()?;
+        result.year = year;
+        let (extended, remains) = strip_byte(self.bytes, b'-');
+        let remains = match remains.split_first() {
+            Some((b'W', remains)) => remains,
+            _ => return Err(ParseError::DateWeek),
+        };
+        let (week, remains) = parse_digits(remains, 2).ok_or(ParseError::DateWeek)?;
+        let weeks_in_year = year.map_or(53, weeks_in_year);
+        if !(1..=weeks_in_year).contains(&week) {
+            return Err(ParseError::DateWeek);
+        }
+        result.week = Some(week as u8);
+        self.bytes = remains;
+
+        let (had_separator, remains) = strip_byte(self.bytes, b'-');
+        if had_separator && !extended {
+            return Err(ParseError::DateSeparator);
+        }
+        match parse_digits(remains, 1) {
+            Some((week_day, remains)) => {
+                if !(1..=7).contains(&week_day) {
+                    return Err(ParseError::DateWeekDay);
+                }
+                if extended && !had_separator {
+                    return Err(ParseError::DateSeparator);
+                }
+                result.week_day = Some(week_day as u8);
+                self.bytes = remains;
+            }
+            None if had_separator => return Err(ParseError::DateWeekDay),
+            None => {}
+        }
+        self.parse_time_after_date(&mut result)?;
+        self.parse_suffix(&mut result)?;
+        Ok(result)
+    }
+
+    /// Parse an ordinal date, such as `2024-071` or `2024071`, optionally followed by a time and
+    /// annotations.
+    ///
+    /// # Examples
+    /// ```
+    /// use ixdtf::parser::{DateTimeParser, ParseError};
+    ///
+    /// let parsed = DateTimeParser::new("2024-366".as_bytes())
+    ///     .parse_ordinal_date()
+    ///     .unwrap();
+    /// assert_eq!((parsed.year, parsed.day_of_year), (Some(2024), Some(366)));
+    ///
+    /// assert_eq!(
+    ///     DateTimeParser::new("2023-366".as_bytes()).parse_ordinal_date(),
+    ///     Err(ParseError::DateOrdinal)
+    /// );
+    /// ```
+    pub fn parse_ordinal_date(&mut self) -> Result<ParsedDateTime<'a>, ParseError> {
+        let mut result = Self::new_result();
+        let year = self.parse_date_year()?;
+        result.year = year;
+        let remains = strip_byte(self.bytes, b'-').1;
+        let (day_of_year, remains) = parse_digits(remains, 3).ok_or(ParseError::DateOrdinal)?;
+        let days_in_year = match year {
+            Some(year) if !is_leap_year(year) => 365,
+            _ => 366,
+        };
+        if !(1..=days_in_year).contains(&day_of_year) {
+            return Err(ParseError::DateOrdinal);
+        }
+        result.day_of_year = Some(day_of_year as u16);
+        self.bytes = remains;
+        self.parse_time_after_date(&mut result)?;
+        self.parse_suffix(&mut result)?;
+        Ok(result)
+    }
+
+    fn new_result() -> ParsedDateTime<'a> {
+        ParsedDateTime {
+            year: None,
+            month: None,
+            day: None,
+            hour: None,
+            minute: None,
+            second: None,
+            nano_second: None,
+            week: None,
+            week_day: None,
+            day_of_year: None,
+            offset: None,
+            time_zone: None,
+            annotations: Annotations::default(),
+        }
+    }
+
+    fn parse_time_after_date(&mut self, result: &mut ParsedDateTime<'a>) -> Result<(), ParseError> {
         if let Some((first, remains)) = self.bytes.split_first() {
             if Self::is_date_time_separator(first) {
                 self.bytes = remains;
-                result.hour = match self.parse_time_hour() {
-                    Ok(hour) => hour,
-                    Err(e) => return Err(e),
-                };
-                let had_first_time_separator = {
-                    if let Some((first, inner_remains)) = self.bytes.split_first() {
-                        if first == &b':' {
-                            self.bytes = inner_remains;
-                            true
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                };
-                if had_first_time_separator && self.bytes.is_empty() {
-                    return Err(ParseError::TimeSeparator);
+                self.parse_time_fields(result)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_time_fields(&mut self, result: &mut ParsedDateTime<'a>) -> Result<(), ParseError> {
+        result.hour = match self.parse_time_hour() {
+            Ok(hour) => hour,
+            Err(e) => return Err(e),
+        };
+        let had_first_time_separator = {
+            if let Some((first, inner_remains)) = self.bytes.split_first() {
+                if first == &b':' {
+                    self.bytes = inner_remains;
+                    true
+                } else {
+                    false
                 }
-                result.minute = match self.parse_time_minute() {
-                    Ok(minute) => minute,
-                    Err(e) => return Err(e),
-                };
-                let had_second_time_separator = {
-                    if let Some((first, inner_remains)) = self.bytes.split_first() {
-                        if first == &b':' {
-                            self.bytes = inner_remains;
-                            true
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                };
-                if had_second_time_separator && self.bytes.is_empty()
-                    || had_first_time_separator != had_second_time_separator
-                        && !(had_first_time_separator && self.bytes.is_empty())
-                {
-                    return Err(ParseError::TimeSeparator);
+            } else {
+                false
+            }
+        };
+        if had_first_time_separator && self.is_time_end() {
+            return Err(ParseError::TimeSeparator);
+        }
+        result.minute = match self.parse_time_minute() {
+            Ok(minute) => minute,
+            Err(e) => return Err(e),
+        };
+        let had_second_time_separator = {
+            if let Some((first, inner_remains)) = self.bytes.split_first() {
+                if first == &b':' {
+                    self.bytes = inner_remains;
+                    true
+                } else {
+                    false
                 }
-                result.second = match self.parse_time_second() {
-                    Ok(second) => second,
-                    Err(e) => return Err(e),
-                };
-                let had_decimal_separator = {
-                    if let Some((first, inner_remains)) = self.bytes.split_first() {
-                        if first == &DecimalSeparator::Dot.value()
-                            || first == &DecimalSeparator::Comma.value()
-                        {
-                            self.bytes = inner_remains;
-                            true
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                };
-                if had_decimal_separator {
-                    result.nano_second = match self.parse_fraction_part() {
-                        Ok(nano_second) => nano_second,
-                        Err(e) => return Err(e),
-                    };
+            } else {
+                false
+            }
+        };
+        if had_second_time_separator && self.is_time_end()
+            || had_first_time_separator != had_second_time_separator
+                && !(had_first_time_separator && self.is_time_end())
+        {
+            return Err(ParseError::TimeSeparator);
+        }
+        result.second = match self.parse_time_second() {
+            Ok(second) => second,
+            Err(e) => return Err(e),
+        };
+        let had_decimal_separator = {
+            if let Some((first, inner_remains)) = self.bytes.split_first() {
+                if first == &DecimalSeparator::Dot.value()
+                    || first == &DecimalSeparator::Comma.value()
+                {
+                    self.bytes = inner_remains;
+                    true
+                } else {
+                    false
                 }
+            } else {
+                false
             }
+        };
+        if had_decimal_separator {
+            result.nano_second = match self.parse_fraction_part() {
+                Ok(nano_second) => nano_second,
+                Err(e) => return Err(e),
+            };
         }
+        result.offset = self.parse_offset()?;
+        Ok(())
+    }
+
+    fn parse_suffix(&mut self, result: &mut ParsedDateTime<'a>) -> Result<(), ParseError> {
+        result.time_zone = self.parse_time_zone_annotation()?;
+        result.annotations = self.parse_annotations()?;
         if !self.bytes.is_empty() {
             return Err(ParseError::DateUnexpectedEnd);
         }
-        return Ok(result);
+        Ok(())
     }
 }
 
@@ -469,6 +1052,12 @@ mod test {
                 minute: None,
                 second: None,
                 nano_second: None,
+                week: None,
+                week_day: None,
+                day_of_year: None,
+                offset: None,
+                time_zone: None,
+                annotations: Annotations::default(),
             })
         );
 
@@ -484,6 +1073,12 @@ mod test {
                 minute: None,
                 second: None,
                 nano_second: None,
+                week: None,
+                week_day: None,
+                day_of_year: None,
+                offset: None,
+                time_zone: None,
+                annotations: Annotations::default(),
             })
         );
 
@@ -499,6 +1094,12 @@ mod test {
                 minute: None,
                 second: None,
                 nano_second: None,
+                week: None,
+                week_day: None,
+                day_of_year: None,
+                offset: None,
+                time_zone: None,
+                annotations: Annotations::default(),
             })
         );
 
@@ -514,6 +1115,12 @@ mod test {
                 minute: Some(34),
                 second: None,
                 nano_second: None,
+                week: None,
+                week_day: None,
+                day_of_year: None,
+                offset: None,
+                time_zone: None,
+                annotations: Annotations::default(),
             })
         );
 
@@ -529,6 +1136,12 @@ mod test {
                 minute: Some(34),
                 second: Some(22),
                 nano_second: None,
+                week: None,
+                week_day: None,
+                day_of_year: None,
+                offset: None,
+                time_zone: None,
+                annotations: Annotations::default(),
             })
         );
 
@@ -544,6 +1157,12 @@ mod test {
                 minute: Some(34),
                 second: Some(22),
                 nano_second: Some(0),
+                week: None,
+                week_day: None,
+                day_of_year: None,
+                offset: None,
+                time_zone: None,
+                annotations: Annotations::default(),
             })
         );
 
@@ -559,6 +1178,12 @@ mod test {
                 minute: Some(34),
                 second: Some(22),
                 nano_second: Some(0),
+                week: None,
+                week_day: None,
+                day_of_year: None,
+                offset: None,
+                time_zone: None,
+                annotations: Annotations::default(),
             })
         );
     }
@@ -616,4 +1241,240 @@ mod test {
         let parsed = DateTimeParser::new(dt).parse();
         assert_eq!(parsed, Err(ParseError::DateUnexpectedEnd));
     }
+
+    #[test]
+    fn test_offset() {
+        let dt = "2024-03-11T10:00:00+05:30".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse().unwrap();
+        assert_eq!(parsed.second, Some(0));
+        assert_eq!(
+            parsed.offset,
+            Some(Offset::Numeric(UtcOffset {
+                sign: 1,
+                hour: 5,
+                minute: 30,
+                second: 0,
+                nano_second: 0,
+            }))
+        );
+
+        let dt = "2024-03-11T10:00Z".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse().unwrap();
+        assert_eq!(parsed.minute, Some(0));
+        assert_eq!(parsed.offset, Some(Offset::Z));
+
+        let dt = "2024-03-11T10-0330".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse().unwrap();
+        assert_eq!(parsed.hour, Some(10));
+        assert_eq!(
+            parsed.offset,
+            Some(Offset::Numeric(UtcOffset {
+                sign: -1,
+                hour: 3,
+                minute: 30,
+                second: 0,
+                nano_second: 0,
+            }))
+        );
+
+        let dt = "2024-03-11T10:00:00.25-00:00:01.5".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse().unwrap();
+        assert_eq!(parsed.nano_second, Some(250_000_000));
+        assert_eq!(
+            parsed.offset,
+            Some(Offset::Numeric(UtcOffset {
+                sign: -1,
+                hour: 0,
+                minute: 0,
+                second: 1,
+                nano_second: 500_000_000,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bad_offset() {
+        let dt = "2024-03-11Z".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::DateUnexpectedEnd));
+
+        let dt = "2024-03-11T10:00+24:00".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::UtcOffsetHour));
+
+        let dt = "2024-03-11T10:00+05:60".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::UtcOffsetMinute));
+
+        let dt = "2024-03-11T10:00+05:3000".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::UtcOffsetSeparator));
+
+        let dt = "2024-03-11T10:00+0530:00".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::UtcOffsetSeparator));
+
+        let dt = "2024-03-11T10:+05:30".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::TimeSeparator));
+    }
+
+    #[test]
+    fn test_annotations() {
+        let dt = "2024-03-11T10:00:00+05:30[Asia/Kolkata]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse().unwrap();
+        assert_eq!(
+            parsed.time_zone,
+            Some(TimeZoneAnnotation {
+                critical: false,
+                time_zone: TimeZoneRecord::Name("Asia/Kolkata".as_bytes()),
+            })
+        );
+        assert!(parsed.annotations.is_empty());
+
+        let dt = "2024-03-11T10:00Z[!-03:00][u-ca=hebrew][_foo=bar-baz]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse().unwrap();
+        assert_eq!(
+            parsed.time_zone,
+            Some(TimeZoneAnnotation {
+                critical: true,
+                time_zone: TimeZoneRecord::Offset(UtcOffset {
+                    sign: -1,
+                    hour: 3,
+                    minute: 0,
+                    second: 0,
+                    nano_second: 0,
+                }),
+            })
+        );
+        let mut annotations = parsed.annotations.iter();
+        assert_eq!(
+            annotations.next(),
+            Some(Annotation {
+                critical: false,
+                key: "u-ca".as_bytes(),
+                value: "hebrew".as_bytes(),
+            })
+        );
+        assert_eq!(
+            annotations.next(),
+            Some(Annotation {
+                critical: false,
+                key: "_foo".as_bytes(),
+                value: "bar-baz".as_bytes(),
+            })
+        );
+        assert_eq!(annotations.next(), None);
+
+        // Annotations without a time zone
+        let dt = "2024-03-11[!u-ca=japanese][u-ca=gregory]".as_bytes();
+        let parsed = DateTimeParser::new(dt);
+        assert_eq!(
+            parsed.clone().parse().map(|p| p.annotations.calendar()),
+            Err(ParseError::CriticalDuplicateCalendar)
+        );
+
+        let dt = "2024-03-11[u-ca=japanese][u-ca=gregory]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse().unwrap();
+        assert_eq!(parsed.time_zone, None);
+        assert_eq!(parsed.annotations.calendar(), Some("japanese".as_bytes()));
+    }
+
+    #[test]
+    fn test_bad_annotations() {
+        let dt = "2024-03-11T10:00[!foo=bar]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::UnrecognizedCriticalAnnotation));
+
+        let dt = "2024-03-11T10:00[Asia/Kolkata".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::AnnotationClose));
+
+        let dt = "2024-03-11T10:00[Asia/../Kolkata]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::TimeZoneAnnotation));
+
+        let dt = "2024-03-11T10:00[+05:30:00]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::TimeZoneAnnotation));
+
+        let dt = "2024-03-11T10:00[u-ca=hebrew][Asia/Kolkata]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::AnnotationKey));
+
+        let dt = "2024-03-11T10:00[U-CA=hebrew]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::AnnotationKey));
+
+        let dt = "2024-03-11T10:00[u-ca=hebrew-]".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::AnnotationValue));
+
+        let dt = "2024-03-11T10:00[u-ca=hebrew]x".as_bytes();
+        let parsed = DateTimeParser::new(dt).parse();
+        assert_eq!(parsed, Err(ParseError::DateUnexpectedEnd));
+    }
+
+    #[test]
+    fn test_partial_dates() {
+        let parsed = DateTimeParser::new("202403".as_bytes()).parse_year_month();
+        assert_eq!(parsed.map(|p| (p.year, p.month)), Ok((Some(2024), Some(3))));
+        let parsed = DateTimeParser::new("2024-03-11".as_bytes()).parse_year_month();
+        assert_eq!(parsed, Err(ParseError::DateUnexpectedEnd));
+
+        let parsed = DateTimeParser::new("0229".as_bytes()).parse_month_day();
+        assert_eq!(parsed.map(|p| (p.month, p.day)), Ok((Some(2), Some(29))));
+        let parsed = DateTimeParser::new("--02-30".as_bytes()).parse_month_day();
+        assert_eq!(parsed, Err(ParseError::DateDay));
+
+        let parsed = DateTimeParser::new("10:30:15Z".as_bytes())
+            .parse_time()
+            .unwrap();
+        assert_eq!(
+            (parsed.hour, parsed.minute, parsed.second, parsed.offset),
+            (Some(10), Some(30), Some(15), Some(Offset::Z))
+        );
+        let parsed = DateTimeParser::new("T10:30[Europe/Paris]".as_bytes()).parse_time();
+        assert!(matches!(
+            parsed.map(|p| p.time_zone),
+            Ok(Some(TimeZoneAnnotation {
+                time_zone: TimeZoneRecord::Name(b"Europe/Paris"),
+                ..
+            }))
+        ));
+        let parsed = DateTimeParser::new("T24:00".as_bytes()).parse_time();
+        assert_eq!(parsed, Err(ParseError::TimeHour));
+    }
+
+    #[test]
+    fn test_week_and_ordinal_dates() {
+        let parsed = DateTimeParser::new("2020W537".as_bytes()).parse_week_date();
+        assert_eq!(
+            parsed.map(|p| (p.year, p.week, p.week_day)),
+            Ok((Some(2020), Some(53), Some(7)))
+        );
+        let parsed = DateTimeParser::new("2026-W53T10:00".as_bytes()).parse_week_date();
+        assert_eq!(
+            parsed.map(|p| (p.week, p.week_day, p.hour)),
+            Ok((Some(53), None, Some(10)))
+        );
+        let parsed = DateTimeParser::new("2024-W00-1".as_bytes()).parse_week_date();
+        assert_eq!(parsed, Err(ParseError::DateWeek));
+        let parsed = DateTimeParser::new("2024-W11-8".as_bytes()).parse_week_date();
+        assert_eq!(parsed, Err(ParseError::DateWeekDay));
+        let parsed = DateTimeParser::new("2024-W111".as_bytes()).parse_week_date();
+        assert_eq!(parsed, Err(ParseError::DateSeparator));
+        let parsed = DateTimeParser::new("2024W11-1".as_bytes()).parse_week_date();
+        assert_eq!(parsed, Err(ParseError::DateSeparator));
+
+        let parsed = DateTimeParser::new("2024071T10:00+01:00".as_bytes()).parse_ordinal_date();
+        assert_eq!(
+            parsed.map(|p| (p.year, p.day_of_year, p.hour)),
+            Ok((Some(2024), Some(71), Some(10)))
+        );
+        let parsed = DateTimeParser::new("2024-000".as_bytes()).parse_ordinal_date();
+        assert_eq!(parsed, Err(ParseError::DateOrdinal));
+        let parsed = DateTimeParser::new("2024-71".as_bytes()).parse_ordinal_date();
+        assert_eq!(parsed, Err(ParseError::DateOrdinal));
+    }
 }
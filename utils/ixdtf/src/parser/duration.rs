@@ -0,0 +1,304 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::{parse_fraction, ParseError};
+
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+/// [`ParsedDuration`] is the parsed result from the [`DurationParser`].
+///
+/// All fields are unsigned; the sign of the whole duration is stored in `sign`. A fractional
+/// hour or minute is balanced into the smaller units, so `PT1.5H` has `hours: 1` and
+/// `minutes: 30`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParsedDuration {
+    /// `-1` for negative durations, `1` otherwise.
+    pub sign: i8,
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    pub nano_seconds: i32,
+}
+
+// The designators in the order in which they must appear.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+enum Unit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+/// [`DurationParser`] is the parser to parse ISO 8601 duration bytes, such as
+/// `P1Y2M3DT4H5M6.5S`.
+///
+/// # Examples
+/// ```
+/// use ixdtf::parser::{DurationParser, ParseError};
+///
+/// let duration = DurationParser::new("-P1DT1.5H".as_bytes()).parse().unwrap();
+/// assert_eq!(duration.sign, -1);
+/// assert_eq!(duration.days, 1);
+/// assert_eq!(duration.hours, 1);
+/// assert_eq!(duration.minutes, 30);
+///
+/// // Designators must be in order
+/// let mut parser = DurationParser::new("P1D2Y".as_bytes());
+/// assert_eq!(parser.parse(), Err(ParseError::DurationOrder));
+/// assert_eq!(parser.position(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationParser<'a> {
+    source: &'a [u8],
+    bytes: &'a [u8],
+}
+
+impl<'a> DurationParser<'a> {
+    /// Create a new instance of [`DurationParser`].
+    pub fn new(bytes: &'a [u8]) -> DurationParser<'a> {
+        DurationParser {
+            source: bytes,
+            bytes,
+        }
+    }
+
+    /// Returns the number of bytes consumed so far.
+    ///
+    /// After [`DurationParser::parse`] fails, this is the offset of the offending component.
+    pub fn position(&self) -> usize {
+        self.source.len() - self.bytes.len()
+    }
+
+    /// Parse the ISO 8601 duration bytes to human readable results, stored in
+    /// [`ParsedDuration`].
+    pub fn parse(&mut self) -> Result<ParsedDuration, ParseError> {
+        let mut result = ParsedDuration {
+            sign: 1,
+            ..Default::default()
+        };
+        match self.bytes.split_first() {
+            Some((b'+', remains)) => self.bytes = remains,
+            Some((b'-', remains)) => {
+                result.sign = -1;
+                self.bytes = remains;
+            }
+            _ => {}
+        }
+        match self.bytes.split_first() {
+            Some((b'P' | b'p', remains)) => self.bytes = remains,
+            _ => return Err(ParseError::DurationPrefix),
+        }
+
+        let mut in_time = false;
+        let mut last_unit: Option<Unit> = None;
+        let mut had_fraction = false;
+        let mut had_time_component = false;
+        while let Some(first) = self.bytes.first() {
+            if matches!(first, b'T' | b't') {
+                if in_time {
+                    return Err(ParseError::DurationOrder);
+                }
+                in_time = true;
+                self.bytes = self.bytes.get(1..).unwrap_or_default();
+                continue;
+            }
+
+            let start = self.bytes;
+            let (value, fraction, unit) = self.parse_component(in_time)?;
+            if last_unit.map_or(false, |last| last >= unit) {
+                self.bytes = start;
+                return Err(ParseError::DurationOrder);
+            }
+            // Only the smallest unit may have a fraction
+            if had_fraction || fraction.is_some() && unit < Unit::Hours {
+                self.bytes = start;
+                return Err(ParseError::DurationFraction);
+            }
+            match unit {
+                Unit::Years => result.years = value,
+                Unit::Months => result.months = value,
+                Unit::Weeks => result.weeks = value,
+                Unit::Days => result.days = value,
+                Unit::Hours => result.hours = value,
+                Unit::Minutes => result.minutes = value,
+                Unit::Seconds => result.seconds = value,
+            }
+            if let Some(fraction) = fraction {
+                Self::balance_fraction(&mut result, unit, fraction);
+            }
+            last_unit = Some(unit);
+            had_fraction = fraction.is_some();
+            had_time_component |= in_time;
+        }
+
+        if last_unit.is_none() || in_time && !had_time_component {
+            return Err(ParseError::DurationEmpty);
+        }
+        Ok(result)
+    }
+
+    // Parses a component like `12D` or `1.5H`. The parser is only advanced on success.
+    fn parse_component(&mut self, in_time: bool) -> Result<(u32, Option<i32>, Unit), ParseError> {
+        let len = self
+            .bytes
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return Err(ParseError::DurationValue);
+        }
+        let (digits, mut remains) = self.bytes.split_at(len);
+        let value = digits
+            .iter()
+            .try_fold(0_u32, |value, digit| {
+                value.checked_mul(10)?.checked_add((digit - b'0') as u32)
+            })
+            .ok_or(ParseError::DurationValue)?;
+
+        let fraction = match remains.split_first() {
+            Some((b'.' | b',', fraction)) => {
+                let (fraction, fraction_remains) =
+                    parse_fraction(fraction).ok_or(ParseError::FractionPart)?;
+                remains = fraction_remains;
+                Some(fraction)
+            }
+            _ => None,
+        };
+
+        let (designator, remains) = remains
+            .split_first()
+            .ok_or(ParseError::DurationDesignator)?;
+        let unit = match (in_time, designator.to_ascii_uppercase()) {
+            (false, b'Y') => Unit::Years,
+            (false, b'M') => Unit::Months,
+            (false, b'W') => Unit::Weeks,
+            (false, b'D') => Unit::Days,
+            (true, b'H') => Unit::Hours,
+            (true, b'M') => Unit::Minutes,
+            (true, b'S') => Unit::Seconds,
+            _ => return Err(ParseError::DurationDesignator),
+        };
+        self.bytes = remains;
+        Ok((value, fraction, unit))
+    }
+
+    // Distributes a fractional hour, minute or second into the smaller units.
+    fn balance_fraction(result: &mut ParsedDuration, unit: Unit, fraction: i32) {
+        let fraction = fraction as u64;
+        let nano_seconds = match unit {
+            Unit::Hours => fraction * 3600,
+            Unit::Minutes => fraction * 60,
+            _ => fraction,
+        };
+        // These are less than 60, because the fraction is less than one unit.
+        let minutes = nano_seconds / (60 * NANOSECONDS_PER_SECOND);
+        let seconds = nano_seconds / NANOSECONDS_PER_SECOND % 60;
+        if unit == Unit::Hours {
+            result.minutes = minutes as u32;
+        }
+        if unit <= Unit::Minutes {
+            result.seconds = seconds as u32;
+        }
+        result.nano_seconds = (nano_seconds % NANOSECONDS_PER_SECOND) as i32;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(duration: &str) -> Result<ParsedDuration, (ParseError, usize)> {
+        let mut parser = DurationParser::new(duration.as_bytes());
+        parser.parse().map_err(|e| (e, parser.position()))
+    }
+
+    #[test]
+    fn test_correct_duration() {
+        assert_eq!(
+            parse("P1Y2M3DT4H5M6.5S"),
+            Ok(ParsedDuration {
+                sign: 1,
+                years: 1,
+                months: 2,
+                weeks: 0,
+                days: 3,
+                hours: 4,
+                minutes: 5,
+                seconds: 6,
+                nano_seconds: 500_000_000,
+            })
+        );
+        assert_eq!(
+            parse("-P2W"),
+            Ok(ParsedDuration {
+                sign: -1,
+                weeks: 2,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse("+pt10m"),
+            Ok(ParsedDuration {
+                sign: 1,
+                minutes: 10,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse("PT1.755H"),
+            Ok(ParsedDuration {
+                sign: 1,
+                hours: 1,
+                minutes: 45,
+                seconds: 18,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse("P1DT0,000000001M"),
+            Ok(ParsedDuration {
+                sign: 1,
+                days: 1,
+                nano_seconds: 60,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse("PT4294967295S"),
+            Ok(ParsedDuration {
+                sign: 1,
+                seconds: u32::MAX,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_bad_duration() {
+        assert_eq!(parse("1D"), Err((ParseError::DurationPrefix, 0)));
+        assert_eq!(parse("-"), Err((ParseError::DurationPrefix, 1)));
+        assert_eq!(parse("P"), Err((ParseError::DurationEmpty, 1)));
+        assert_eq!(parse("P1DT"), Err((ParseError::DurationEmpty, 4)));
+        assert_eq!(parse("PT1HT1M"), Err((ParseError::DurationOrder, 4)));
+        assert_eq!(parse("P1M1Y"), Err((ParseError::DurationOrder, 3)));
+        assert_eq!(parse("PT1S1M"), Err((ParseError::DurationOrder, 4)));
+        assert_eq!(parse("P1D1D"), Err((ParseError::DurationOrder, 3)));
+        assert_eq!(parse("P1H"), Err((ParseError::DurationDesignator, 1)));
+        assert_eq!(parse("PT1D"), Err((ParseError::DurationDesignator, 2)));
+        assert_eq!(parse("P1"), Err((ParseError::DurationDesignator, 1)));
+        assert_eq!(parse("P1DX"), Err((ParseError::DurationValue, 3)));
+        assert_eq!(parse("PT4294967296S"), Err((ParseError::DurationValue, 2)));
+        assert_eq!(parse("P1.5D"), Err((ParseError::DurationFraction, 1)));
+        assert_eq!(parse("PT1.5H2M"), Err((ParseError::DurationFraction, 6)));
+        assert_eq!(parse("PT1.S"), Err((ParseError::FractionPart, 2)));
+    }
+}
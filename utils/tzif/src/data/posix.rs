@@ -68,3 +68,117 @@ pub struct PosixTzString {
     /// The variant info of the DST time-zone variant if present.
     pub dst_info: Option<DstTransitionInfo>,
 }
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+impl PosixTzString {
+    /// Returns the time-zone variant in effect at the given UNIX timestamp, and whether it is
+    /// the DST variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tzif::data::time::Seconds;
+    ///
+    /// let posix = tzif::parse_posix_tz_string(b"PST8PDT,M3.2.0,M11.1.0").unwrap();
+    ///
+    /// // 2024-07-01T00:00:00Z
+    /// let (variant, is_dst) = posix.find_variant(Seconds(1719792000));
+    /// assert_eq!(variant.name, "PDT");
+    /// assert!(is_dst);
+    ///
+    /// // 2024-12-01T00:00:00Z
+    /// let (variant, is_dst) = posix.find_variant(Seconds(1733011200));
+    /// assert_eq!(variant.name, "PST");
+    /// assert!(!is_dst);
+    /// ```
+    pub fn find_variant(&self, timestamp: Seconds) -> (&ZoneVariantInfo, bool) {
+        let dst_info = match &self.dst_info {
+            Some(dst_info) => dst_info,
+            None => return (&self.std_info, false),
+        };
+        // The transition dates are in local time, so the year is determined in standard time.
+        let year =
+            year_from_days((timestamp.0 - self.std_info.offset.0).div_euclid(SECONDS_PER_DAY));
+        // The start of DST is given in standard time, the end in DST.
+        let start = dst_info.start_date.timestamp_in_year(year) + self.std_info.offset.0;
+        let end = dst_info.end_date.timestamp_in_year(year) + dst_info.variant_info.offset.0;
+        let is_dst = if start <= end {
+            start <= timestamp.0 && timestamp.0 < end
+        } else {
+            // DST spans the new year, as in the southern hemisphere.
+            !(end <= timestamp.0 && timestamp.0 < start)
+        };
+        if is_dst {
+            (&dst_info.variant_info, true)
+        } else {
+            (&self.std_info, false)
+        }
+    }
+}
+
+impl TransitionDate {
+    /// Returns the local seconds since the UNIX epoch of this transition in the given year.
+    fn timestamp_in_year(&self, year: i64) -> i64 {
+        let is_leap = is_leap_year(year);
+        let days = match self.day {
+            TransitionDay::NoLeap(day) => {
+                let day = i64::from(day) - 1;
+                // Days on or after March 1st skip February 29th
+                days_from_civil(year, 1, 1) + day + i64::from(is_leap && day >= 59)
+            }
+            TransitionDay::WithLeap(day) => days_from_civil(year, 1, 1) + i64::from(day),
+            TransitionDay::Mwd(month, week, weekday) => {
+                let month = i64::from(month);
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day =
+                    (i64::from(weekday) - first_weekday).rem_euclid(7) + 7 * (i64::from(week) - 1);
+                // Week 5 means the last such weekday of the month
+                while day >= days_in_month(year, month) {
+                    day -= 7;
+                }
+                first + day
+            }
+        };
+        days * SECONDS_PER_DAY + self.time.0
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 of the given proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // Count years from March, so that the leap day is at the end of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the proleptic Gregorian year of the given number of days since 1970-01-01.
+fn year_from_days(days: i64) -> i64 {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    // Years are counted from March, so January and February belong to the next year.
+    era * 400 + year_of_era + i64::from(month >= 10)
+}
@@ -150,6 +150,47 @@ impl TzifData {
             _ => self.header2.as_ref().map(TzifHeader::block_size::<V>),
         }
     }
+
+    /// Returns the [`LocalTimeTypeRecord`] that applies at the given UNIX timestamp.
+    ///
+    /// The version-2+ data block is used if present. Timestamps before the first transition
+    /// use the first local time type, and timestamps at or after the last transition use the
+    /// rules of the footer if present. A record derived from the footer is taken from the
+    /// data block if it has a matching offset, DST flag and designation; otherwise its `idx`
+    /// is zero.
+    ///
+    /// Returns [`None`] if the data block has no local time type records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tzif::data::time::Seconds;
+    ///
+    /// let data = tzif::parse_tzif_file("testdata/America/Los_Angeles").unwrap();
+    ///
+    /// // 2024-07-01T00:00:00Z
+    /// let record = data.find_local_time_type(Seconds(1719792000)).unwrap();
+    /// assert_eq!(record.utoff, Seconds(-7 * 60 * 60));
+    /// assert!(record.is_dst);
+    /// ```
+    pub fn find_local_time_type(&self, timestamp: Seconds) -> Option<LocalTimeTypeRecord> {
+        let block = self.data_block2.as_ref().unwrap_or(&self.data_block1);
+        let transitions = block
+            .transition_times
+            .partition_point(|time| *time <= timestamp);
+        match (&self.footer, transitions.checked_sub(1)) {
+            (Some(footer), _) if transitions == block.transition_times.len() => {
+                let (variant, is_dst) = footer.find_variant(timestamp);
+                Some(block.find_record(&variant.name, Seconds(-variant.offset.0), is_dst))
+            }
+            (_, None) => block.local_time_type_records.first().copied(),
+            (_, Some(transition)) => block
+                .transition_types
+                .get(transition)
+                .and_then(|&ty| block.local_time_type_records.get(ty))
+                .copied(),
+        }
+    }
 }
 
 /// A record specifying a local time type.
@@ -182,6 +223,26 @@ pub struct LocalTimeTypeRecord {
     pub idx: usize,
 }
 
+impl DataBlock {
+    /// Returns the local time type record with the given designation, offset and DST flag.
+    fn find_record(&self, designation: &str, utoff: Seconds, is_dst: bool) -> LocalTimeTypeRecord {
+        self.local_time_type_records
+            .iter()
+            .zip(&self.time_zone_designations)
+            .find(|(record, name)| {
+                record.utoff == utoff && record.is_dst == is_dst && name.as_str() == designation
+            })
+            .map_or(
+                LocalTimeTypeRecord {
+                    utoff,
+                    is_dst,
+                    idx: 0,
+                },
+                |(record, _)| *record,
+            )
+    }
+}
+
 /// A record specifying the corrections that need to be applied to the UTC in
 /// in order to determine TAI.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -17,6 +17,16 @@
 //! let data = tzif::parse_tzif_file("path_to_file").unwrap();
 //! ```
 //!
+//! ### Parse TZif data from bytes
+//! ```rust
+//! use tzif::data::time::Seconds;
+//!
+//! let data =
+//!     tzif::parse_tzif_bytes(include_bytes!("../testdata/America/Bogota")).unwrap();
+//! let record = data.find_local_time_type(Seconds(0)).unwrap();
+//! assert_eq!(record.utoff, Seconds(-5 * 60 * 60));
+//! ```
+//!
 //! ### Parse POSIX time-zone strings
 //! ```rust
 //! let data =
@@ -49,6 +59,11 @@ pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
     Ok(parse::tzif::tzif().parse(stream)?.0)
 }
 
+/// Parses `TZif` data from the given bytes, such as a file included with [`include_bytes!`].
+pub fn parse_tzif_bytes(bytes: &[u8]) -> Result<TzifData, Error> {
+    Ok(parse::tzif::tzif().parse(bytes)?.0)
+}
+
 /// Parses a POSIX time-zone string from the given bytes.
 pub fn parse_posix_tz_string(bytes: &[u8]) -> Result<PosixTzString, Error> {
     Ok(parse::posix::posix_tz_string().parse(bytes)?.0)
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use std::path::Path;
+use tzif::data::time::Seconds;
 use walkdir::WalkDir;
 
 fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<(), tzif::error::Error> {
@@ -27,3 +28,76 @@ fn parse_tzif_testdata() -> Result<(), tzif::error::Error> {
 fn parse_posix_tz_string() {
     assert!(tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").is_ok());
 }
+
+#[test]
+fn parse_tzif_bytes() {
+    let bytes = std::fs::read("testdata/America/Los_Angeles").unwrap();
+    let parsed = tzif::parse_tzif_bytes(&bytes).unwrap();
+    assert_eq!(parsed.version_number(), 2);
+    assert!(parsed.footer.is_some());
+
+    assert!(tzif::parse_tzif_bytes(&bytes[..bytes.len() / 2]).is_err());
+    assert!(tzif::parse_tzif_bytes(b"TZif").is_err());
+}
+
+#[test]
+fn find_local_time_type() {
+    let assert_local_time_type = |zone: &str, timestamp: i64, utoff: i64, is_dst: bool| {
+        let parsed = tzif::parse_tzif_file(format!("testdata/{zone}")).unwrap();
+        let record = parsed.find_local_time_type(Seconds(timestamp)).unwrap();
+        assert_eq!(
+            (record.utoff, record.is_dst),
+            (Seconds(utoff), is_dst),
+            "{zone} at {timestamp}"
+        );
+    };
+
+    // Before the first transition
+    assert_local_time_type("America/Los_Angeles", -3000000000, -28378, false);
+    // Transitions in the data block
+    assert_local_time_type("America/Los_Angeles", 0, -28800, false);
+    assert_local_time_type("America/Los_Angeles", 1719792000, -25200, true);
+    // The footer rules after the last transition
+    assert_local_time_type("America/Los_Angeles", 4108701599, -28800, false);
+    assert_local_time_type("America/Los_Angeles", 4108701600, -25200, true);
+    assert_local_time_type("America/Los_Angeles", 4129261199, -25200, true);
+    assert_local_time_type("America/Los_Angeles", 4129261200, -28800, false);
+    // DST in the southern hemisphere
+    assert_local_time_type("Chile/EasterIsland", 4102444800, -18000, true);
+    assert_local_time_type("Chile/EasterIsland", 4118083200, -21600, false);
+    // Explicit DST offset
+    assert_local_time_type("Antarctica/Troll", 4102444800, 0, false);
+    assert_local_time_type("Antarctica/Troll", 4118083200, 7200, true);
+    assert_local_time_type("Arctic/Longyearbyen", 4102444800, 3600, false);
+    assert_local_time_type("Arctic/Longyearbyen", 4118083200, 7200, true);
+    // No DST
+    assert_local_time_type("Asia/Tokyo", 4118083200, 32400, false);
+}
+
+#[test]
+fn footer_consistent_with_last_transition() {
+    for entry in WalkDir::new("testdata").follow_links(true) {
+        let entry = entry.unwrap();
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let parsed = tzif::parse_tzif_file(entry.path()).unwrap();
+        let (block, footer) = match (&parsed.data_block2, &parsed.footer) {
+            (Some(block), Some(footer)) => (block, footer),
+            _ => continue,
+        };
+        let (time, ty) = match (block.transition_times.last(), block.transition_types.last()) {
+            (Some(&time), Some(&ty)) => (time, ty),
+            _ => continue,
+        };
+        let expected = block.local_time_type_records[ty];
+        let (variant, is_dst) = footer.find_variant(time);
+        assert_eq!(
+            (Seconds(-variant.offset.0), is_dst),
+            (expected.utoff, expected.is_dst),
+            "{:?}",
+            entry.path()
+        );
+        assert_eq!(parsed.find_local_time_type(time), Some(expected));
+    }
+}